@@ -0,0 +1,293 @@
+// Dependencies
+use std::net::IpAddr;
+use sellix_api_models::SellixError;
+use sellix_api_models::blacklist::{BlacklistRaw, BlacklistTypes};
+use sellix_api_models::whitelist::{WhitelistRaw, WhitelistTypes};
+use crate::client::Client;
+use crate::resource::Listable;
+
+/// A single blacklist or whitelist entry, parsed so it can be matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessRule {
+    /// An email address, e.g. `name@example.com`, or every address at a domain, e.g. `@example.com`.
+    Email(String),
+    /// An IP address, or a CIDR range, e.g. `10.0.0.0/8`.
+    Ip {
+        network: IpAddr,
+        prefix: u8,
+    },
+    /// An ISO 3166-1 alpha-2 country code, e.g. `GB`.
+    Country(String),
+    /// The name of an internet service provider.
+    Isp(String),
+    /// An autonomous system number, e.g. `15169` for `AS15169`.
+    Asn(u64),
+    /// A hostname, along with every subdomain of it.
+    Host(String),
+}
+
+/// The types shared by blacklist and whitelist entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleType {
+    Email,
+    Ip,
+    Country,
+    Isp,
+    Asn,
+    Host,
+}
+
+impl AccessRule {
+    /// Parses a blacklist entry from its type and its data.
+    /// `None` if the type is not known, or the data is not valid for it.
+    pub fn parse_blacklist(kind: &BlacklistTypes, data: &str) -> Option<Self> {
+        let kind = match kind {
+            BlacklistTypes::Email => RuleType::Email,
+            BlacklistTypes::IP => RuleType::Ip,
+            BlacklistTypes::Country => RuleType::Country,
+            BlacklistTypes::ISP => RuleType::Isp,
+            BlacklistTypes::ASN => RuleType::Asn,
+            BlacklistTypes::Host => RuleType::Host,
+            BlacklistTypes::Unknown(_) => return None,
+        };
+        Self::parse(kind, data)
+    }
+
+    /// Parses a whitelist entry from its type and its data.
+    /// `None` if the type is not known, or the data is not valid for it.
+    pub fn parse_whitelist(kind: &WhitelistTypes, data: &str) -> Option<Self> {
+        let kind = match kind {
+            WhitelistTypes::Email => RuleType::Email,
+            WhitelistTypes::IP => RuleType::Ip,
+            WhitelistTypes::Country => RuleType::Country,
+            WhitelistTypes::ISP => RuleType::Isp,
+            WhitelistTypes::ASN => RuleType::Asn,
+            WhitelistTypes::Host => RuleType::Host,
+            WhitelistTypes::Unknown(_) => return None,
+        };
+        Self::parse(kind, data)
+    }
+
+    /// Parses an entry's data, `None` if it is not valid for the type.
+    fn parse(kind: RuleType, data: &str) -> Option<Self> {
+        let data = data.trim();
+        if data.is_empty() {
+            return None;
+        }
+
+        match kind {
+            RuleType::Email => Some(Self::Email(data.to_lowercase())),
+            RuleType::Ip => {
+                let (network, prefix) = match data.split_once('/') {
+                    Some((network, prefix)) => (network.parse::<IpAddr>().ok()?.to_canonical(), Some(prefix.parse::<u8>().ok()?)),
+                    None => (data.parse::<IpAddr>().ok()?.to_canonical(), None)
+                };
+                let max = if network.is_ipv4() { 32 } else { 128 };
+                let prefix = prefix.unwrap_or(max);
+                (prefix <= max).then_some(Self::Ip { network, prefix })
+            },
+            RuleType::Country => Some(Self::Country(data.to_uppercase())),
+            RuleType::Isp => Some(Self::Isp(data.to_lowercase())),
+            RuleType::Asn => {
+                let digits = data.strip_prefix("AS").or_else(|| data.strip_prefix("as")).unwrap_or(data);
+                digits.parse().ok().map(Self::Asn)
+            },
+            RuleType::Host => Some(Self::Host(data.trim_end_matches('.').to_lowercase())),
+        }
+    }
+
+    /// Whether the rule matches the request.
+    pub fn matches(&self, request: &AccessRequest) -> bool {
+        match self {
+            Self::Email(email) => request.email.as_ref().is_some_and(|x| {
+                let x = x.trim().to_lowercase();
+                match email.strip_prefix('@') {
+                    Some(domain) => x.rsplit_once('@').is_some_and(|(_, x)| x == domain),
+                    None => x == *email
+                }
+            }),
+            Self::Ip { network, prefix } => request.ip.is_some_and(|x| in_network(x.to_canonical(), *network, *prefix)),
+            Self::Country(country) => request.country.as_ref().is_some_and(|x| x.trim().eq_ignore_ascii_case(country)),
+            Self::Isp(isp) => request.isp.as_ref().is_some_and(|x| x.trim().to_lowercase() == *isp),
+            Self::Asn(asn) => request.asn == Some(*asn),
+            Self::Host(host) => request.host.as_ref().is_some_and(|x| {
+                let x = x.trim().trim_end_matches('.').to_lowercase();
+                x == *host || x.strip_suffix(host.as_str()).is_some_and(|x| x.ends_with('.'))
+            }),
+        }
+    }
+}
+
+/// Whether the address is within the network.
+fn in_network(address: IpAddr, network: IpAddr, prefix: u8) -> bool {
+    match (address, network) {
+        (IpAddr::V4(address), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(address) & mask == u32::from(network) & mask
+        },
+        (IpAddr::V6(address), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(address) & mask == u128::from(network) & mask
+        },
+        _ => false
+    }
+}
+
+/// Everything known about a customer, any of which may be missing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessRequest {
+    pub email: Option<String>,
+    pub ip: Option<IpAddr>,
+    pub country: Option<String>,
+    pub isp: Option<String>,
+    pub asn: Option<u64>,
+    pub host: Option<String>,
+}
+impl AccessRequest {
+    /// Initialises an empty request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the email.
+    pub fn email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_owned());
+        self
+    }
+
+    /// Sets the IP address.
+    pub fn ip(mut self, ip: IpAddr) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// Sets the country code.
+    pub fn country(mut self, country: &str) -> Self {
+        self.country = Some(country.to_owned());
+        self
+    }
+
+    /// Sets the ISP.
+    pub fn isp(mut self, isp: &str) -> Self {
+        self.isp = Some(isp.to_owned());
+        self
+    }
+
+    /// Sets the ASN.
+    pub fn asn(mut self, asn: u64) -> Self {
+        self.asn = Some(asn);
+        self
+    }
+
+    /// Sets the hostname.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_owned());
+        self
+    }
+}
+
+/// The entry which matched a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessMatch {
+    /// The uniqid of the blacklist or whitelist.
+    pub uniqid: String,
+    /// The note it was created with.
+    pub note: String,
+    pub rule: AccessRule,
+}
+
+/// Whether a customer would be blocked, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessDecision {
+    /// No blacklist matched.
+    Allowed,
+    /// A blacklist matched, and no whitelist did.
+    Blocked(AccessMatch),
+    /// A blacklist matched, but so did a whitelist, which takes priority.
+    Whitelisted {
+        blocked: AccessMatch,
+        whitelisted: AccessMatch,
+    },
+}
+impl AccessDecision {
+    /// Whether the customer would be blocked.
+    pub fn is_blocked(&self) -> bool {
+        matches!(self, Self::Blocked(_))
+    }
+}
+
+/// Checks customers against a shop's blacklists and whitelists, without any requests.
+///
+/// ```no_run
+/// # use sellix_rs::{Client, access::{AccessPolicy, AccessRequest}};
+/// # async fn example(client: &Client) -> Result<(), sellix_rs::sellix_api_models::SellixError> {
+/// let policy = AccessPolicy::load(client).await?;
+/// let request = AccessRequest::new()
+///     .email("name@example.com")
+///     .ip("203.0.113.7".parse().unwrap());
+///
+/// if policy.check(&request).is_blocked() {
+///     println!("Unable to sign up");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Entries whose data cannot be parsed, or whose type is not known, are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessPolicy {
+    blacklist: Vec<AccessMatch>,
+    whitelist: Vec<AccessMatch>,
+}
+impl AccessPolicy {
+    /// Creates a policy from entries already retrieved.
+    pub fn new(blacklists: &[BlacklistRaw], whitelists: &[WhitelistRaw]) -> Self {
+        let entry = |uniqid: &str, rule: Option<AccessRule>, note: &str| rule.map(|rule| AccessMatch {
+            uniqid: uniqid.to_owned(),
+            note: note.to_owned(),
+            rule,
+        });
+
+        Self {
+            blacklist: blacklists.iter().filter_map(|x| entry(&x.uniqid, AccessRule::parse_blacklist(&x.r#type, &x.data), &x.note)).collect(),
+            whitelist: whitelists.iter().filter_map(|x| entry(&x.uniqid, AccessRule::parse_whitelist(&x.r#type, &x.data), &x.note)).collect(),
+        }
+    }
+
+    /// Retrieves every blacklist and whitelist of the shop, and creates a policy from them.
+    pub async fn load(client: &Client) -> Result<Self, SellixError> {
+        let blacklists = client.blacklist.list_all().await?;
+        let whitelists = client.whitelist.list_all().await?;
+        Ok(Self::new(&blacklists, &whitelists))
+    }
+
+    /// The blacklist entries which could be parsed.
+    pub fn blacklist(&self) -> &[AccessMatch] {
+        &self.blacklist
+    }
+
+    /// The whitelist entries which could be parsed.
+    pub fn whitelist(&self) -> &[AccessMatch] {
+        &self.whitelist
+    }
+
+    /// Works out whether the customer would be blocked.
+    pub fn check(&self, request: &AccessRequest) -> AccessDecision {
+        let Some(blocked) = self.blacklist.iter().find(|x| x.rule.matches(request)) else {
+            return AccessDecision::Allowed;
+        };
+
+        match self.whitelist.iter().find(|x| x.rule.matches(request)) {
+            Some(whitelisted) => AccessDecision::Whitelisted {
+                blocked: blocked.clone(),
+                whitelisted: whitelisted.clone(),
+            },
+            None => AccessDecision::Blocked(blocked.clone())
+        }
+    }
+
+    /// Whether the customer would be blocked.
+    pub fn is_blocked(&self, request: &AccessRequest) -> bool {
+        self.check(request).is_blocked()
+    }
+}
@@ -1,12 +1,14 @@
 // Dependencies
-use sellix_api_models::{SellixError, SellixHttpCode, RawAPIResponse, feedback::FeedbackReplyPayload, RequestType};
+use sellix_api_models::{SellixError, SellixErrorKind, SellixHttpCode, Route};
 use serde::Serialize;
-use serde_json::{Value, json};
+use serde_json::Value;
 use async_trait::async_trait;
 use sellix_macros::{WithAPIKey, WithDoRequest, DefaultAPI};
-
-// Constants
-const API_BASE: &str = "https://dev.sellix.io/v1";
+use reqwest::header::HeaderMap;
+use crate::audit::{audit, Drift, DriftReport};
+use crate::middleware::Request;
+use crate::resource::{Resource, Gettable, Listable, Creatable, Editable, Deletable};
+use crate::transport::Transport;
 
 /// Ensures that the API key exists within trait.
 pub trait WithAPIKey {
@@ -17,36 +19,56 @@ pub trait WithAPIKey {
 /// Used within API models to add support for sending HTTP requests to the API.
 #[async_trait]
 pub trait DoRequest: WithAPIKey {
-    /// Performs an API request.
+    /// The transport used to send requests.
+    fn transport(&self) -> &Transport;
+
+    /// Performs an API request, passing it through the transport's middleware.
     /// `T` must be a [`RawAPIResponse`].
-    async fn do_request<T: for<'de> serde::Deserialize<'de>, B: Serialize + std::marker::Send>(&self, method: reqwest::Method, path: &str, body: Option<B>) -> Result<T, SellixError> {
-        // Create the client
-        let client = reqwest::Client::new();
-        
-        // Create the request, set url, body, headers...
-        let url = reqwest::Url::parse(&(API_BASE.to_owned() + path)).unwrap();
-        let request = client.request(method, url)
-            .header("Authorization", format!("Bearer {}", self.api_key()))
-            .header("X-Sellix-Merchant", self.merchant().unwrap_or(String::from("")))
-            .json(&body);
+    /// If the transport audits responses, any drift between the response and `T` is reported to its hook,
+    /// including a response which could not be deserialized at all, before the error is returned.
+    async fn do_request<T: Serialize + for<'de> serde::Deserialize<'de>, B: Serialize + std::marker::Send>(&self, route: Route, body: Option<B>) -> Result<T, SellixError> {
+        // Build the request
+        let body = body.map(serde_json::to_value).transpose().map_err(|error| SellixError {
+            kind: SellixHttpCode::BadRequest.into(),
+            message: format!("unable to serialize the request body: {}", error),
+        })?;
+        let request = Request {
+            request_type: route.request_type(),
+            method: route.method(),
+            path: route.path(),
+            merchant: self.merchant(),
+            body,
+            headers: HeaderMap::new(),
+        };
 
-        // Grab the response
-        let response = request.send().await?;
-        let json = response.json::<Value>().await?;
+        // Send it
+        let (request_type, path) = (request.request_type, request.path.clone());
+        let json = self.transport().execute(&self.api_key(), request).await?;
 
-        // Check the status
-        let status = json["status"].as_u64().unwrap();
-        if status == 200 {
-            let json_resp: T = serde_json::from_value(json).unwrap();
-            return Ok(json_resp);
+        // Audit the response, if enabled
+        let invalid = |error: serde_json::Error| SellixError {
+            kind: SellixErrorKind::Decode,
+            message: format!("unable to deserialize the response into {}: {}", std::any::type_name::<T>(), error),
+        };
+        let Some(on_drift) = self.transport().on_drift() else {
+            return serde_json::from_value(json).map_err(invalid);
+        };
+        let (json_resp, drift) = match audit::<T>(&json) {
+            Ok((json_resp, drift)) => (Ok(json_resp), drift),
+            Err(error) => {
+                let drift = Drift::invalid(&error);
+                (Err(invalid(error)), drift)
+            },
+        };
+        if !drift.is_empty() {
+            on_drift(&DriftReport {
+                request_type,
+                path,
+                model: std::any::type_name::<T>(),
+                drift
+            });
         }
-        
-        // Uh oh error
-        let status_code = SellixHttpCode::from_repr(status.try_into().unwrap()).unwrap();
-        Err(SellixError {
-            kind: status_code.clone(),
-            message: status_code.to_string()
-        })
+        json_resp
     }
 }
 
@@ -54,55 +76,41 @@ pub trait DoRequest: WithAPIKey {
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Blacklist {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Allow certain people from accessing your shop.
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Whitelist {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Categories
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Category {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 /// Provide a discount to your products.
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Coupon {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Manage feedback.
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 #[api_methods(create=false,edit=false,delete=false)]
+#[endpoint(name = "reply", method = POST, path = "/feedback/reply/{uniqid}", body = FeedbackReplyPayload<'_>, returns = (), doc = "Replies to a Feedback.")]
 pub struct Feedback {
     pub api_key: String,
-    pub merchant: Option<String>
-}
-impl Feedback {
-    /// Replies to a Feedback.
-    pub async fn reply(&self, uniqid: &str, reply: &str) -> Result<bool, SellixError> {
-        // Used to build the url
-        let (method, path_builder) = RequestType::CouponCreate.request_details();
-        let payload = FeedbackReplyPayload {
-            reply
-        };
-        let path = handlebars::Handlebars::new()
-            .render_template(path_builder, &json!({
-                "uniqid": uniqid
-            }))
-            .expect("unable to parse path");
-
-        // Send it
-        self.do_request::<RawAPIResponse<()>, FeedbackReplyPayload>(method, &path, Some(payload))
-            .await
-            .map(|x| x.status == SellixHttpCode::Ok)
-    }
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Orders
@@ -110,7 +118,8 @@ impl Feedback {
 #[api_methods(create=false,edit=false,delete=false)]
 pub struct Order {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 
@@ -118,21 +127,24 @@ pub struct Order {
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Group {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Customers
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Customer {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Queries
 #[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
 pub struct Query {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
 
 /// Subscriptions
@@ -140,5 +152,6 @@ pub struct Query {
 #[api_methods(edit=false)]
 pub struct Subscription {
     pub api_key: String,
-    pub merchant: Option<String>
+    pub merchant: Option<String>,
+    pub transport: Transport
 }
\ No newline at end of file
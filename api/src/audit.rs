@@ -0,0 +1,26 @@
+// Dependencies
+use std::fmt;
+use std::sync::Arc;
+use sellix_api_models::RequestType;
+pub use sellix_api_models::audit::{audit, Drift};
+
+/// Called with every response that did not match its model, see [`ClientBuilder::on_drift`](crate::ClientBuilder::on_drift).
+pub type DriftHook = Arc<dyn Fn(&DriftReport) + Send + Sync>;
+
+/// A response whose fields drifted from the model it was deserialized into.
+#[derive(Debug, Clone)]
+pub struct DriftReport {
+    /// What the request was for.
+    pub request_type: RequestType,
+    /// The path the request was sent to, relative to the API base.
+    pub path: String,
+    /// The name of the model the response was deserialized into.
+    pub model: &'static str,
+    /// The fields that were unknown or missing.
+    pub drift: Drift,
+}
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {} ({}) - {}", self.request_type, self.path, self.model, self.drift)
+    }
+}
@@ -0,0 +1,324 @@
+//! Backs up every resource of a shop to a single JSON archive, which can be restored to another shop.
+//!
+//! ```no_run
+//! # use std::collections::HashMap;
+//! # use sellix_rs::{Client, backup::Backup};
+//! # async fn example(client: &Client, other: &Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let backup = Backup::create(client).await?;
+//! std::fs::write("backup.json", backup.to_json())?;
+//!
+//! let backup = Backup::from_json(&std::fs::read_to_string("backup.json")?)?;
+//! let report = backup.restore(other, &HashMap::new()).await?;
+//! for item in report.errors() {
+//!     println!("Unable to restore {} {:?}: {:?}", item.kind, item.name, item.outcome);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only groups, categories, coupons, blacklists, whitelists and customers can be restored.
+//! Orders, queries, subscriptions and feedback are made by customers, so are only kept for reference.
+
+// Dependencies
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use sellix_api_models::{ResourceKind, SellixError, Timestamp};
+use sellix_api_models::blacklist::BlacklistRaw;
+use sellix_api_models::category::CategoryRaw;
+use sellix_api_models::coupon::CouponRaw;
+use sellix_api_models::customer::{CustomerCreatePayload, CustomerCreatePayloadBuilder, CustomerRaw};
+use sellix_api_models::feedback::FeedbackRaw;
+use sellix_api_models::group::GroupRaw;
+use sellix_api_models::order::OrderRaw;
+use sellix_api_models::query::QueryRaw;
+use sellix_api_models::subscription::SubscriptionRaw;
+use sellix_api_models::validation::ValidationError;
+use sellix_api_models::whitelist::WhitelistRaw;
+use crate::bulk::{key, Entries};
+use crate::client::Client;
+use crate::resource::{Creatable, Listable};
+use crate::sync::{CategoryConfig, CouponConfig, GroupConfig};
+
+// Constants
+/// The version of the archive format written by [`Backup::to_json`].
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Every resource of a shop, as returned by the API.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Backup {
+    /// The version of the archive format, see [`BACKUP_VERSION`].
+    pub version: u32,
+    /// When the backup was made.
+    pub created_at: Timestamp,
+    /// The merchant the backup was made of, if one was given.
+    pub merchant: Option<String>,
+    pub orders: Vec<OrderRaw>,
+    pub customers: Vec<CustomerRaw>,
+    pub coupons: Vec<CouponRaw>,
+    pub categories: Vec<CategoryRaw>,
+    pub groups: Vec<GroupRaw>,
+    pub blacklists: Vec<BlacklistRaw>,
+    pub whitelists: Vec<WhitelistRaw>,
+    pub queries: Vec<QueryRaw>,
+    pub subscriptions: Vec<SubscriptionRaw>,
+    pub feedback: Vec<FeedbackRaw>,
+}
+impl Backup {
+    /// Retrieves every page of every resource of the client's shop.
+    pub async fn create(client: &Client) -> Result<Self, SellixError> {
+        Ok(Self {
+            version: BACKUP_VERSION,
+            created_at: Timestamp::now(),
+            merchant: client.merchant().map(|x| x.to_owned()),
+            orders: client.order.list_all().await?,
+            customers: client.customer.list_all().await?,
+            coupons: client.coupon.list_all().await?,
+            categories: client.category.list_all().await?,
+            groups: client.group.list_all().await?,
+            blacklists: client.blacklist.list_all().await?,
+            whitelists: client.whitelist.list_all().await?,
+            queries: client.query.list_all().await?,
+            subscriptions: client.subscription.list_all().await?,
+            feedback: client.feedback.list_all().await?,
+        })
+    }
+
+    /// Writes the archive.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("every model can be serialized")
+    }
+
+    /// Reads an archive, failing if it was written by a newer version of the format.
+    pub fn from_json(value: &str) -> Result<Self, serde_json::Error> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+
+        let header: Header = serde_json::from_str(value)?;
+        if header.version > BACKUP_VERSION {
+            return Err(serde_json::Error::custom(format!("backup version {} is newer than the supported version {}", header.version, BACKUP_VERSION)));
+        }
+        serde_json::from_str(value)
+    }
+
+    /// Recreates every restorable resource on the client's shop, in the order groups, categories, coupons, blacklists, whitelists and customers.
+    ///
+    /// Products cannot be backed up, so `products` maps the uniqid of each product in the backup to the uniqid of the same product on the shop.
+    /// Any product left out keeps its uniqid, so an empty map restores a shop to itself.
+    /// Groups are remapped to the ones restored, so categories contain the same groups as before.
+    /// A category is still created without any group which could not be restored, see [`RestoreOutcome::Incomplete`].
+    ///
+    /// A resource which already exists, going by its title, code, type and data, or email, is not created again.
+    /// Only fails if the shop's existing resources cannot be retrieved, otherwise each resource reports its own outcome.
+    pub async fn restore(&self, client: &Client, products: &HashMap<String, String>) -> Result<RestoreReport, SellixError> {
+        let mut report = RestoreReport::default();
+        let products = |uniqids: &[String]| -> Vec<String> {
+            uniqids.iter().map(|x| products.get(x).unwrap_or(x).clone()).collect()
+        };
+
+        // Groups, which categories refer to
+        let live: HashMap<String, String> = client.group.list_all().await?
+            .into_iter()
+            .map(|x| (x.title, x.uniqid))
+            .collect();
+        let mut groups = HashMap::new();
+        for raw in &self.groups {
+            let outcome = match live.get(&raw.title) {
+                Some(uniqid) => RestoreOutcome::Existing(uniqid.clone()),
+                None => {
+                    let mut config = GroupConfig::from(raw);
+                    config.products = products(&config.products);
+                    create(&client.group, config.create_payload()).await
+                }
+            };
+            if let Some(uniqid) = outcome.uniqid() {
+                groups.insert(raw.uniqid.clone(), uniqid.to_owned());
+            }
+            report.push(ResourceKind::Group, &raw.uniqid, &raw.title, outcome);
+        }
+
+        // Categories
+        let live: HashMap<String, String> = client.category.list_all().await?
+            .into_iter()
+            .map(|x| (x.title, x.uniqid))
+            .collect();
+        for raw in &self.categories {
+            let outcome = match live.get(&raw.title) {
+                Some(uniqid) => RestoreOutcome::Existing(uniqid.clone()),
+                None => {
+                    let mut config = CategoryConfig::from(raw);
+                    config.products = products(&config.products);
+                    let (restored, missing): (Vec<_>, Vec<_>) = raw.groups_bound.iter()
+                        .partition(|x| groups.contains_key(&x.uniqid));
+                    let uniqids: Vec<&str> = restored.iter().map(|x| groups[&x.uniqid].as_str()).collect();
+                    match create(&client.category, config.create_payload(&uniqids)).await {
+                        RestoreOutcome::Created(uniqid) if !missing.is_empty() => RestoreOutcome::Incomplete {
+                            uniqid,
+                            missing_groups: missing.iter().map(|x| x.uniqid.clone()).collect(),
+                        },
+                        outcome => outcome
+                    }
+                }
+            };
+            report.push(ResourceKind::Category, &raw.uniqid, &raw.title, outcome);
+        }
+
+        // Coupons
+        let live: HashMap<String, String> = client.coupon.list_all().await?
+            .into_iter()
+            .map(|x| (x.code, x.uniqid))
+            .collect();
+        for raw in &self.coupons {
+            let outcome = match live.get(&raw.code) {
+                Some(uniqid) => RestoreOutcome::Existing(uniqid.clone()),
+                None => {
+                    let mut config = CouponConfig::from(raw);
+                    config.products = products(&config.products);
+                    create(&client.coupon, config.create_payload()).await
+                }
+            };
+            report.push(ResourceKind::Coupon, &raw.uniqid, &raw.code, outcome);
+        }
+
+        // Blacklists and whitelists
+        restore_entries(&mut report, ResourceKind::Blacklist, &client.blacklist, &self.blacklists).await?;
+        restore_entries(&mut report, ResourceKind::Whitelist, &client.whitelist, &self.whitelists).await?;
+
+        // Customers
+        let live: HashMap<String, String> = client.customer.list_all().await?
+            .into_iter()
+            .map(|x| (x.email.to_lowercase(), x.id))
+            .collect();
+        for raw in &self.customers {
+            let outcome = match live.get(&raw.email.to_lowercase()) {
+                Some(id) => RestoreOutcome::Existing(id.clone()),
+                None => create(&client.customer, customer_payload(raw)).await
+            };
+            report.push(ResourceKind::Customer, &raw.id, &raw.email, outcome);
+        }
+
+        Ok(report)
+    }
+}
+
+/// Creates a resource, unless its payload is invalid.
+async fn create<R: Creatable>(resource: &R, payload: Result<R::CreatePayload, ValidationError>) -> RestoreOutcome {
+    let created = match payload {
+        Ok(payload) => resource.create(payload).await,
+        Err(error) => Err(error.into())
+    };
+    match created {
+        Ok(created) => RestoreOutcome::Created(created.uniqid),
+        Err(error) => RestoreOutcome::Failed(error)
+    }
+}
+
+/// Restores blacklists or whitelists, which are identified by their type and data.
+async fn restore_entries<R: Entries>(report: &mut RestoreReport, kind: ResourceKind, resource: &R, backup: &[R::Raw]) -> Result<(), SellixError> {
+    let live: HashMap<(String, String), String> = resource.list_all().await?
+        .iter()
+        .map(|x| {
+            let [uniqid, r#type, data, _] = R::entry(x);
+            (key(r#type, data), uniqid.to_owned())
+        })
+        .collect();
+
+    for raw in backup {
+        let [uniqid, r#type, data, note] = R::entry(raw);
+        let outcome = match live.get(&key(r#type, data)) {
+            Some(uniqid) => RestoreOutcome::Existing(uniqid.clone()),
+            None => create(resource, R::payload(r#type, data, Some(note).filter(|x| !x.is_empty()))).await
+        };
+        report.push(kind, uniqid, &format!("{} {}", r#type, data), outcome);
+    }
+    Ok(())
+}
+
+/// Builds the payload to recreate a customer, where empty fields were never set.
+fn customer_payload(raw: &CustomerRaw) -> Result<CustomerCreatePayload, ValidationError> {
+    type Setter = fn(CustomerCreatePayloadBuilder, &str) -> CustomerCreatePayloadBuilder;
+    let fields: [(&str, Setter); 8] = [
+        (&raw.country_code, CustomerCreatePayloadBuilder::country_code),
+        (&raw.phone, CustomerCreatePayloadBuilder::phone),
+        (&raw.phone_country_code, CustomerCreatePayloadBuilder::phone_country_code),
+        (&raw.address, CustomerCreatePayloadBuilder::address),
+        (&raw.additional_address_info, CustomerCreatePayloadBuilder::additional_address_info),
+        (&raw.city, CustomerCreatePayloadBuilder::city),
+        (&raw.postal_code, CustomerCreatePayloadBuilder::postal_code),
+        (&raw.state, CustomerCreatePayloadBuilder::state),
+    ];
+
+    let mut builder = CustomerCreatePayload::builder(&raw.name, &raw.surname, &raw.email);
+    for (value, set) in fields {
+        if !value.is_empty() {
+            builder = set(builder, value);
+        }
+    }
+    builder.build()
+}
+
+/// What happened to a single resource of a restore.
+#[derive(Debug)]
+pub enum RestoreOutcome {
+    /// The resource was created, with the given uniqid.
+    Created(String),
+    /// The resource already exists, with the given uniqid.
+    Existing(String),
+    /// The resource was created, with the given uniqid, but without some of the groups it contained,
+    /// which could not be restored, by their uniqids within the backup.
+    Incomplete { uniqid: String, missing_groups: Vec<String> },
+    /// The resource could not be created.
+    Failed(SellixError),
+}
+impl RestoreOutcome {
+    /// The uniqid of the resource on the restored shop, unless it could not be created.
+    pub fn uniqid(&self) -> Option<&str> {
+        match self {
+            Self::Created(uniqid) | Self::Existing(uniqid) | Self::Incomplete { uniqid, .. } => Some(uniqid),
+            Self::Failed(_) => None
+        }
+    }
+}
+
+/// A single resource of a restore.
+#[derive(Debug)]
+pub struct RestoredItem {
+    pub kind: ResourceKind,
+    /// The uniqid it had within the backup.
+    pub uniqid: String,
+    /// What identifies it, e.g. the title of a category or the code of a coupon.
+    pub name: String,
+    pub outcome: RestoreOutcome,
+}
+
+/// Every resource of a restore, in the order they were restored.
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    pub items: Vec<RestoredItem>,
+}
+impl RestoreReport {
+    fn push(&mut self, kind: ResourceKind, uniqid: &str, name: &str, outcome: RestoreOutcome) {
+        self.items.push(RestoredItem {
+            kind,
+            uniqid: uniqid.to_owned(),
+            name: name.to_owned(),
+            outcome,
+        });
+    }
+
+    /// The uniqid a resource of the backup has on the restored shop, unless it could not be created.
+    pub fn uniqid(&self, kind: ResourceKind, uniqid: &str) -> Option<&str> {
+        self.items.iter()
+            .find(|x| x.kind == kind && x.uniqid == uniqid)
+            .and_then(|x| x.outcome.uniqid())
+    }
+
+    /// The resources which could not be created, or were created without everything they contained.
+    pub fn errors(&self) -> Vec<&RestoredItem> {
+        self.items.iter()
+            .filter(|x| matches!(x.outcome, RestoreOutcome::Failed(_) | RestoreOutcome::Incomplete { .. }))
+            .collect()
+    }
+}
@@ -0,0 +1,315 @@
+// Dependencies
+use std::collections::HashMap;
+use futures::stream::{self, StreamExt};
+use sellix_api_models::SellixError;
+use sellix_api_models::blacklist::{BlacklistCreatePayload, BlacklistRaw, BlacklistTypes};
+use sellix_api_models::validation::ValidationError;
+use sellix_api_models::whitelist::{WhitelistCreatePayload, WhitelistRaw, WhitelistTypes};
+use crate::api::{Blacklist, Whitelist};
+use crate::resource::{Creatable, Listable};
+
+// Constants
+/// How many entries are created at once, unless given.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+/// The columns of every CSV file.
+const HEADER: [&str; 3] = ["type", "data", "note"];
+
+/// What happened to a single row of an import.
+#[derive(Debug)]
+pub enum ImportOutcome {
+    /// The entry was created, with the given uniqid.
+    Created(String),
+    /// An entry with the same type and data already exists, with the given uniqid.
+    Duplicate(String),
+    /// An earlier row of the same file has the same type and data, on the given line.
+    DuplicateRow(usize),
+    /// The row could not be turned into a payload.
+    Invalid(ValidationError),
+    /// The API refused to create the entry.
+    Failed(SellixError),
+}
+
+/// A single row of an import.
+#[derive(Debug)]
+pub struct ImportRow {
+    /// The line of the file the row starts on, counting from 1.
+    pub line: usize,
+    /// The type, as written.
+    pub r#type: String,
+    /// The data, as written.
+    pub data: String,
+    /// The note, if one was given.
+    pub note: Option<String>,
+    pub outcome: ImportOutcome,
+}
+
+/// Every row of an import, in the order of the file.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub rows: Vec<ImportRow>,
+}
+impl ImportReport {
+    /// The uniqids of every entry created.
+    pub fn created(&self) -> Vec<&str> {
+        self.rows.iter()
+            .filter_map(|x| match &x.outcome {
+                ImportOutcome::Created(uniqid) => Some(uniqid.as_str()),
+                _ => None
+            })
+            .collect()
+    }
+
+    /// The rows which were skipped as duplicates, of an existing entry or an earlier row.
+    pub fn duplicates(&self) -> Vec<&ImportRow> {
+        self.rows.iter()
+            .filter(|x| matches!(x.outcome, ImportOutcome::Duplicate(_) | ImportOutcome::DuplicateRow(_)))
+            .collect()
+    }
+
+    /// The rows which were invalid, or could not be created.
+    pub fn errors(&self) -> Vec<&ImportRow> {
+        self.rows.iter()
+            .filter(|x| matches!(x.outcome, ImportOutcome::Invalid(_) | ImportOutcome::Failed(_)))
+            .collect()
+    }
+}
+
+/// A sub-API whose entries are a type, data and note, such as blacklists and whitelists.
+pub(crate) trait Entries: Listable + Creatable {
+    /// The uniqid, type, data and note of an entry.
+    fn entry(raw: &Self::Raw) -> [&str; 4];
+
+    /// Validates a row, then builds the payload to create it.
+    fn payload(r#type: &str, data: &str, note: Option<&str>) -> Result<Self::CreatePayload, ValidationError>;
+}
+impl Entries for Blacklist {
+    fn entry(raw: &BlacklistRaw) -> [&str; 4] {
+        [&raw.uniqid, raw.r#type.as_str(), &raw.data, &raw.note]
+    }
+
+    fn payload(r#type: &str, data: &str, note: Option<&str>) -> Result<BlacklistCreatePayload, ValidationError> {
+        let r#type = BlacklistTypes::from(r#type.to_owned());
+        if r#type.is_unknown() {
+            return Err(ValidationError::new("type", "not a known blacklist type"));
+        }
+
+        let builder = BlacklistCreatePayload::builder(r#type, data);
+        match note {
+            Some(note) => builder.note(note).build(),
+            None => builder.build()
+        }
+    }
+}
+impl Entries for Whitelist {
+    fn entry(raw: &WhitelistRaw) -> [&str; 4] {
+        [&raw.uniqid, raw.r#type.as_str(), &raw.data, &raw.note]
+    }
+
+    fn payload(r#type: &str, data: &str, note: Option<&str>) -> Result<WhitelistCreatePayload, ValidationError> {
+        let r#type = WhitelistTypes::from(r#type.to_owned());
+        if r#type.is_unknown() {
+            return Err(ValidationError::new("type", "not a known whitelist type"));
+        }
+
+        let builder = WhitelistCreatePayload::builder(r#type, data);
+        match note {
+            Some(note) => builder.note(note).build(),
+            None => builder.build()
+        }
+    }
+}
+
+/// Where an entry has already been seen, when importing.
+enum Seen {
+    /// An existing entry, with the given uniqid.
+    Existing(String),
+    /// An earlier row, on the given line.
+    Row(usize),
+}
+
+/// A row of an import, whose outcome is unknown until its entry has been created.
+struct PendingRow {
+    line: usize,
+    r#type: String,
+    data: String,
+    note: Option<String>,
+    outcome: Option<ImportOutcome>,
+}
+
+/// Identifies an entry regardless of case or surrounding whitespace, e.g. `EMAIL` and `name@example.com`.
+pub(crate) fn key(r#type: &str, data: &str) -> (String, String) {
+    (r#type.trim().to_uppercase(), data.trim().to_lowercase())
+}
+
+/// A row of the file, which may not be valid.
+struct ParsedRow {
+    line: usize,
+    fields: Result<[String; 3], ValidationError>,
+}
+
+/// The line a record starts on, counting from 1.
+/// Its position can be within the empty lines before it, which are skipped.
+fn line(csv: &str, position: &csv::Position) -> usize {
+    let start = position.byte() as usize;
+    let skipped = csv[start..].len() - csv[start..].trim_start_matches(['\r', '\n']).len();
+    1 + csv[..start + skipped].matches('\n').count()
+}
+
+/// Parses every row of the file, skipping the header if there is one, and empty lines.
+fn parse_rows(csv: &str) -> Vec<ParsedRow> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+
+    let mut rows = Vec::new();
+    for (i, record) in reader.records().enumerate() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                let line = error.position().map(|x| line(csv, x)).unwrap_or(i + 1);
+                rows.push(ParsedRow {
+                    line,
+                    fields: Err(ValidationError::new("row", &error.to_string())),
+                });
+                continue;
+            }
+        };
+        if (i == 0 && record.get(0).is_some_and(|x| x.eq_ignore_ascii_case(HEADER[0]))) || record.iter().all(str::is_empty) {
+            continue;
+        }
+
+        let line = record.position().map(|x| line(csv, x)).unwrap_or(i + 1);
+        let field = |x: usize| record.get(x).unwrap_or_default().to_owned();
+        let fields = if record.len() > HEADER.len() {
+            Err(ValidationError::new("row", "must only have type, data and note columns"))
+        } else {
+            Ok([field(0).to_uppercase(), field(1), field(2)])
+        };
+        rows.push(ParsedRow { line, fields });
+    }
+    rows
+}
+
+/// Imports every row which is valid, and not a duplicate, creating up to `concurrency` entries at once.
+async fn import<R: Entries>(resource: &R, csv: &str, concurrency: usize) -> Result<ImportReport, SellixError> {
+    // Every entry which already exists
+    let mut seen: HashMap<(String, String), Seen> = resource.list_all().await?
+        .iter()
+        .map(|x| {
+            let [uniqid, r#type, data, _] = R::entry(x);
+            (key(r#type, data), Seen::Existing(uniqid.to_owned()))
+        })
+        .collect();
+
+    // Work out which rows to create
+    let mut rows = Vec::new();
+    let mut payloads = Vec::new();
+    for row in parse_rows(csv) {
+        let [r#type, data, note] = match row.fields {
+            Ok(fields) => fields,
+            Err(error) => {
+                rows.push(PendingRow {
+                    line: row.line,
+                    r#type: String::new(),
+                    data: String::new(),
+                    note: None,
+                    outcome: Some(ImportOutcome::Invalid(error)),
+                });
+                continue;
+            }
+        };
+        let note = Some(note).filter(|x| !x.is_empty());
+
+        let outcome = match seen.get(&key(&r#type, &data)) {
+            Some(Seen::Existing(uniqid)) => Some(ImportOutcome::Duplicate(uniqid.clone())),
+            Some(Seen::Row(line)) => Some(ImportOutcome::DuplicateRow(*line)),
+            None => match R::payload(&r#type, &data, note.as_deref()) {
+                Ok(payload) => {
+                    seen.insert(key(&r#type, &data), Seen::Row(row.line));
+                    payloads.push((rows.len(), payload));
+                    // Known once it has been created
+                    None
+                },
+                Err(error) => Some(ImportOutcome::Invalid(error))
+            }
+        };
+        rows.push(PendingRow {
+            line: row.line,
+            r#type,
+            data,
+            note,
+            outcome,
+        });
+    }
+
+    // Create them, a few at a time
+    let results: Vec<_> = stream::iter(payloads)
+        .map(|(i, payload)| async move { (i, resource.create(payload).await) })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+    for (i, result) in results {
+        rows[i].outcome = Some(match result {
+            Ok(created) => ImportOutcome::Created(created.uniqid),
+            Err(error) => ImportOutcome::Failed(error)
+        });
+    }
+
+    let rows = rows.into_iter()
+        .map(|x| ImportRow {
+            line: x.line,
+            r#type: x.r#type,
+            data: x.data,
+            note: x.note,
+            outcome: x.outcome.expect("every row to create has been created, or failed to be"),
+        })
+        .collect();
+    Ok(ImportReport { rows })
+}
+
+/// Writes every entry, with a header.
+async fn export<R: Entries>(resource: &R) -> Result<String, SellixError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(HEADER).expect("unable to write to memory");
+    for raw in resource.list_all().await? {
+        let [_, r#type, data, note] = R::entry(&raw);
+        writer.write_record([r#type, data, note]).expect("unable to write to memory");
+    }
+
+    let bytes = writer.into_inner().expect("unable to write to memory");
+    Ok(String::from_utf8(bytes).expect("every field is valid UTF-8"))
+}
+
+impl Blacklist {
+    /// Creates a blacklist for every row of a CSV file, with `type`, `data` and an optional `note` column.
+    ///
+    /// Rows are skipped if they are invalid, or have the same type and data as an existing blacklist or an earlier row.
+    /// Up to `concurrency` blacklists are created at once, see [`DEFAULT_CONCURRENCY`].
+    /// Only fails if the existing blacklists cannot be retrieved, otherwise each row reports its own outcome.
+    pub async fn import_csv(&self, csv: &str, concurrency: usize) -> Result<ImportReport, SellixError> {
+        import(self, csv, concurrency).await
+    }
+
+    /// Writes every blacklist to a CSV file, which can be imported by [`import_csv`](Self::import_csv).
+    pub async fn export_csv(&self) -> Result<String, SellixError> {
+        export(self).await
+    }
+}
+
+impl Whitelist {
+    /// Creates a whitelist for every row of a CSV file, with `type`, `data` and an optional `note` column.
+    ///
+    /// Rows are skipped if they are invalid, or have the same type and data as an existing whitelist or an earlier row.
+    /// Up to `concurrency` whitelists are created at once, see [`DEFAULT_CONCURRENCY`].
+    /// Only fails if the existing whitelists cannot be retrieved, otherwise each row reports its own outcome.
+    pub async fn import_csv(&self, csv: &str, concurrency: usize) -> Result<ImportReport, SellixError> {
+        import(self, csv, concurrency).await
+    }
+
+    /// Writes every whitelist to a CSV file, which can be imported by [`import_csv`](Self::import_csv).
+    pub async fn export_csv(&self) -> Result<String, SellixError> {
+        export(self).await
+    }
+}
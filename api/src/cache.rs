@@ -0,0 +1,178 @@
+// Dependencies
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use sellix_api_models::{ResourceKind, SellixError};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use crate::middleware::{Middleware, Next, Request};
+
+/// Somewhere to store cached responses.
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Returns the cached response, if it exists and has not expired.
+    async fn get(&self, key: &str) -> Option<Value>;
+    /// Stores a response for `ttl`.
+    async fn insert(&self, key: String, value: Value, ttl: Duration);
+    /// Removes every response whose key starts with `prefix`.
+    async fn invalidate_prefix(&self, prefix: &str);
+}
+
+/// An entry within [`MemoryLru`].
+struct LruEntry {
+    value: Value,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+/// The state of [`MemoryLru`].
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, LruEntry>,
+    tick: u64,
+}
+
+/// An in-memory cache, evicting the least recently used entry once full.
+pub struct MemoryLru {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+impl MemoryLru {
+    /// Creates a cache that holds up to `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// The number of responses currently held, including expired ones.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache holds no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+#[async_trait]
+impl CacheBackend for MemoryLru {
+    async fn get(&self, key: &str) -> Option<Value> {
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+
+        // Drop it if it has expired
+        let entry = state.entries.get_mut(key)?;
+        if entry.expires_at <= Instant::now() {
+            state.entries.remove(key);
+            return None;
+        }
+
+        entry.last_used = tick;
+        Some(entry.value.clone())
+    }
+
+    async fn insert(&self, key: String, value: Value, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+
+        // Make room by evicting the least recently used entry
+        if state.entries.len() >= self.capacity && !state.entries.contains_key(&key) {
+            let oldest = state.entries.iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(key, LruEntry {
+            value,
+            expires_at: Instant::now() + ttl,
+            last_used: tick,
+        });
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) {
+        self.state.lock().unwrap().entries.retain(|key, _| !key.starts_with(prefix));
+    }
+}
+
+/// A read-through cache for the get and list requests.
+///
+/// Any other request to a resource (create, edit, delete...) invalidates every cached response for that resource, merchant and API key.
+/// Add it as a middleware; the cache is shared by everything using the same transport,
+/// but responses are kept apart for each API key, as keys can see different shops.
+pub struct Cache<B: CacheBackend> {
+    backend: B,
+    default_ttl: Duration,
+    ttls: HashMap<ResourceKind, Duration>,
+}
+impl<B: CacheBackend> Cache<B> {
+    /// Creates a cache, keeping responses for 60 seconds by default.
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            default_ttl: Duration::from_secs(60),
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Sets how long responses are kept for, unless overridden for a resource.
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Sets how long responses for a resource are kept for.
+    pub fn ttl(mut self, resource: ResourceKind, ttl: Duration) -> Self {
+        self.ttls.insert(resource, ttl);
+        self
+    }
+
+    /// The backend the responses are stored within.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// The key prefix shared by every response of a resource for an API key and merchant.
+    /// Only a hash of the API key is kept, so it is not stored within the backend.
+    fn prefix(api_key: &str, merchant: Option<&str>, resource: ResourceKind) -> String {
+        let api_key = hex::encode(&Sha256::digest(api_key.as_bytes())[..8]);
+        format!("{}:{}:{}:", api_key, merchant.unwrap_or(""), resource)
+    }
+}
+#[async_trait]
+impl<B: CacheBackend> Middleware for Cache<B> {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Value, SellixError> {
+        let resource = request.request_type.resource();
+        let prefix = Self::prefix(next.api_key, request.merchant.as_deref(), resource);
+
+        // Writes go straight through, and invalidate the resource
+        if !request.request_type.action().is_read() {
+            let response = next.run(request).await;
+            self.backend.invalidate_prefix(&prefix).await;
+            return response;
+        }
+
+        // Attempt to serve it from the cache
+        let key = prefix + &request.path;
+        if let Some(value) = self.backend.get(&key).await {
+            return Ok(value);
+        }
+
+        // Send it, caching the response if it succeeded
+        let value = next.run(request).await?;
+        let ttl = self.ttls.get(&resource).copied().unwrap_or(self.default_ttl);
+        self.backend.insert(key, value.clone(), ttl).await;
+        Ok(value)
+    }
+}
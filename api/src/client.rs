@@ -1,5 +1,9 @@
 // Dependencies
-use crate::api::{Blacklist, Whitelist, Category, Coupon, Feedback, Customer, Subscription, Group, Order};
+use crate::api::{Blacklist, Whitelist, Category, Coupon, Feedback, Customer, Subscription, Group, Order, Query};
+use std::sync::Arc;
+use crate::audit::{DriftHook, DriftReport};
+use crate::middleware::Middleware;
+use crate::transport::Transport;
 
 /// Contains each "sub-API" within one struct.
 pub struct Client {
@@ -11,21 +15,133 @@ pub struct Client {
     pub order: Order,
     pub group: Group,
     pub customer: Customer,
-    pub subscription: Subscription
+    pub subscription: Subscription,
+    pub query: Query,
+    api_key: String,
+    merchant: Option<String>,
+    transport: Transport
 }
 impl Client {
     /// Create an instance of the struct.
     pub fn new(api_key: &str, merchant: Option<&str>) -> Self {
+        Self::with_transport(api_key, merchant, Transport::default())
+    }
+
+    /// Create an instance of the struct that sends requests through an existing transport.
+    pub fn with_transport(api_key: &str, merchant: Option<&str>, transport: Transport) -> Self {
         Self {
-            blacklist: Blacklist::client( api_key, merchant ),
-            whitelist: Whitelist::client( api_key, merchant ),
-            category: Category::client( api_key, merchant ),
-            coupon: Coupon::client( api_key, merchant ),
-            feedback: Feedback::client( api_key, merchant ),
-            order: Order::client( api_key, merchant ),
-            group: Group::client( api_key, merchant ),
-            customer: Customer::client( api_key, merchant ),
-            subscription: Subscription::client( api_key, merchant ),
+            blacklist: Blacklist::with_transport( api_key, merchant, transport.clone() ),
+            whitelist: Whitelist::with_transport( api_key, merchant, transport.clone() ),
+            category: Category::with_transport( api_key, merchant, transport.clone() ),
+            coupon: Coupon::with_transport( api_key, merchant, transport.clone() ),
+            feedback: Feedback::with_transport( api_key, merchant, transport.clone() ),
+            order: Order::with_transport( api_key, merchant, transport.clone() ),
+            group: Group::with_transport( api_key, merchant, transport.clone() ),
+            customer: Customer::with_transport( api_key, merchant, transport.clone() ),
+            subscription: Subscription::with_transport( api_key, merchant, transport.clone() ),
+            query: Query::with_transport( api_key, merchant, transport.clone() ),
+            api_key: api_key.to_owned(),
+            merchant: merchant.map(|x| x.to_owned()),
+            transport
         }
     }
+
+    /// Initialises a builder, used to configure the transport and middleware.
+    pub fn builder(api_key: &str) -> ClientBuilder {
+        ClientBuilder::new(api_key)
+    }
+
+    /// Creates a client for another merchant on the same account.
+    /// The new client shares this client's transport.
+    pub fn for_merchant(&self, merchant: &str) -> Self {
+        Self::with_transport(&self.api_key, Some(merchant), self.transport.clone())
+    }
+
+    /// The merchant this client sends requests on behalf of.
+    pub fn merchant(&self) -> Option<&str> {
+        self.merchant.as_deref()
+    }
+
+    /// The transport shared by every sub-API of this client.
+    pub fn transport(&self) -> &Transport {
+        &self.transport
+    }
+}
+
+
+/// Builds a [`Client`].
+/// 
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use sellix_rs::{Client, middleware::{ReadOnly, RequestCounter}};
+/// let counter = Arc::new(RequestCounter::default());
+/// let client = Client::builder("api_key")
+///     .merchant("my_shop")
+///     .middleware(counter.clone())
+///     .middleware(Arc::new(ReadOnly))
+///     .build();
+/// ```
+pub struct ClientBuilder {
+    api_key: String,
+    merchant: Option<String>,
+    http: Option<reqwest::Client>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    on_drift: Option<DriftHook>
+}
+impl ClientBuilder {
+    /// Initialises a builder.
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            api_key: api_key.to_owned(),
+            merchant: None,
+            http: None,
+            middleware: Vec::new(),
+            on_drift: None
+        }
+    }
+
+    /// Sets the merchant.
+    pub fn merchant(mut self, merchant: &str) -> Self {
+        self.merchant = Some(merchant.to_owned());
+        self
+    }
+
+    /// Sets the HTTP client to send requests with.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Adds a middleware layer.
+    /// Layers are applied in the order they are added, the first being the outermost.
+    pub fn middleware(mut self, layer: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(layer);
+        self
+    }
+
+    /// Audits every response against the model it is deserialized into,
+    /// calling the hook whenever the API sent fields the model does not declare, or left out fields it does.
+    ///
+    /// ```no_run
+    /// # use sellix_rs::Client;
+    /// let client = Client::builder("api_key")
+    ///     .on_drift(|report| eprintln!("schema drift: {}", report))
+    ///     .build();
+    /// ```
+    pub fn on_drift(mut self, hook: impl Fn(&DriftReport) + Send + Sync + 'static) -> Self {
+        self.on_drift = Some(Arc::new(hook));
+        self
+    }
+
+    /// Builds the transport alone, e.g. to share with a [`MerchantPool`](crate::MerchantPool).
+    pub fn build_transport(self) -> Transport {
+        Transport::from_parts(self.http.unwrap_or_default(), self.middleware, self.on_drift)
+    }
+
+    /// Builds the client.
+    pub fn build(self) -> Client {
+        let api_key = self.api_key.clone();
+        let merchant = self.merchant.clone();
+        Client::with_transport(&api_key, merchant.as_deref(), self.build_transport())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+// Dependencies
+use std::collections::BTreeMap;
+use sellix_api_models::group::GroupRaw;
+use sellix_api_models::payment::PaymentGateway;
+use sellix_api_models::product::ProductRaw;
+use sellix_api_models::validation::{self, ValidationError};
+
+// Constants
+/// The script which turns buttons into embedded checkouts.
+pub const EMBED_SCRIPT: &str = "https://cdn.sellix.io/static/js/embed.js";
+/// The stylesheet used by embedded checkouts.
+pub const EMBED_STYLESHEET: &str = "https://cdn.sellix.io/static/css/embed.css";
+
+/// The theme of an embedded checkout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EmbedTheme {
+    Light,
+    Dark,
+}
+impl EmbedTheme {
+    /// The value of the `data-sellix-theme` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+/// Generates the HTML for a button which opens an embedded checkout, for a product or a group.
+///
+/// ```
+/// # use sellix_rs::embed::{EmbedButton, EmbedTheme};
+/// let button = EmbedButton::product("demobde8a50")
+///     .label("Buy \"Pro\" now")
+///     .quantity(2)
+///     .theme(EmbedTheme::Dark)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(button, r#"<button type="submit" alt="Buy Now with sellix.io" data-sellix-product="demobde8a50" data-sellix-quantity="2" data-sellix-theme="dark">Buy &quot;Pro&quot; now</button>"#);
+/// ```
+///
+/// Every value is HTML-escaped, and custom fields are added in alphabetical order, after the others.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmbedButton {
+    /// The attribute the uniqid is given by, e.g. `data-sellix-product`.
+    kind: &'static str,
+    uniqid: String,
+    /// The text of the button.
+    label: String,
+    /// Implement any more custom fields.
+    pub custom_fields: BTreeMap<String, String>,
+    /// Specifies the number of the same product to purchase.
+    quantity: Option<u64>,
+    /// Specifies which payment gateway should be selected.
+    gateway: Option<PaymentGateway>,
+    /// The code of a coupon that should be applied automatically to the purchase.
+    coupon_code: Option<String>,
+    /// Specifies the theme of the checkout.
+    theme: Option<EmbedTheme>,
+}
+impl EmbedButton {
+    /// The text of a button, unless set.
+    const DEFAULT_LABEL: &'static str = "Purchase";
+
+    /// Initialises a button for a product.
+    pub fn product(uniqid: &str) -> Self {
+        Self::new("data-sellix-product", uniqid)
+    }
+
+    /// Initialises a button for a group.
+    pub fn group(uniqid: &str) -> Self {
+        Self::new("data-sellix-group", uniqid)
+    }
+
+    fn new(kind: &'static str, uniqid: &str) -> Self {
+        Self {
+            kind,
+            uniqid: uniqid.to_owned(),
+            label: Self::DEFAULT_LABEL.to_owned(),
+            custom_fields: BTreeMap::default(),
+            quantity: None,
+            gateway: None,
+            coupon_code: None,
+            theme: None,
+        }
+    }
+
+    /// Sets the text of the button.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_owned();
+        self
+    }
+
+    /// Sets the quantity.
+    pub fn quantity(mut self, quantity: u64) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    /// Sets the gateway.
+    pub fn gateway(mut self, gateway: PaymentGateway) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    /// Sets the coupon code.
+    pub fn coupon_code(mut self, coupon_code: &str) -> Self {
+        self.coupon_code = Some(coupon_code.to_owned());
+        self
+    }
+
+    /// Sets the theme.
+    pub fn theme(mut self, theme: EmbedTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Add a custom field, given by a `data-sellix-custom-{key}` attribute.
+    pub fn add_custom_field(mut self, key: &str, value: &str) -> Self {
+        self.custom_fields.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Builds the `<button>` element.
+    ///
+    /// Fails if the uniqid is empty, or a custom field's name cannot be used within an attribute name.
+    pub fn build(&self) -> Result<String, ValidationError> {
+        validation::non_empty("uniqid", &self.uniqid)?;
+
+        // Add the target, then all optional fields
+        let mut attributes = vec![
+            ("type".to_owned(), "submit".to_owned()),
+            ("alt".to_owned(), "Buy Now with sellix.io".to_owned()),
+            (self.kind.to_owned(), self.uniqid.clone()),
+        ];
+        if let Some(quantity) = self.quantity {
+            attributes.push(("data-sellix-quantity".to_owned(), quantity.to_string()));
+        }
+        if let Some(gateway) = &self.gateway {
+            attributes.push(("data-sellix-gateway".to_owned(), gateway.to_string()));
+        }
+        if let Some(coupon_code) = &self.coupon_code {
+            attributes.push(("data-sellix-coupon".to_owned(), coupon_code.clone()));
+        }
+        if let Some(theme) = &self.theme {
+            attributes.push(("data-sellix-theme".to_owned(), theme.as_str().to_owned()));
+        }
+
+        // Add all custom fields, whose names must be safe to use unescaped
+        for (name, value) in &self.custom_fields {
+            if name.is_empty() || !name.chars().all(|x| x.is_ascii_alphanumeric() || x == '-' || x == '_') {
+                return Err(ValidationError::new("custom_fields", "names must only contain letters, digits, '-' and '_'"));
+            }
+            attributes.push((format!("data-sellix-custom-{}", name), value.clone()));
+        }
+
+        // Create the element
+        let attributes: String = attributes.iter()
+            .map(|(name, value)| format!(" {}=\"{}\"", name, escape(value)))
+            .collect();
+        Ok(format!("<button{}>{}</button>", attributes, escape(&self.label)))
+    }
+
+    /// Builds the `<button>` element, preceded by the script and stylesheet it needs.
+    ///
+    /// Both only need to be included once per page, so use [`build`](Self::build) for any other buttons.
+    pub fn snippet(&self) -> Result<String, ValidationError> {
+        Ok(format!(
+            "<script src=\"{}\"></script>\n<link href=\"{}\" rel=\"stylesheet\" />\n{}",
+            EMBED_SCRIPT,
+            EMBED_STYLESHEET,
+            self.build()?
+        ))
+    }
+}
+impl From<&ProductRaw> for EmbedButton {
+    /// A button for the product, labelled with its title.
+    fn from(product: &ProductRaw) -> Self {
+        Self::product(&product.uniqid).label(&product.title)
+    }
+}
+impl From<&GroupRaw> for EmbedButton {
+    /// A button for the group, labelled with its title.
+    fn from(group: &GroupRaw) -> Self {
+        Self::group(&group.uniqid).label(&group.title)
+    }
+}
+
+/// Escapes text, so it can be used within an element or a quoted attribute.
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for x in value.chars() {
+        match x {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            x => output.push(x),
+        }
+    }
+    output
+}
@@ -1,14 +1,54 @@
 /*
 Exports
 */
+pub mod access;
 pub mod api;
+pub mod audit;
+pub mod backup;
+pub mod bulk;
+pub mod cache;
 pub mod client;
-pub use client::Client;
+pub mod embed;
+pub mod links;
+pub mod middleware;
+#[cfg(feature = "sqlite")]
+pub mod order_sync;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod pool;
+pub mod resource;
+pub mod sync;
+pub mod transport;
+pub use client::{Client, ClientBuilder};
+pub use embed::EmbedButton;
+pub use links::{LinkTarget, Storefront, StorefrontLink};
+pub use pool::MerchantPool;
+pub use transport::Transport;
 pub use sellix_api_models;
 
+// Lets the code generated by `DefaultAPI` refer to `::sellix_rs` within this crate too
+extern crate self as sellix_rs;
+
+/// Keeps the mocks generated by `DefaultAPI`, only with the `mock` feature.
+#[cfg(feature = "mock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mock {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Drops the mocks generated by `DefaultAPI`, without the `mock` feature.
+#[cfg(not(feature = "mock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __mock {
+    ($($item:item)*) => {};
+}
+
 // Dependencies
-use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 use sellix_api_models::payment::PaymentGateway;
+use sellix_api_models::validation::ValidationError;
 use serde_repr::{Serialize_repr, Deserialize_repr};
 use sha2::Sha512;
 use hmac::{Hmac, Mac};
@@ -51,102 +91,115 @@ pub enum QueryStep {
 /// These links can have querystring parameters added to them, like this example:
 /// 
 /// https://exampleStore.mysellix.io/product/demode8a50?quantity=5&gateway=LITECOIN&step=1
+///
+/// Every value is percent-encoded, and custom fields are added in alphabetical order, after the others.
+/// See [`StorefrontLink`] for links to any other page, or to a store on a custom domain,
+/// and [`EmbedButton`] for buy buttons on an external site.
+///
+/// Dereferences to the [`StorefrontLink`] it builds, for its getters and custom fields.
+#[derive(Clone, Debug, PartialEq)]
 pub struct ProductQuerystringBuilder {
-    store_name: String,
-    uniqid: String,
-    /// Implement any more custom fields.
-    pub custom_fields: HashMap<String, String>,
-    /// Specifies the number of the same product to purchase.
-    quantity: Option<u64>,
-    /// Specifies which payment gateway should be selected.
-    gateway: Option<PaymentGateway>,
-    /// Specifies which step of the checkout process should be displayed.
-    step: Option<QueryStep>,
-    /// The customer's email address.
-    email: Option<String>,
-    /// The code of a coupon that should be applied automatically to the purchase.
-    coupon_code: Option<String>,
+    link: StorefrontLink,
 }
 impl ProductQuerystringBuilder {
     /// Initialises a builder.
     pub fn new(store_name: &str, uniqid: &str) -> Self {
         Self {
-            store_name: store_name.to_owned(),
-            uniqid: uniqid.to_owned(),
-            custom_fields: HashMap::default(),
-            quantity: None,
-            gateway: None,
-            step: None,
-            email: None,
-            coupon_code: None,
+            link: StorefrontLink::product(Storefront::subdomain(store_name), uniqid),
         }
     }
 
     /// Sets the quantity.
     pub fn quantity(mut self, quantity: u64) -> Self {
-        self.quantity = Some(quantity);
+        self.link = self.link.quantity(quantity);
         self
     }
 
     /// Sets the gateway.
     pub fn gateway(mut self, gateway: PaymentGateway) -> Self {
-        self.gateway = Some(gateway);
+        self.link = self.link.gateway(gateway);
         self
     }
 
     /// Sets the step.
     pub fn step(mut self, step: QueryStep) -> Self {
-        self.step = Some(step);
+        self.link = self.link.step(step);
         self
     }
 
-    /// Sets the quantity.
+    /// Sets the email.
     pub fn email(mut self, email: &str) -> Self {
-        self.email = Some(email.to_owned());
+        self.link = self.link.email(email);
         self
     }
 
-    /// Sets the quantity.
+    /// Sets the coupon code.
     pub fn coupon_code(mut self, coupon_code: &str) -> Self {
-        self.coupon_code = Some(coupon_code.to_owned());
+        self.link = self.link.coupon_code(coupon_code);
         self
     }
 
     /// Add a custom field.
     pub fn add_custom_field(mut self, key: &str, value: &str) -> Self {
-        self.custom_fields.insert(key.to_owned(), value.to_owned());
+        self.link = self.link.add_custom_field(key, value);
         self
     }
 
+    /// The name of the store, i.e. its subdomain.
+    pub fn store_name(&self) -> &str {
+        let (Storefront::Subdomain(store_name) | Storefront::Domain(store_name)) = self.link.storefront();
+        store_name
+    }
+
+    /// The uniqid of the product.
+    pub fn uniqid(&self) -> &str {
+        match self.link.target() {
+            LinkTarget::Product(uniqid) => uniqid,
+            _ => unreachable!("the builder only ever holds product links")
+        }
+    }
+
+    /// The same link, as a [`StorefrontLink`].
+    pub fn link(&self) -> StorefrontLink {
+        self.link.clone()
+    }
+
     /// Builds the string to a URL.
+    ///
+    /// # Panics
+    ///
+    /// If the store name is not a valid subdomain, or the uniqid is empty, see [`try_build`](Self::try_build).
     pub fn build(&self) -> String {
-        // Create the base string
-        let mut output = format!("https://{}.mysellix.io/product/{}?", self.store_name, self.uniqid);
+        self.try_build().expect("invalid product link")
+    }
 
-        // Add all optional fields to the string
-        if let Some(quantity) = self.quantity  {
-            output.push_str(&format!("quantity={}&", quantity));
-        }
-        if let Some(gateway) = &self.gateway  {
-            output.push_str(&format!("gateway={}&", gateway));
-        }
-        if let Some(step) = &self.step  {
-            output.push_str(&format!("step={}&", serde_json::to_string(step).unwrap()));
-        }
-        if let Some(email) = &self.email  {
-            output.push_str(&format!("email={}&", email));
-        }
-        if let Some(coupon_code) = &self.coupon_code  {
-            output.push_str(&format!("couponCode={}&", coupon_code));
-        }
+    /// Builds the string to a URL.
+    ///
+    /// Fails if the store name is not a valid subdomain, or the uniqid is empty.
+    pub fn try_build(&self) -> Result<String, ValidationError> {
+        self.link.build()
+    }
 
-        // Add all custom fields
-        for (name, value) in &self.custom_fields {
-            output.push_str(&(name.to_owned() + "=" + value + "&"));
+    /// Parses a product link, e.g. one made by [`build`](Self::build).
+    ///
+    /// Any parameter that is not recognised is kept as a custom field.
+    pub fn parse(url: &str) -> Result<Self, ValidationError> {
+        let link = StorefrontLink::parse(url)?;
+        if !matches!((link.storefront(), link.target()), (Storefront::Subdomain(_), LinkTarget::Product(_))) {
+            return Err(ValidationError::new("url", "not a mysellix.io product link"));
         }
+        Ok(Self { link })
+    }
+}
+impl Deref for ProductQuerystringBuilder {
+    type Target = StorefrontLink;
 
-        // Return output
-        output.pop();
-        output
+    fn deref(&self) -> &StorefrontLink {
+        &self.link
     }
-}
\ No newline at end of file
+}
+impl DerefMut for ProductQuerystringBuilder {
+    fn deref_mut(&mut self) -> &mut StorefrontLink {
+        &mut self.link
+    }
+}
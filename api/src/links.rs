@@ -0,0 +1,356 @@
+// Dependencies
+use std::collections::BTreeMap;
+use percent_encoding::percent_decode_str;
+use reqwest::Url;
+use sellix_api_models::payment::PaymentGateway;
+use sellix_api_models::validation::{self, ValidationError};
+use crate::QueryStep;
+
+/// Where a shop is hosted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Storefront {
+    /// Hosted by Sellix, e.g. `exampleStore` for `https://exampleStore.mysellix.io`.
+    Subdomain(String),
+    /// Hosted on a custom domain, e.g. `shop.example.com`.
+    Domain(String),
+}
+impl Storefront {
+    /// The domain every hosted store is a subdomain of.
+    const DOMAIN: &'static str = ".mysellix.io";
+
+    /// A store hosted by Sellix.
+    pub fn subdomain(store_name: &str) -> Self {
+        Self::Subdomain(store_name.to_owned())
+    }
+
+    /// A store hosted on a custom domain.
+    pub fn custom_domain(domain: &str) -> Self {
+        Self::Domain(domain.to_owned())
+    }
+
+    /// The root of the store, e.g. `https://exampleStore.mysellix.io/`.
+    pub fn base_url(&self) -> Result<Url, ValidationError> {
+        let host = match self {
+            Self::Subdomain(store_name) => {
+                if !is_label(store_name, true) {
+                    return Err(ValidationError::new("store_name", "must only contain letters, digits, '-' and '_'"));
+                }
+                format!("{}{}", store_name, Self::DOMAIN)
+            },
+            Self::Domain(domain) => {
+                if !domain.contains('.') || !domain.split('.').all(|x| is_label(x, false)) {
+                    return Err(ValidationError::new("domain", "not a valid domain name"));
+                }
+                domain.to_owned()
+            }
+        };
+
+        Url::parse(&format!("https://{}/", host))
+            .map_err(|x| ValidationError::new("domain", &x.to_string()))
+    }
+
+    /// Works out the store from the host of a link.
+    fn from_host(host: &str) -> Result<Self, ValidationError> {
+        match host.strip_suffix(Self::DOMAIN) {
+            Some(store_name) if is_label(store_name, true) => Ok(Self::subdomain(store_name)),
+            Some(_) => Err(ValidationError::new("url", "not a mysellix.io store")),
+            None => Ok(Self::custom_domain(host))
+        }
+    }
+}
+
+/// Whether the value can be used as one part of a host name.
+fn is_label(value: &str, allow_underscore: bool) -> bool {
+    !value.is_empty() && value.chars().all(|x| x.is_ascii_alphanumeric() || x == '-' || (allow_underscore && x == '_'))
+}
+
+/// What a [`StorefrontLink`] points to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// A product, at `/product/{uniqid}`.
+    Product(String),
+    /// A group of products, at `/group/{uniqid}`.
+    Group(String),
+    /// A category, at `/category/{uniqid}`.
+    Category(String),
+    /// A cart of several products and their quantities, at `/cart`.
+    ///
+    /// Each product is added as an `item={uniqid}:{quantity}` parameter, in order.
+    Cart(Vec<(String, u64)>),
+    /// An invoice, at `/invoice/{uniqid}`.
+    Invoice(String),
+}
+impl LinkTarget {
+    /// The path segments of the target.
+    fn segments(&self) -> Vec<&str> {
+        match self {
+            Self::Product(uniqid) => vec!["product", uniqid],
+            Self::Group(uniqid) => vec!["group", uniqid],
+            Self::Category(uniqid) => vec!["category", uniqid],
+            Self::Cart(_) => vec!["cart"],
+            Self::Invoice(uniqid) => vec!["invoice", uniqid],
+        }
+    }
+}
+
+/// Builds links to any page of a store, with checkout presets.
+///
+/// ```
+/// # use sellix_rs::{QueryStep, links::{Storefront, StorefrontLink}};
+/// let link = StorefrontLink::cart(Storefront::custom_domain("shop.example.com"))
+///     .add_to_cart("demobde8a50", 2)
+///     .add_to_cart("demode8a50", 1)
+///     .step(QueryStep::EmailAndPay)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(link, "https://shop.example.com/cart?item=demobde8a50%3A2&item=demode8a50%3A1&step=3");
+/// ```
+///
+/// Every value is percent-encoded, and custom fields are added in alphabetical order, after the others.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorefrontLink {
+    storefront: Storefront,
+    target: LinkTarget,
+    /// Implement any more custom fields.
+    pub custom_fields: BTreeMap<String, String>,
+    /// Specifies the number of the same product to purchase, only used by product links.
+    quantity: Option<u64>,
+    /// Specifies which payment gateway should be selected.
+    gateway: Option<PaymentGateway>,
+    /// Specifies which step of the checkout process should be displayed.
+    step: Option<QueryStep>,
+    /// The customer's email address.
+    email: Option<String>,
+    /// The code of a coupon that should be applied automatically to the purchase.
+    coupon_code: Option<String>,
+}
+impl StorefrontLink {
+    /// Initialises a link to any target.
+    pub fn new(storefront: Storefront, target: LinkTarget) -> Self {
+        Self {
+            storefront,
+            target,
+            custom_fields: BTreeMap::default(),
+            quantity: None,
+            gateway: None,
+            step: None,
+            email: None,
+            coupon_code: None,
+        }
+    }
+
+    /// Initialises a link to a product.
+    pub fn product(storefront: Storefront, uniqid: &str) -> Self {
+        Self::new(storefront, LinkTarget::Product(uniqid.to_owned()))
+    }
+
+    /// Initialises a link to a group.
+    pub fn group(storefront: Storefront, uniqid: &str) -> Self {
+        Self::new(storefront, LinkTarget::Group(uniqid.to_owned()))
+    }
+
+    /// Initialises a link to a category.
+    pub fn category(storefront: Storefront, uniqid: &str) -> Self {
+        Self::new(storefront, LinkTarget::Category(uniqid.to_owned()))
+    }
+
+    /// Initialises a link to an empty cart, see [`add_to_cart`](Self::add_to_cart).
+    pub fn cart(storefront: Storefront) -> Self {
+        Self::new(storefront, LinkTarget::Cart(Vec::new()))
+    }
+
+    /// Initialises a link to an invoice.
+    pub fn invoice(storefront: Storefront, uniqid: &str) -> Self {
+        Self::new(storefront, LinkTarget::Invoice(uniqid.to_owned()))
+    }
+
+    /// Adds a product to a cart link, ignored by any other link.
+    pub fn add_to_cart(mut self, uniqid: &str, quantity: u64) -> Self {
+        if let LinkTarget::Cart(items) = &mut self.target {
+            items.push((uniqid.to_owned(), quantity));
+        }
+        self
+    }
+
+    /// Sets the quantity, only used by product links.
+    pub fn quantity(mut self, quantity: u64) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    /// Sets the gateway.
+    pub fn gateway(mut self, gateway: PaymentGateway) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    /// Sets the step.
+    pub fn step(mut self, step: QueryStep) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the email.
+    pub fn email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_owned());
+        self
+    }
+
+    /// Sets the coupon code.
+    pub fn coupon_code(mut self, coupon_code: &str) -> Self {
+        self.coupon_code = Some(coupon_code.to_owned());
+        self
+    }
+
+    /// Add a custom field.
+    pub fn add_custom_field(mut self, key: &str, value: &str) -> Self {
+        self.custom_fields.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Where the store is hosted.
+    pub fn storefront(&self) -> &Storefront {
+        &self.storefront
+    }
+
+    /// What the link points to.
+    pub fn target(&self) -> &LinkTarget {
+        &self.target
+    }
+
+    /// The quantity, if set.
+    pub fn get_quantity(&self) -> Option<u64> {
+        self.quantity
+    }
+
+    /// The gateway, if set.
+    pub fn get_gateway(&self) -> Option<&PaymentGateway> {
+        self.gateway.as_ref()
+    }
+
+    /// The step, if set.
+    pub fn get_step(&self) -> Option<&QueryStep> {
+        self.step.as_ref()
+    }
+
+    /// The email, if set.
+    pub fn get_email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// The coupon code, if set.
+    pub fn get_coupon_code(&self) -> Option<&str> {
+        self.coupon_code.as_deref()
+    }
+
+    /// Builds the link to a URL.
+    ///
+    /// Fails if the store is not a valid host, a uniqid is empty, or a cart is empty.
+    pub fn build(&self) -> Result<String, ValidationError> {
+        // Make sure the target can be linked to
+        match &self.target {
+            LinkTarget::Cart(items) => {
+                if items.is_empty() {
+                    return Err(ValidationError::new("cart", "must contain at least one product"));
+                }
+                for (uniqid, _) in items {
+                    validation::non_empty("uniqid", uniqid)?;
+                }
+            },
+            LinkTarget::Product(uniqid) | LinkTarget::Group(uniqid) | LinkTarget::Category(uniqid) | LinkTarget::Invoice(uniqid) => {
+                validation::non_empty("uniqid", uniqid)?;
+            }
+        }
+        if self.quantity.is_some() && !matches!(self.target, LinkTarget::Product(_)) {
+            return Err(ValidationError::new("quantity", "only used by product links"));
+        }
+
+        // Create the base URL, with the target as encoded path segments
+        let mut url = self.storefront.base_url()?;
+        url.path_segments_mut()
+            .expect("https URLs have a path")
+            .pop_if_empty()
+            .extend(self.target.segments());
+
+        // Add the cart, then all optional fields, followed by the custom fields
+        let mut pairs = Vec::new();
+        if let LinkTarget::Cart(items) = &self.target {
+            pairs.extend(items.iter().map(|(uniqid, quantity)| ("item", format!("{}:{}", uniqid, quantity))));
+        }
+        if let Some(quantity) = self.quantity {
+            pairs.push(("quantity", quantity.to_string()));
+        }
+        if let Some(gateway) = &self.gateway {
+            pairs.push(("gateway", gateway.to_string()));
+        }
+        if let Some(step) = &self.step {
+            pairs.push(("step", (step.clone() as u16).to_string()));
+        }
+        if let Some(email) = &self.email {
+            pairs.push(("email", email.clone()));
+        }
+        if let Some(coupon_code) = &self.coupon_code {
+            pairs.push(("couponCode", coupon_code.clone()));
+        }
+        pairs.extend(self.custom_fields.iter().map(|(name, value)| (name.as_str(), value.clone())));
+        if !pairs.is_empty() {
+            url.query_pairs_mut().extend_pairs(pairs);
+        }
+
+        Ok(url.into())
+    }
+
+    /// Parses a link, e.g. one made by [`build`](Self::build).
+    ///
+    /// Any host other than `*.mysellix.io` is treated as a custom domain.
+    /// Any parameter that is not recognised is kept as a custom field.
+    pub fn parse(url: &str) -> Result<Self, ValidationError> {
+        let url = Url::parse(url)
+            .map_err(|x| ValidationError::new("url", &x.to_string()))?;
+        if url.scheme() != "https" {
+            return Err(ValidationError::new("url", "must use https"));
+        }
+        let storefront = Storefront::from_host(url.host_str().unwrap_or_default())?;
+
+        // Work out the target from the path
+        let segments = url.path_segments()
+            .map(|x| x.map(|x| percent_decode_str(x).decode_utf8().map(|x| x.into_owned())).collect::<Result<Vec<_>, _>>())
+            .unwrap_or(Ok(Vec::new()))
+            .map_err(|_| ValidationError::new("url", "path is not valid UTF-8"))?;
+        let target = match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+            ["product", uniqid] if !uniqid.is_empty() => LinkTarget::Product(uniqid.to_string()),
+            ["group", uniqid] if !uniqid.is_empty() => LinkTarget::Group(uniqid.to_string()),
+            ["category", uniqid] if !uniqid.is_empty() => LinkTarget::Category(uniqid.to_string()),
+            ["invoice", uniqid] if !uniqid.is_empty() => LinkTarget::Invoice(uniqid.to_string()),
+            ["cart"] => LinkTarget::Cart(Vec::new()),
+            _ => return Err(ValidationError::new("url", "not a storefront link"))
+        };
+        let mut link = Self::new(storefront, target);
+
+        // Recover every parameter
+        for (name, value) in url.query_pairs() {
+            match (name.as_ref(), &mut link.target) {
+                ("item", LinkTarget::Cart(items)) => {
+                    let item = value.rsplit_once(':')
+                        .and_then(|(uniqid, quantity)| Some((uniqid.to_owned(), quantity.parse().ok()?)))
+                        .ok_or_else(|| ValidationError::new("item", "must be {uniqid}:{quantity}"))?;
+                    items.push(item);
+                },
+                ("quantity", _) => link.quantity = Some(value.parse()
+                    .map_err(|_| ValidationError::new("quantity", "not a number"))?),
+                ("gateway", _) => link.gateway = Some(PaymentGateway::from(value.into_owned())),
+                ("step", _) => link.step = Some(value.parse().ok()
+                    .and_then(QueryStep::from_repr)
+                    .ok_or_else(|| ValidationError::new("step", "not a known step"))?),
+                ("email", _) => link.email = Some(value.into_owned()),
+                ("couponCode", _) => link.coupon_code = Some(value.into_owned()),
+                _ => {
+                    link.custom_fields.insert(name.into_owned(), value.into_owned());
+                }
+            }
+        }
+
+        Ok(link)
+    }
+}
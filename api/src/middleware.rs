@@ -0,0 +1,126 @@
+// Dependencies
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use async_trait::async_trait;
+use reqwest::Method;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use sellix_api_models::{RequestType, SellixError, SellixHttpCode};
+use serde_json::Value;
+use crate::transport::Transport;
+
+/// A request that is about to be sent to the API.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// What the request is for.
+    pub request_type: RequestType,
+    /// The HTTP method.
+    pub method: Method,
+    /// The path, relative to the API base.
+    pub path: String,
+    /// The merchant the request is sent on behalf of.
+    pub merchant: Option<String>,
+    /// The JSON body, if any.
+    pub body: Option<Value>,
+    /// Any additional headers to send.
+    /// The `Authorization` and `X-Sellix-Merchant` headers are always added when sending.
+    pub headers: HeaderMap,
+}
+
+/// A layer that wraps every request sent through a [`Transport`].
+///
+/// A layer can inspect or modify the [`Request`], short-circuit by returning without calling [`Next::run`],
+/// or inspect and modify the raw JSON response.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Value, SellixError>;
+}
+
+/// The rest of the middleware chain.
+pub struct Next<'a> {
+    pub(crate) transport: &'a Transport,
+    pub(crate) api_key: &'a str,
+    pub(crate) middleware: &'a [Arc<dyn Middleware>],
+}
+impl<'a> Next<'a> {
+    /// Passes the request onto the next layer, or sends it if there are none left.
+    pub async fn run(self, request: Request) -> Result<Value, SellixError> {
+        match self.middleware.split_first() {
+            Some((layer, rest)) => {
+                let next = Next {
+                    transport: self.transport,
+                    api_key: self.api_key,
+                    middleware: rest,
+                };
+                layer.handle(request, next).await
+            },
+            None => self.transport.send(self.api_key, request).await,
+        }
+    }
+}
+
+/// Adds the same headers to every request.
+pub struct Headers {
+    headers: HeaderMap,
+}
+impl Headers {
+    /// Initialises the layer without any headers.
+    pub fn new() -> Self {
+        Self {
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Adds a header.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+}
+impl Default for Headers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[async_trait]
+impl Middleware for Headers {
+    async fn handle(&self, mut request: Request, next: Next<'_>) -> Result<Value, SellixError> {
+        for (name, value) in &self.headers {
+            request.headers.insert(name.clone(), value.clone());
+        }
+        next.run(request).await
+    }
+}
+
+/// Rejects every request that could modify the shop, e.g. within staging.
+pub struct ReadOnly;
+#[async_trait]
+impl Middleware for ReadOnly {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Value, SellixError> {
+        if request.method != Method::GET {
+            return Err(SellixError {
+                kind: SellixHttpCode::Forbidden.into(),
+                message: format!("read-only mode - refused to send {} {}", request.method, request.path)
+            });
+        }
+        next.run(request).await
+    }
+}
+
+/// Counts how many requests have passed through it.
+#[derive(Default)]
+pub struct RequestCounter {
+    count: AtomicU64,
+}
+impl RequestCounter {
+    /// The number of requests so far.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+#[async_trait]
+impl Middleware for RequestCounter {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Value, SellixError> {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        next.run(request).await
+    }
+}
@@ -0,0 +1,161 @@
+//! Mocks of every sub-API, so code which depends on them can be tested without any HTTP.
+//!
+//! `DefaultAPI` generates a mock for each sub-API (e.g. [`MockCoupon`](crate::api::MockCoupon)),
+//! which implements the same [capability traits](crate::resource) as the real one.
+//! Each method is a public [`MockMethod`] field, used to program its responses and inspect its calls.
+//!
+//! Only available with the `mock` feature.
+//!
+//! ```
+//! # use sellix_rs::{api::MockCoupon, resource::Deletable};
+//! # use sellix_rs::sellix_api_models::SellixHttpCode;
+//! # async fn example() {
+//! let coupons = MockCoupon::new();
+//! coupons.delete.expect().with("abc".to_owned()).returning_error(SellixHttpCode::NotFound);
+//!
+//! assert!(coupons.delete("abc").await.is_err());
+//! assert_eq!(coupons.delete.call_count(), 1);
+//! # }
+//! ```
+
+// Dependencies
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use sellix_api_models::{SellixError, SellixErrorKind};
+
+// Used by the mocks generated by `DefaultAPI`, wherever it is derived
+#[doc(hidden)]
+pub use serde_json::{to_value, Value};
+
+/// The arguments an expectation matches.
+type Matcher<A> = Box<dyn Fn(&A) -> bool + Send + Sync>;
+
+/// Produces a response to a matched call.
+/// Shared, so that it can be called without holding onto every expectation of the method.
+type Responder<A, R> = Arc<Mutex<dyn FnMut(&A) -> Result<R, SellixError> + Send>>;
+
+/// A programmed response.
+struct Expectation<A, R> {
+    matcher: Option<Matcher<A>>,
+    responder: Responder<A, R>,
+    times: Option<usize>,
+    calls: usize,
+}
+impl<A, R> Expectation<A, R> {
+    /// Whether the expectation accepts another call with these arguments.
+    fn matches(&self, args: &A) -> bool {
+        self.times.is_none_or(|x| self.calls < x) && self.matcher.as_ref().is_none_or(|x| x(args))
+    }
+}
+
+/// One method of a mocked sub-API.
+///
+/// Calls are answered by the first expectation that matches them, in the order they were added.
+/// A call that no expectation matches panics.
+pub struct MockMethod<A, R> {
+    name: &'static str,
+    expectations: Mutex<Vec<Expectation<A, R>>>,
+    calls: Mutex<Vec<A>>,
+}
+impl<A: Debug + Clone, R> MockMethod<A, R> {
+    /// Creates a method without any expectations, e.g. `"MockCoupon::get"`.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            expectations: Mutex::new(Vec::new()),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Starts programming a response.
+    pub fn expect(&self) -> ExpectationBuilder<'_, A, R> {
+        ExpectationBuilder {
+            method: self,
+            matcher: None,
+            times: None,
+        }
+    }
+
+    /// The arguments of every call so far, in order.
+    pub fn calls(&self) -> Vec<A> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// The number of calls so far.
+    pub fn call_count(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+
+    /// Panics if an expectation with a set number of calls has not been called that many times.
+    pub fn verify(&self) {
+        for expectation in self.expectations.lock().unwrap().iter() {
+            if let Some(times) = expectation.times {
+                assert_eq!(expectation.calls, times, "{} was expected to be called {} times, but was called {} times", self.name, times, expectation.calls);
+            }
+        }
+    }
+
+    /// Records the call, and answers it.
+    pub fn call(&self, args: A) -> Result<R, SellixError> {
+        self.calls.lock().unwrap().push(args.clone());
+
+        // Find the expectation, then let go of them all so the responder may call back into the mock
+        let responder = {
+            let mut expectations = self.expectations.lock().unwrap();
+            let expectation = expectations.iter_mut()
+                .find(|x| x.matches(&args))
+                .unwrap_or_else(|| panic!("unexpected call to {}({:?})", self.name, args));
+            expectation.calls += 1;
+            expectation.responder.clone()
+        };
+        let mut responder = responder.lock().unwrap();
+        (*responder)(&args)
+    }
+}
+
+/// Programs a response for a [`MockMethod`], which is added once [`returning`](Self::returning) is called.
+pub struct ExpectationBuilder<'a, A, R> {
+    method: &'a MockMethod<A, R>,
+    matcher: Option<Matcher<A>>,
+    times: Option<usize>,
+}
+impl<'a, A, R> ExpectationBuilder<'a, A, R> {
+    /// Only matches calls with these arguments.
+    pub fn with(self, args: A) -> Self
+    where
+        A: PartialEq + Send + Sync + 'static
+    {
+        self.with_fn(move |x| *x == args)
+    }
+
+    /// Only matches calls whose arguments satisfy the predicate.
+    pub fn with_fn(mut self, predicate: impl Fn(&A) -> bool + Send + Sync + 'static) -> Self {
+        self.matcher = Some(Box::new(predicate));
+        self
+    }
+
+    /// Only matches this many calls, which [`MockMethod::verify`] checks were all made.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Answers each matched call using the closure.
+    pub fn returning(self, responder: impl FnMut(&A) -> Result<R, SellixError> + Send + 'static) {
+        self.method.expectations.lock().unwrap().push(Expectation {
+            matcher: self.matcher,
+            responder: Arc::new(Mutex::new(responder)),
+            times: self.times,
+            calls: 0,
+        });
+    }
+
+    /// Answers each matched call with an error, as the API would.
+    pub fn returning_error(self, kind: impl Into<SellixErrorKind>) {
+        let kind = kind.into();
+        self.returning(move |_| Err(SellixError {
+            kind: kind.clone(),
+            message: kind.to_string()
+        }));
+    }
+}
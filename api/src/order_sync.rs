@@ -0,0 +1,337 @@
+//! Keeps a local SQLite database of every order, for reporting.
+//!
+//! Each order is kept within the `orders` table, with its status history, crypto transactions
+//! and custom fields in the `order_status_history`, `order_crypto_transactions` and `order_custom_fields` tables.
+//! The order as returned by the API is also kept in `orders.raw`.
+//!
+//! ```no_run
+//! # use sellix_rs::{Client, order_sync::OrderStore};
+//! # async fn example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut store = OrderStore::open("orders.db")?;
+//! let report = store.sync(client).await?;
+//! println!("{} orders changed", report.changed);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only available with the `sqlite` feature.
+
+// Dependencies
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+use rusqlite::{Connection, OptionalExtension, Transaction, params};
+use sellix_api_models::{SellixError, SellixErrorKind, Timestamp};
+use sellix_api_models::order::OrderRaw;
+use sellix_api_models::webhook::RawWebsocketRequest;
+use crate::client::Client;
+use crate::resource::MAX_PAGES;
+
+// Constants
+/// How long after being created an order is still checked for changes by default, see [`OrderStore::look_back`].
+pub const DEFAULT_LOOK_BACK: Duration = Duration::from_secs(30 * 86_400);
+
+/// Creates every table, unless it already exists.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS orders (
+    uniqid TEXT PRIMARY KEY,
+    id INTEGER NOT NULL,
+    type TEXT NOT NULL,
+    status TEXT NOT NULL,
+    customer_email TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    product_title TEXT NOT NULL,
+    gateway TEXT,
+    currency TEXT NOT NULL,
+    total TEXT NOT NULL,
+    total_display TEXT NOT NULL,
+    discount TEXT NOT NULL,
+    quantity INTEGER NOT NULL,
+    coupon_id TEXT,
+    country TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER,
+    modified_at INTEGER NOT NULL,
+    raw TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS orders_modified_at ON orders (modified_at);
+CREATE TABLE IF NOT EXISTS order_status_history (
+    order_uniqid TEXT NOT NULL REFERENCES orders (uniqid) ON DELETE CASCADE,
+    id INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    details TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (order_uniqid, id)
+);
+CREATE TABLE IF NOT EXISTS order_crypto_transactions (
+    order_uniqid TEXT NOT NULL REFERENCES orders (uniqid) ON DELETE CASCADE,
+    hash TEXT NOT NULL,
+    crypto_amount TEXT NOT NULL,
+    confirmations INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER,
+    PRIMARY KEY (order_uniqid, hash)
+);
+CREATE TABLE IF NOT EXISTS order_custom_fields (
+    order_uniqid TEXT NOT NULL REFERENCES orders (uniqid) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (order_uniqid, name)
+);
+CREATE TABLE IF NOT EXISTS order_sync_state (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    checkpoint INTEGER,
+    resume_page INTEGER,
+    pending_checkpoint INTEGER
+);
+INSERT OR IGNORE INTO order_sync_state (id) VALUES (1);
+";
+
+/// Returned when orders cannot be synced.
+#[derive(Debug)]
+pub enum OrderSyncError {
+    /// A page of orders could not be retrieved.
+    Api(SellixError),
+    /// The database could not be read or written.
+    Database(rusqlite::Error),
+}
+impl fmt::Display for OrderSyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Api(error) => write!(f, "unable to retrieve orders: {}", error),
+            Self::Database(error) => write!(f, "unable to update the database: {}", error),
+        }
+    }
+}
+impl std::error::Error for OrderSyncError {}
+impl From<SellixError> for OrderSyncError {
+    fn from(error: SellixError) -> Self {
+        Self::Api(error)
+    }
+}
+impl From<rusqlite::Error> for OrderSyncError {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Database(error)
+    }
+}
+
+/// What a single [`OrderStore::sync`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderSyncReport {
+    /// How many pages were retrieved.
+    pub pages: u64,
+    /// How many orders were retrieved.
+    pub fetched: usize,
+    /// How many orders were new or had changed.
+    pub changed: usize,
+    /// The checkpoint the next sync stops at.
+    pub checkpoint: Option<Timestamp>,
+}
+
+/// A SQLite database of orders, which is synced incrementally.
+///
+/// Orders are listed newest first by when they were created, but an older order can still change, e.g. when it is refunded.
+/// So a sync stops at the first page where every order was created before the look-back window,
+/// which ends at the checkpoint, i.e. when the latest change seen by the previous sync was made.
+/// Each page is written within its own transaction, along with where to resume from,
+/// so a sync which is interrupted carries on from the same page the next time.
+///
+/// An order which changes once it is older than the look-back window is not seen by a sync,
+/// so feed order webhooks to [`upsert_event`](Self::upsert_event), or call [`reset`](Self::reset) to sync every page again.
+pub struct OrderStore {
+    connection: Connection,
+    look_back: Duration,
+}
+impl OrderStore {
+    /// Opens a database file, creating it and its tables if needed.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, rusqlite::Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a database in memory, e.g. for testing.
+    pub fn open_in_memory() -> Result<Self, rusqlite::Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// Uses an existing connection, creating the tables if needed.
+    pub fn with_connection(connection: Connection) -> Result<Self, rusqlite::Error> {
+        connection.execute_batch("PRAGMA foreign_keys = ON;")?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection, look_back: DEFAULT_LOOK_BACK })
+    }
+
+    /// How long before the checkpoint orders were created which are still checked for changes, [`DEFAULT_LOOK_BACK`] by default.
+    pub fn look_back(mut self, look_back: Duration) -> Self {
+        self.look_back = look_back;
+        self
+    }
+
+    /// The connection, e.g. to query the tables.
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// When the latest change seen by the last complete sync was made.
+    pub fn checkpoint(&self) -> Result<Option<Timestamp>, rusqlite::Error> {
+        self.connection.query_row("SELECT checkpoint FROM order_sync_state", [], |row| row.get::<_, Option<i64>>(0))
+            .map(|x| x.map(Timestamp::from_unix))
+    }
+
+    /// Forgets the checkpoint, and any interrupted sync, so the next sync retrieves every page.
+    pub fn reset(&mut self) -> Result<(), rusqlite::Error> {
+        self.connection.execute("UPDATE order_sync_state SET checkpoint = NULL, resume_page = NULL, pending_checkpoint = NULL", [])?;
+        Ok(())
+    }
+
+    /// Retrieves every page of orders created since the look-back window, and writes every order which is new or has changed.
+    /// Fails with [`SellixErrorKind::TooManyPages`] once more than [`MAX_PAGES`] pages have been requested,
+    /// or if a page has the same uniqids as the page before, e.g. if the API ignores the page number,
+    /// as the end of the window would then never be reached.
+    pub async fn sync(&mut self, client: &Client) -> Result<OrderSyncReport, OrderSyncError> {
+        let (checkpoint, resume_page, pending) = self.connection.query_row(
+            "SELECT checkpoint, resume_page, pending_checkpoint FROM order_sync_state",
+            [],
+            |row| Ok((row.get::<_, Option<i64>>(0)?, row.get::<_, Option<u64>>(1)?, row.get::<_, Option<i64>>(2)?))
+        )?;
+
+        let mut report = OrderSyncReport::default();
+        let mut latest = pending.into_iter().chain(checkpoint).max();
+        let horizon = checkpoint.map(|x| x.saturating_sub(i64::try_from(self.look_back.as_secs()).unwrap_or(i64::MAX)));
+        let mut previous = Vec::new();
+        for page in resume_page.unwrap_or(1)..=MAX_PAGES {
+            let orders = client.order.get_list(Some(page)).await?.orders;
+            report.pages += 1;
+            report.fetched += orders.len();
+
+            let uniqids: Vec<String> = orders.iter().map(|x| x.uniqid.clone()).collect();
+            if !uniqids.is_empty() && uniqids == previous {
+                return Err(SellixError {
+                    kind: SellixErrorKind::TooManyPages,
+                    message: format!("gave up syncing orders as page {} repeats the page before", page),
+                }.into());
+            }
+            previous = uniqids;
+
+            // Write the page, and where to resume from, together
+            let transaction = self.connection.transaction()?;
+            let mut finished = true;
+            for order in &orders {
+                let modified_at = modified_at(order);
+                latest = latest.max(Some(modified_at));
+                if horizon.is_none_or(|x| order.created_at.unix() >= x) {
+                    finished = false;
+                }
+                if upsert(&transaction, order)? {
+                    report.changed += 1;
+                }
+            }
+            if finished {
+                transaction.execute("UPDATE order_sync_state SET checkpoint = ?1, resume_page = NULL, pending_checkpoint = NULL", params![latest])?;
+            } else {
+                transaction.execute("UPDATE order_sync_state SET resume_page = ?1, pending_checkpoint = ?2", params![page + 1, latest])?;
+            }
+            transaction.commit()?;
+
+            if finished {
+                report.checkpoint = latest.map(Timestamp::from_unix);
+                return Ok(report);
+            }
+        }
+
+        Err(SellixError {
+            kind: SellixErrorKind::TooManyPages,
+            message: format!("gave up syncing orders after {} pages", MAX_PAGES),
+        }.into())
+    }
+
+    /// Writes an order, e.g. from a webhook, unless it is unchanged or older than the one already written.
+    /// Returns whether it was written.
+    pub fn upsert(&mut self, order: &OrderRaw) -> Result<bool, rusqlite::Error> {
+        let transaction = self.connection.transaction()?;
+        let written = upsert(&transaction, order)?;
+        transaction.commit()?;
+        Ok(written)
+    }
+
+    /// Writes the order of an `order:*` webhook, see [`upsert`](Self::upsert).
+    pub fn upsert_event(&mut self, event: &RawWebsocketRequest<OrderRaw>) -> Result<bool, rusqlite::Error> {
+        self.upsert(&event.data)
+    }
+}
+
+/// When an order was last changed.
+fn modified_at(order: &OrderRaw) -> i64 {
+    order.updated_at.unwrap_or(order.created_at).unix()
+}
+
+/// Writes an order along with its status history, crypto transactions and custom fields, replacing any older version.
+fn upsert(transaction: &Transaction, order: &OrderRaw) -> Result<bool, rusqlite::Error> {
+    // Through a value, so custom fields are always written in the same order
+    let raw = serde_json::to_value(order).expect("every order can be serialized").to_string();
+    let modified_at = modified_at(order);
+
+    // Skip the order if it has not changed, or only an older version was given
+    let existing: Option<(i64, String)> = transaction.query_row(
+        "SELECT modified_at, raw FROM orders WHERE uniqid = ?1",
+        [&order.uniqid],
+        |row| Ok((row.get(0)?, row.get(1)?))
+    ).optional()?;
+    if existing.is_some_and(|(x, existing)| x > modified_at || existing == raw) {
+        return Ok(false);
+    }
+
+    // Replace the order, which removes every row referring to it
+    transaction.execute("DELETE FROM orders WHERE uniqid = ?1", [&order.uniqid])?;
+    transaction.execute(
+        "INSERT INTO orders (uniqid, id, type, status, customer_email, product_id, product_title, gateway, currency, total, total_display, discount, quantity, coupon_id, country, created_at, updated_at, modified_at, raw)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
+        params![
+            order.uniqid,
+            order.id,
+            order.type_field,
+            order.status.as_str(),
+            order.customer_email,
+            order.product_id,
+            order.product_title,
+            order.gateway,
+            order.currency.as_str(),
+            order.total.to_string(),
+            order.total_display.to_string(),
+            order.discount.to_string(),
+            order.quantity,
+            order.coupon_id,
+            order.country,
+            order.created_at.unix(),
+            order.updated_at.map(|x| x.unix()),
+            modified_at,
+            raw,
+        ]
+    )?;
+
+    for status in &order.status_history {
+        transaction.execute(
+            "INSERT OR REPLACE INTO order_status_history (order_uniqid, id, status, details, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![order.uniqid, status.id, status.status, status.details, status.created_at.unix()]
+        )?;
+    }
+    for crypto_transaction in &order.crypto_transactions {
+        transaction.execute(
+            "INSERT OR REPLACE INTO order_crypto_transactions (order_uniqid, hash, crypto_amount, confirmations, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                order.uniqid,
+                crypto_transaction.hash,
+                crypto_transaction.crypto_amount.to_string(),
+                crypto_transaction.confirmations,
+                crypto_transaction.created_at.unix(),
+                crypto_transaction.updated_at.map(|x| x.unix()),
+            ]
+        )?;
+    }
+    for (name, value) in &order.custom_fields {
+        transaction.execute(
+            "INSERT INTO order_custom_fields (order_uniqid, name, value) VALUES (?1, ?2, ?3)",
+            params![order.uniqid, name, value]
+        )?;
+    }
+    Ok(true)
+}
@@ -0,0 +1,69 @@
+// Dependencies
+use std::collections::HashMap;
+use std::future::Future;
+use futures::future::join_all;
+use sellix_api_models::SellixError;
+use crate::client::Client;
+use crate::transport::Transport;
+
+/// Manages several merchants under one account.
+/// 
+/// Every [`Client`] handed out by the pool shares the same [`Transport`].
+pub struct MerchantPool {
+    api_key: String,
+    merchants: Vec<String>,
+    transport: Transport
+}
+impl MerchantPool {
+    /// Creates a pool for the given merchants.
+    pub fn new(api_key: &str, merchants: &[&str]) -> Self {
+        Self::with_transport(api_key, merchants, Transport::default())
+    }
+
+    /// Creates a pool for the given merchants that sends requests through an existing transport.
+    pub fn with_transport(api_key: &str, merchants: &[&str], transport: Transport) -> Self {
+        Self {
+            api_key: api_key.to_owned(),
+            merchants: merchants.iter().map(|x| x.to_string()).collect(),
+            transport
+        }
+    }
+
+    /// Adds a merchant to the pool, if it is not already present.
+    pub fn add_merchant(&mut self, merchant: &str) {
+        if !self.merchants.iter().any(|x| x == merchant) {
+            self.merchants.push(merchant.to_owned());
+        }
+    }
+
+    /// Returns all of the merchants within the pool.
+    pub fn merchants(&self) -> &[String] {
+        &self.merchants
+    }
+
+    /// Creates a client for a merchant.
+    /// The merchant does not have to be part of the pool.
+    pub fn client(&self, merchant: &str) -> Client {
+        Client::with_transport(&self.api_key, Some(merchant), self.transport.clone())
+    }
+
+    /// Runs `f` for every merchant concurrently, returning the results keyed by merchant.
+    /// 
+    /// ```no_run
+    /// # async fn example(pool: sellix_rs::MerchantPool) {
+    /// let orders = pool.fan_out(|client| async move { client.order.get_list(None).await }).await;
+    /// # }
+    /// ```
+    pub async fn fan_out<F, Fut, T>(&self, f: F) -> HashMap<String, Result<T, SellixError>>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, SellixError>>
+    {
+        let requests = self.merchants.iter().map(|merchant| {
+            let request = f(self.client(merchant));
+            async move { (merchant.clone(), request.await) }
+        });
+
+        join_all(requests).await.into_iter().collect()
+    }
+}
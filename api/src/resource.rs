@@ -0,0 +1,90 @@
+// Dependencies
+use async_trait::async_trait;
+use sellix_api_models::{Identified, ResourceKind, SellixError, SellixErrorKind, UniqidDict};
+
+// Constants
+/// The most pages [`Listable::list_all`] requests before giving up.
+pub const MAX_PAGES: u64 = 1_000;
+
+/// Implemented by every sub-API, so generic code can be written once for all of them.
+///
+/// Which methods a sub-API supports is given by the capability traits: [`Gettable`], [`Listable`], [`Creatable`], [`Editable`] and [`Deletable`].
+/// Each is implemented by `DefaultAPI`, following its `api_methods(...)` options, for both the sub-API and its mock.
+///
+/// ```no_run
+/// # use sellix_rs::resource::Listable;
+/// # use sellix_api_models::SellixError;
+/// async fn count<R: Listable>(resource: &R) -> Result<usize, SellixError> {
+///     Ok(resource.list_all().await?.len())
+/// }
+/// ```
+pub trait Resource: Send + Sync {
+    /// Which resource this is.
+    const KIND: ResourceKind;
+    /// A single instance, e.g. `BlacklistRaw`.
+    type Raw: Identified + Send;
+    /// A page of instances, e.g. `BlacklistArray`.
+    type List: IntoIterator<Item = Self::Raw> + Send;
+}
+
+/// A sub-API whose instances can be retrieved by their uniqid.
+#[async_trait]
+pub trait Gettable: Resource {
+    /// Retrieves an instance by its uniqid.
+    async fn get(&self, uniqid: &str) -> Result<Self::Raw, SellixError>;
+}
+
+/// A sub-API whose instances can be listed.
+#[async_trait]
+pub trait Listable: Resource {
+    /// Returns a page of instances.
+    async fn get_list(&self, page: Option<u64>) -> Result<Self::List, SellixError>;
+
+    /// Returns every instance, requesting each page in turn until an empty one is returned,
+    /// or one with the same uniqids as the page before, e.g. if the API ignores the page number.
+    /// Fails with [`SellixErrorKind::TooManyPages`] once more than [`MAX_PAGES`] pages have been requested.
+    async fn list_all(&self) -> Result<Vec<Self::Raw>, SellixError> {
+        let mut items = Vec::new();
+        let mut previous = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let list: Vec<Self::Raw> = self.get_list(Some(page)).await?.into_iter().collect();
+            let uniqids: Vec<String> = list.iter().map(|x| x.uniqid().to_owned()).collect();
+            if list.is_empty() || uniqids == previous {
+                return Ok(items);
+            }
+            items.extend(list);
+            previous = uniqids;
+        }
+        Err(SellixError {
+            kind: SellixErrorKind::TooManyPages,
+            message: format!("gave up listing every {} after {} pages", Self::KIND, MAX_PAGES),
+        })
+    }
+}
+
+/// A sub-API which instances can be created through.
+#[async_trait]
+pub trait Creatable: Resource {
+    /// The payload used to create an instance, e.g. `BlacklistCreatePayload`.
+    type CreatePayload: Send;
+
+    /// Creates an instance.
+    async fn create(&self, payload: Self::CreatePayload) -> Result<UniqidDict, SellixError>;
+}
+
+/// A sub-API whose instances can be edited.
+#[async_trait]
+pub trait Editable: Resource {
+    /// The payload used to edit an instance, e.g. `BlacklistUpdatePayload`.
+    type UpdatePayload<'a>: Send;
+
+    /// Edits an instance, only changing the fields set within the payload.
+    async fn edit(&self, uniqid: &str, payload: Self::UpdatePayload<'_>) -> Result<bool, SellixError>;
+}
+
+/// A sub-API whose instances can be deleted.
+#[async_trait]
+pub trait Deletable: Resource {
+    /// Deletes an instance.
+    async fn delete(&self, uniqid: &str) -> Result<bool, SellixError>;
+}
@@ -0,0 +1,622 @@
+//! Declarative shop configuration, so a shop can be described in a file and kept in sync with it.
+//!
+//! A [`ShopConfig`] lists the categories, groups, coupons, blacklists and whitelists a shop should have.
+//! [`ShopConfig::plan`] compares it against the live shop, giving the creates, updates and deletes
+//! needed to make them match, which [`Plan::apply`] then makes.
+//!
+//! ```no_run
+//! # use sellix_rs::{Client, sync::ShopConfig};
+//! # async fn example(client: &Client, config: ShopConfig) -> Result<(), Box<dyn std::error::Error>> {
+//! let plan = config.plan(client).await?;
+//! print!("{}", plan);
+//! plan.apply(client).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A resource which is left out of the file entirely is not managed, so is never changed.
+//! Reading and writing TOML or YAML files needs the `toml` or `yaml` feature, e.g. `ShopConfig::from_toml`.
+
+// Dependencies
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use sellix_api_models::{ResourceKind, SellixError, Timestamp};
+use sellix_api_models::blacklist::BlacklistUpdatePayload;
+use sellix_api_models::category::{CategoryCreatePayload, CategoryRaw, CategoryUpdatePayload};
+use sellix_api_models::coupon::{CouponCreatePayload, CouponRaw, CouponUpdatePayload};
+use sellix_api_models::group::{GroupCreatePayload, GroupRaw, GroupUpdatePayload};
+use sellix_api_models::payment::DiscountType;
+use sellix_api_models::validation::ValidationError;
+use sellix_api_models::whitelist::WhitelistUpdatePayload;
+use crate::api::{Blacklist, Whitelist};
+use crate::bulk::{key, Entries};
+use crate::client::Client;
+use crate::resource::Listable;
+
+/// A category, identified by its title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryConfig {
+    pub title: String,
+    #[serde(default)]
+    pub unlisted: bool,
+    #[serde(default)]
+    pub sort_priority: u64,
+    /// The uniqids of the products it contains.
+    #[serde(default)]
+    pub products: Vec<String>,
+    /// The titles of the groups it contains.
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
+impl CategoryConfig {
+    /// The fields which differ from the live category.
+    fn changes(&self, raw: &CategoryRaw) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.unlisted != raw.unlisted {
+            fields.push("unlisted");
+        }
+        if self.sort_priority != raw.sort_priority {
+            fields.push("sort_priority");
+        }
+        if !same_set(&self.products, raw.products_bound.iter().map(|x| &x.uniqid)) {
+            fields.push("products");
+        }
+        if !same_set(&self.groups, raw.groups_bound.iter().map(|x| &x.title)) {
+            fields.push("groups");
+        }
+        fields
+    }
+
+    pub(crate) fn create_payload(&self, groups: &[&str]) -> Result<CategoryCreatePayload, ValidationError> {
+        CategoryCreatePayload::builder(&self.title)
+            .unlisted(self.unlisted)
+            .sort_priority(self.sort_priority)
+            .products_bound(&self.products.iter().map(String::as_str).collect::<Vec<_>>())
+            .groups_bound(groups)
+            .build()
+    }
+
+    fn update_payload<'a>(&'a self, fields: &[&str], groups: Vec<&'a str>) -> CategoryUpdatePayload<'a> {
+        CategoryUpdatePayload {
+            title: None,
+            unlisted: fields.contains(&"unlisted").then_some(self.unlisted),
+            products_bound: fields.contains(&"products").then(|| self.products.iter().map(String::as_str).collect()),
+            groups_bound: fields.contains(&"groups").then_some(groups),
+            sort_priority: fields.contains(&"sort_priority").then_some(self.sort_priority),
+        }
+    }
+}
+
+impl From<&CategoryRaw> for CategoryConfig {
+    /// Describes a live category.
+    fn from(raw: &CategoryRaw) -> Self {
+        Self {
+            title: raw.title.clone(),
+            unlisted: raw.unlisted,
+            sort_priority: raw.sort_priority,
+            products: raw.products_bound.iter().map(|x| x.uniqid.clone()).collect(),
+            groups: raw.groups_bound.iter().map(|x| x.title.clone()).collect(),
+        }
+    }
+}
+
+/// A group, identified by its title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupConfig {
+    pub title: String,
+    #[serde(default)]
+    pub unlisted: bool,
+    #[serde(default)]
+    pub sort_priority: u64,
+    /// The uniqids of the products it contains.
+    #[serde(default)]
+    pub products: Vec<String>,
+}
+impl GroupConfig {
+    /// The fields which differ from the live group.
+    fn changes(&self, raw: &GroupRaw) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.unlisted != raw.unlisted {
+            fields.push("unlisted");
+        }
+        if self.sort_priority != raw.sort_priority {
+            fields.push("sort_priority");
+        }
+        if !same_set(&self.products, raw.products_bound.iter().map(|x| &x.uniqid)) {
+            fields.push("products");
+        }
+        fields
+    }
+
+    pub(crate) fn create_payload(&self) -> Result<GroupCreatePayload, ValidationError> {
+        GroupCreatePayload::builder(&self.title)
+            .unlisted(self.unlisted)
+            .sort_priority(self.sort_priority)
+            .products_bound(&self.products.iter().map(String::as_str).collect::<Vec<_>>())
+            .build()
+    }
+
+    fn update_payload(&self, fields: &[&str]) -> GroupUpdatePayload<'_> {
+        GroupUpdatePayload {
+            title: None,
+            unlisted: fields.contains(&"unlisted").then_some(self.unlisted),
+            products_bound: fields.contains(&"products").then(|| self.products.iter().map(String::as_str).collect()),
+            sort_priority: fields.contains(&"sort_priority").then_some(self.sort_priority),
+        }
+    }
+}
+
+impl From<&GroupRaw> for GroupConfig {
+    /// Describes a live group.
+    fn from(raw: &GroupRaw) -> Self {
+        Self {
+            title: raw.title.clone(),
+            unlisted: raw.unlisted,
+            sort_priority: raw.sort_priority,
+            products: raw.products_bound.iter().map(|x| x.uniqid.clone()).collect(),
+        }
+    }
+}
+
+/// A coupon, identified by its code.
+///
+/// An expiry date or discount type cannot be removed from an existing coupon through the API,
+/// so leaving either out never changes it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CouponConfig {
+    pub code: String,
+    /// The discount, as a percentage unless the discount type is `FIXED`.
+    pub discount: u64,
+    /// Whether the discount is a percentage or a fixed amount, a percentage if left out of a new coupon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_type: Option<DiscountType>,
+    /// How many times the coupon can be used, -1 for unlimited.
+    #[serde(default = "CouponConfig::unlimited")]
+    pub max_uses: i64,
+    /// The uniqids of the products it can be used with, any product if empty.
+    #[serde(default)]
+    pub products: Vec<String>,
+    #[serde(default)]
+    pub disabled_with_volume_discounts: bool,
+    #[serde(default)]
+    pub all_recurring_bill_invoices: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expire_at: Option<Timestamp>,
+}
+impl CouponConfig {
+    fn unlimited() -> i64 {
+        -1
+    }
+
+    /// The fields which differ from the live coupon.
+    fn changes(&self, raw: &CouponRaw) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.discount != raw.discount {
+            fields.push("discount");
+        }
+        if self.discount_type.is_some() && self.discount_type != raw.discount_type {
+            fields.push("discount_type");
+        }
+        if self.max_uses != raw.max_uses {
+            fields.push("max_uses");
+        }
+        if !same_set(&self.products, raw.products_bound.iter().flatten()) {
+            fields.push("products");
+        }
+        if self.disabled_with_volume_discounts != raw.disabled_with_volume_discounts {
+            fields.push("disabled_with_volume_discounts");
+        }
+        if self.all_recurring_bill_invoices != raw.all_recurring_bill_invoices {
+            fields.push("all_recurring_bill_invoices");
+        }
+        if self.expire_at.is_some() && self.expire_at != raw.expire_at {
+            fields.push("expire_at");
+        }
+        fields
+    }
+
+    pub(crate) fn create_payload(&self) -> Result<CouponCreatePayload, ValidationError> {
+        let mut builder = CouponCreatePayload::builder(&self.code, self.discount)
+            .max_uses(self.max_uses)
+            .disabled_with_volume_discounts(self.disabled_with_volume_discounts)
+            .all_recurring_bill_invoices(self.all_recurring_bill_invoices);
+        if !self.products.is_empty() {
+            builder = builder.products_bound(&self.products.iter().map(String::as_str).collect::<Vec<_>>());
+        }
+        if let Some(discount_type) = &self.discount_type {
+            builder = builder.discount_type(discount_type.clone());
+        }
+        if let Some(expire_at) = self.expire_at {
+            builder = builder.expire_at(expire_at);
+        }
+        builder.build()
+    }
+
+    fn update_payload(&self, fields: &[&str]) -> CouponUpdatePayload<'_> {
+        CouponUpdatePayload {
+            code: None,
+            discount_value: fields.contains(&"discount").then_some(self.discount),
+            max_uses: fields.contains(&"max_uses").then_some(self.max_uses),
+            products_bound: fields.contains(&"products").then(|| self.products.iter().map(String::as_str).collect()),
+            discount_type: self.discount_type.clone().filter(|_| fields.contains(&"discount_type")),
+            disabled_with_volume_discounts: fields.contains(&"disabled_with_volume_discounts").then_some(self.disabled_with_volume_discounts),
+            all_recurring_bill_invoices: fields.contains(&"all_recurring_bill_invoices").then_some(self.all_recurring_bill_invoices),
+            expire_at: self.expire_at.filter(|_| fields.contains(&"expire_at")),
+        }
+    }
+}
+
+impl From<&CouponRaw> for CouponConfig {
+    /// Describes a live coupon.
+    fn from(raw: &CouponRaw) -> Self {
+        Self {
+            code: raw.code.clone(),
+            discount: raw.discount,
+            discount_type: raw.discount_type.clone(),
+            max_uses: raw.max_uses,
+            products: raw.products_bound.clone().unwrap_or_default(),
+            disabled_with_volume_discounts: raw.disabled_with_volume_discounts,
+            all_recurring_bill_invoices: raw.all_recurring_bill_invoices,
+            expire_at: raw.expire_at,
+        }
+    }
+}
+
+/// A blacklist or whitelist, identified by its type and data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntryConfig {
+    /// The type of data, e.g. `EMAIL`.
+    pub r#type: String,
+    pub data: String,
+    #[serde(default)]
+    pub note: String,
+}
+
+/// Everything a shop should have.
+/// Each resource left as `None` is not managed, while an empty list deletes every one of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShopConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<CategoryConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<GroupConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coupons: Option<Vec<CouponConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blacklists: Option<Vec<EntryConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub whitelists: Option<Vec<EntryConfig>>,
+}
+impl ShopConfig {
+    /// Parses a TOML file.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(value: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(value)
+    }
+
+    /// Writes a TOML file.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    /// Parses a YAML file.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(value: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(value)
+    }
+
+    /// Writes a YAML file.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Describes everything the live shop has, e.g. to copy it to another shop.
+    pub async fn load(client: &Client) -> Result<Self, SellixError> {
+        Ok(Self {
+            categories: Some(client.category.list_all().await?.iter().map(CategoryConfig::from).collect()),
+            groups: Some(client.group.list_all().await?.iter().map(GroupConfig::from).collect()),
+            coupons: Some(client.coupon.list_all().await?.iter().map(CouponConfig::from).collect()),
+            blacklists: Some(entry_configs::<Blacklist>(&client.blacklist.list_all().await?)),
+            whitelists: Some(entry_configs::<Whitelist>(&client.whitelist.list_all().await?)),
+        })
+    }
+
+    /// Works out the changes needed to make the live shop match the configuration, without making any.
+    ///
+    /// Fails if the configuration is invalid, e.g. has a duplicate or refers to a group which will not exist.
+    pub async fn plan(&self, client: &Client) -> Result<Plan, SellixError> {
+        let mut plan = Plan::default();
+        let mut deletes = Vec::new();
+
+        // Groups, which categories refer to by title
+        let live_groups = client.group.list_all().await?;
+        let mut group_titles: HashSet<&str> = live_groups.iter().map(|x| x.title.as_str()).collect();
+        if let Some(groups) = &self.groups {
+            unique("groups", groups.iter().map(|x| x.title.to_owned()))?;
+            group_titles = groups.iter().map(|x| x.title.as_str()).collect();
+            for config in groups {
+                config.create_payload()?;
+            }
+            diff(&mut plan.changes, &mut deletes, ResourceKind::Group, groups, &live_groups,
+                |x| x.title.clone(), |x| (x.title.clone(), x.uniqid.clone()), GroupConfig::changes, Item::Group);
+        }
+        plan.groups = live_groups.iter().map(|x| (x.title.clone(), x.uniqid.clone())).collect();
+
+        // Categories
+        if let Some(categories) = &self.categories {
+            unique("categories", categories.iter().map(|x| x.title.to_owned()))?;
+            for config in categories {
+                if let Some(group) = config.groups.iter().find(|x| !group_titles.contains(x.as_str())) {
+                    return Err(ValidationError::new("groups", &format!("category {:?} contains group {:?}, which will not exist", config.title, group)).into());
+                }
+                config.create_payload(&[])?;
+            }
+            let live = client.category.list_all().await?;
+            diff(&mut plan.changes, &mut deletes, ResourceKind::Category, categories, &live,
+                |x| x.title.clone(), |x| (x.title.clone(), x.uniqid.clone()), CategoryConfig::changes, Item::Category);
+        }
+
+        // Coupons
+        if let Some(coupons) = &self.coupons {
+            unique("coupons", coupons.iter().map(|x| x.code.to_owned()))?;
+            for config in coupons {
+                config.create_payload()?;
+            }
+            let live = client.coupon.list_all().await?;
+            diff(&mut plan.changes, &mut deletes, ResourceKind::Coupon, coupons, &live,
+                |x| x.code.clone(), |x| (x.code.clone(), x.uniqid.clone()), CouponConfig::changes, Item::Coupon);
+        }
+
+        // Blacklists and whitelists
+        if let Some(blacklists) = &self.blacklists {
+            plan_entries(&mut plan.changes, &mut deletes, ResourceKind::Blacklist, &client.blacklist, blacklists).await?;
+        }
+        if let Some(whitelists) = &self.whitelists {
+            plan_entries(&mut plan.changes, &mut deletes, ResourceKind::Whitelist, &client.whitelist, whitelists).await?;
+        }
+
+        // Deletes go last, with categories before the groups they might contain
+        deletes.sort_by_key(|x: &Change| x.kind != ResourceKind::Category);
+        plan.changes.extend(deletes);
+        Ok(plan)
+    }
+}
+
+/// Describes live blacklists or whitelists.
+fn entry_configs<R: Entries>(list: &[R::Raw]) -> Vec<EntryConfig> {
+    list.iter()
+        .map(|x| {
+            let [_, r#type, data, note] = R::entry(x);
+            EntryConfig { r#type: r#type.to_owned(), data: data.to_owned(), note: note.to_owned() }
+        })
+        .collect()
+}
+
+/// Makes sure there are no duplicate names.
+fn unique(field: &'static str, names: impl Iterator<Item = String>) -> Result<(), ValidationError> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name.clone()) {
+            return Err(ValidationError::new(field, &format!("{:?} is declared more than once", name)));
+        }
+    }
+    Ok(())
+}
+
+/// Whether both contain the same uniqids or titles, in any order.
+fn same_set<'a>(config: &'a [String], live: impl Iterator<Item = &'a String>) -> bool {
+    config.iter().collect::<HashSet<_>>() == live.collect::<HashSet<_>>()
+}
+
+/// Names a blacklist or whitelist entry by its type and data, regardless of case or surrounding whitespace, e.g. `EMAIL name@example.com`.
+fn entry_name(r#type: &str, data: &str) -> String {
+    let (r#type, data) = key(r#type, data);
+    format!("{} {}", r#type, data)
+}
+
+/// Adds the creates and updates needed for every configured resource, and the deletes for every other live one.
+#[allow(clippy::too_many_arguments)]
+fn diff<C: Clone, R>(
+    changes: &mut Vec<Change>,
+    deletes: &mut Vec<Change>,
+    kind: ResourceKind,
+    configs: &[C],
+    live: &[R],
+    name: impl Fn(&C) -> String,
+    live_name: impl Fn(&R) -> (String, String),
+    fields: impl Fn(&C, &R) -> Vec<&'static str>,
+    item: impl Fn(C) -> Item,
+) {
+    let mut live: HashMap<String, (String, &R)> = live.iter()
+        .map(|x| {
+            let (name, uniqid) = live_name(x);
+            (name, (uniqid, x))
+        })
+        .collect();
+
+    for config in configs {
+        let action = match live.remove(&name(config)) {
+            None => Action::Create,
+            Some((uniqid, raw)) => {
+                let fields = fields(config, raw);
+                if fields.is_empty() {
+                    continue;
+                }
+                Action::Update { uniqid, fields }
+            }
+        };
+        changes.push(Change { kind, name: name(config), action, item: Some(item(config.clone())) });
+    }
+
+    let mut remaining: Vec<_> = live.into_iter().collect();
+    remaining.sort_by(|a, b| a.0.cmp(&b.0));
+    for (name, (uniqid, _)) in remaining {
+        deletes.push(Change { kind, name, action: Action::Delete { uniqid }, item: None });
+    }
+}
+
+/// Adds the changes needed for blacklists or whitelists, which are identified by their type and data.
+async fn plan_entries<R: Entries>(changes: &mut Vec<Change>, deletes: &mut Vec<Change>, kind: ResourceKind, resource: &R, entries: &[EntryConfig]) -> Result<(), SellixError> {
+    unique(if kind == ResourceKind::Blacklist { "blacklists" } else { "whitelists" }, entries.iter().map(|x| entry_name(&x.r#type, &x.data)))?;
+    for config in entries {
+        R::payload(&config.r#type.to_uppercase(), &config.data, Some(&config.note).filter(|x| !x.is_empty()).map(String::as_str))?;
+    }
+
+    // Live entries, by type and data
+    let live: Vec<(String, String, String)> = resource.list_all().await?
+        .iter()
+        .map(|x| {
+            let [uniqid, r#type, data, note] = R::entry(x);
+            (uniqid.to_owned(), entry_name(r#type, data), note.to_owned())
+        })
+        .collect();
+    let configs: Vec<(String, EntryConfig)> = entries.iter()
+        .map(|x| (entry_name(&x.r#type, &x.data), x.clone()))
+        .collect();
+
+    diff(changes, deletes, kind, &configs, &live,
+        |(name, _)| name.clone(),
+        |(uniqid, name, _)| (name.clone(), uniqid.clone()),
+        |(_, config), (_, _, note)| if config.note == *note { vec![] } else { vec!["note"] },
+        |(_, config)| Item::Entry(config));
+    Ok(())
+}
+
+/// What a [`Change`] does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    /// Updates the given fields.
+    Update {
+        uniqid: String,
+        fields: Vec<&'static str>,
+    },
+    Delete {
+        uniqid: String,
+    },
+}
+
+/// The configuration of the resource a change makes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Category(CategoryConfig),
+    Group(GroupConfig),
+    Coupon(CouponConfig),
+    Entry(EntryConfig),
+}
+
+/// A single change to a resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ResourceKind,
+    /// What identifies it, e.g. the title of a category or the code of a coupon.
+    pub name: String,
+    pub action: Action,
+    item: Option<Item>,
+}
+impl fmt::Display for Change {
+    /// Formats like `~ category "Games" (unlisted, products)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.action {
+            Action::Create => write!(f, "+ {} {:?}", self.kind, self.name),
+            Action::Update { fields, .. } => write!(f, "~ {} {:?} ({})", self.kind, self.name, fields.join(", ")),
+            Action::Delete { uniqid } => write!(f, "- {} {:?} ({})", self.kind, self.name, uniqid),
+        }
+    }
+}
+
+/// The changes needed to make a shop match its configuration, in the order they are applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+    /// The uniqid of every live group, by title.
+    groups: HashMap<String, String>,
+}
+impl Plan {
+    /// Whether the shop already matches its configuration.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Makes every change, in order.
+    ///
+    /// Stops at the first change which fails, leaving the ones before it made, so the shop should be planned again.
+    pub async fn apply(&self, client: &Client) -> Result<(), SellixError> {
+        let mut groups = self.groups.clone();
+        let group_uniqids = |groups: &HashMap<String, String>, titles: &[String]| -> Result<Vec<String>, ValidationError> {
+            titles.iter()
+                .map(|x| groups.get(x).cloned().ok_or_else(|| ValidationError::new("groups", &format!("group {:?} does not exist", x))))
+                .collect()
+        };
+
+        for change in &self.changes {
+            match (&change.action, &change.item) {
+                (Action::Create, Some(Item::Group(config))) => {
+                    let created = client.group.create(config.create_payload()?).await?;
+                    groups.insert(config.title.clone(), created.uniqid);
+                },
+                (Action::Update { uniqid, fields }, Some(Item::Group(config))) => {
+                    client.group.edit(uniqid, config.update_payload(fields)).await?;
+                },
+                (Action::Create, Some(Item::Category(config))) => {
+                    let uniqids = group_uniqids(&groups, &config.groups)?;
+                    let uniqids: Vec<&str> = uniqids.iter().map(String::as_str).collect();
+                    client.category.create(config.create_payload(&uniqids)?).await?;
+                },
+                (Action::Update { uniqid, fields }, Some(Item::Category(config))) => {
+                    let uniqids = group_uniqids(&groups, &config.groups)?;
+                    let payload = config.update_payload(fields, uniqids.iter().map(String::as_str).collect());
+                    client.category.edit(uniqid, payload).await?;
+                },
+                (Action::Create, Some(Item::Coupon(config))) => {
+                    client.coupon.create(config.create_payload()?).await?;
+                },
+                (Action::Update { uniqid, fields }, Some(Item::Coupon(config))) => {
+                    client.coupon.edit(uniqid, config.update_payload(fields)).await?;
+                },
+                (Action::Create, Some(Item::Entry(config))) => {
+                    let note = Some(config.note.as_str()).filter(|x| !x.is_empty());
+                    let r#type = config.r#type.to_uppercase();
+                    match change.kind {
+                        ResourceKind::Blacklist => client.blacklist.create(Blacklist::payload(&r#type, &config.data, note)?).await?,
+                        _ => client.whitelist.create(Whitelist::payload(&r#type, &config.data, note)?).await?,
+                    };
+                },
+                (Action::Update { uniqid, .. }, Some(Item::Entry(config))) => {
+                    match change.kind {
+                        ResourceKind::Blacklist => client.blacklist.edit(uniqid, BlacklistUpdatePayload { note: Some(&config.note), ..Default::default() }).await?,
+                        _ => client.whitelist.edit(uniqid, WhitelistUpdatePayload { note: Some(&config.note), ..Default::default() }).await?,
+                    };
+                },
+                (Action::Delete { uniqid }, _) => {
+                    match change.kind {
+                        ResourceKind::Category => client.category.delete(uniqid).await?,
+                        ResourceKind::Group => client.group.delete(uniqid).await?,
+                        ResourceKind::Coupon => client.coupon.delete(uniqid).await?,
+                        ResourceKind::Blacklist => client.blacklist.delete(uniqid).await?,
+                        _ => client.whitelist.delete(uniqid).await?,
+                    };
+                },
+                (_, None) => unreachable!("only deletes have no configuration"),
+            }
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for Plan {
+    /// Formats every change on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
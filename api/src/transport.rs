@@ -0,0 +1,117 @@
+// Dependencies
+use std::sync::Arc;
+use sellix_api_models::{SellixError, SellixHttpCode};
+use serde_json::Value;
+use crate::audit::DriftHook;
+use crate::middleware::{Middleware, Next, Request};
+
+// Constants
+const API_BASE: &str = "https://dev.sellix.io/v1";
+
+/// The state shared by every "sub-API" created from the same [`Transport`].
+struct TransportInner {
+    http: reqwest::Client,
+    middleware: Vec<Arc<dyn Middleware>>,
+    on_drift: Option<DriftHook>,
+}
+
+/// A cheaply cloneable handle to the HTTP client used to send requests.
+///
+/// Cloning a [`Transport`] does not create a new connection pool,
+/// so every sub-API (and every merchant) built from it shares the same one, along with its middleware.
+#[derive(Clone)]
+pub struct Transport {
+    inner: Arc<TransportInner>,
+}
+impl Transport {
+    /// Creates a transport from an existing [`reqwest::Client`].
+    pub fn new(http: reqwest::Client) -> Self {
+        Self::with_middleware(http, Vec::new())
+    }
+
+    /// Creates a transport that passes each request through the middleware, in order.
+    /// The first layer is the outermost one.
+    pub fn with_middleware(http: reqwest::Client, middleware: Vec<Arc<dyn Middleware>>) -> Self {
+        Self::from_parts(http, middleware, None)
+    }
+
+    /// Creates a transport from each of its parts.
+    pub(crate) fn from_parts(http: reqwest::Client, middleware: Vec<Arc<dyn Middleware>>, on_drift: Option<DriftHook>) -> Self {
+        Self {
+            inner: Arc::new(TransportInner { http, middleware, on_drift }),
+        }
+    }
+
+    /// The underlying HTTP client.
+    pub fn http(&self) -> &reqwest::Client {
+        &self.inner.http
+    }
+
+    /// The hook called when a response drifts from its model, if auditing is enabled.
+    pub fn on_drift(&self) -> Option<&DriftHook> {
+        self.inner.on_drift.as_ref()
+    }
+
+    /// Whether both handles point to the same shared state.
+    pub fn same_as(&self, other: &Transport) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Passes the request through the middleware and sends it.
+    /// Returns the raw JSON response.
+    pub async fn execute(&self, api_key: &str, request: Request) -> Result<Value, SellixError> {
+        let next = Next {
+            transport: self,
+            api_key,
+            middleware: &self.inner.middleware,
+        };
+        next.run(request).await
+    }
+
+    /// Sends the request, skipping any middleware.
+    pub(crate) async fn send(&self, api_key: &str, request: Request) -> Result<Value, SellixError> {
+        // Create the request, set url, body, headers...
+        let url = reqwest::Url::parse(&(API_BASE.to_owned() + &request.path)).map_err(|error| SellixError {
+            kind: SellixHttpCode::BadRequest.into(),
+            message: format!("invalid request path {:?}: {}", request.path, error),
+        })?;
+        let builder = self.http().request(request.method, url)
+            .header("Authorization", format!("Bearer {}", api_key))
+            .header("X-Sellix-Merchant", request.merchant.unwrap_or(String::from("")))
+            .headers(request.headers)
+            .json(&request.body);
+
+        // Grab the response, which is not always JSON, e.g. from a proxy in front of the API
+        let response = builder.send().await?;
+        let http_status = SellixHttpCode::from(response.status().as_u16());
+        let body = response.text().await?;
+        let json: Value = serde_json::from_str(&body).map_err(|error| SellixError {
+            kind: http_status.clone().into(),
+            message: format!("expected a JSON response with HTTP status {}, but it could not be parsed: {}", http_status.code(), error),
+        })?;
+
+        // Check the status
+        let status = json["status"].as_u64().ok_or_else(|| SellixError {
+            kind: http_status.clone().into(),
+            message: format!("expected a JSON response with an integer status, but got {}", json["status"]),
+        })?;
+        let status_code = u16::try_from(status).map(SellixHttpCode::from).map_err(|_| SellixError {
+            kind: http_status.into(),
+            message: format!("expected a JSON response with an HTTP status, but got {}", status),
+        })?;
+        if status_code == SellixHttpCode::Ok {
+            return Ok(json);
+        }
+
+        // Uh oh error
+        Err(SellixError {
+            message: status_code.to_string(),
+            kind: status_code.into(),
+        })
+    }
+}
+impl Default for Transport {
+    fn default() -> Self {
+        Self::new(reqwest::Client::new())
+    }
+}
@@ -0,0 +1,101 @@
+// Dependencies
+use std::fs;
+use std::sync::Arc;
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::access::{AccessDecision, AccessPolicy, AccessRequest, AccessRule};
+use sellix_rs::sellix_api_models::blacklist::BlacklistTypes;
+use sellix_rs::sellix_api_models::whitelist::WhitelistTypes;
+use sellix_rs::sellix_api_models::{RequestType, SellixError};
+use serde_json::{Value, json};
+
+/// Serves the blacklists and whitelists of the fixtures, plus a few more.
+struct Lists;
+#[async_trait]
+impl Middleware for Lists {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        let (name, key) = match request.request_type {
+            RequestType::BlacklistList => ("blacklist_list", "blacklists"),
+            RequestType::WhitelistList => ("whitelist_list", "whitelists"),
+            request_type => panic!("unexpected request {:?}", request_type),
+        };
+        let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+        let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+        // Only the first page has any entries
+        let entries = json["data"][key].as_array_mut().unwrap();
+        if !request.path.ends_with("?page=1") {
+            entries.clear();
+        } else if key == "blacklists" {
+            let template = entries[0].clone();
+            for (uniqid, kind, data) in [("ip", "IP", "203.0.113.0/24"), ("ip6", "IP", "2001:db8::/32"), ("asn", "ASN", "AS15169"), ("host", "HOST", "proxy.example.net"), ("isp", "ISP", "Bad ISP"), ("domain", "EMAIL", "@spam.test"), ("broken", "IP", "not an ip")] {
+                let mut entry = template.clone();
+                entry["uniqid"] = json!(uniqid);
+                entry["type"] = json!(kind);
+                entry["data"] = json!(data);
+                entries.push(entry);
+            }
+        }
+        Ok(json)
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn access() {
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(Lists))
+        .build();
+    let policy = AccessPolicy::load(&client).await.unwrap();
+
+    // Entries which cannot be parsed are skipped
+    assert_eq!(policy.blacklist().len(), 8);
+    assert_eq!(policy.whitelist().len(), 2);
+    assert_eq!(policy.blacklist()[2].rule, AccessRule::Ip { network: "203.0.113.0".parse().unwrap(), prefix: 24 });
+    assert_eq!(AccessRule::parse_whitelist(&WhitelistTypes::ASN, "AS15169"), Some(AccessRule::Asn(15169)));
+    assert_eq!(AccessRule::parse_blacklist(&BlacklistTypes::Unknown("PHONE".to_owned()), "5550100"), None);
+
+    // Every type of entry
+    let blocked = [
+        ("6488f0a1c3b2e", AccessRequest::new().email("Fraud@Example.com")),
+        ("6488f0a1c3b2f", AccessRequest::new().country("kp")),
+        ("ip", AccessRequest::new().ip("203.0.113.200".parse().unwrap())),
+        ("ip", AccessRequest::new().ip("::ffff:203.0.113.1".parse().unwrap())),
+        ("ip6", AccessRequest::new().ip("2001:db8:1::1".parse().unwrap())),
+        ("asn", AccessRequest::new().asn(15169)),
+        ("host", AccessRequest::new().host("a.proxy.example.net.")),
+        ("isp", AccessRequest::new().isp("bad isp")),
+        ("domain", AccessRequest::new().email("anyone@spam.test")),
+    ];
+    for (uniqid, request) in blocked {
+        match policy.check(&request) {
+            AccessDecision::Blocked(x) => assert_eq!(x.uniqid, uniqid),
+            decision => panic!("{:?} was not blocked: {:?}", request, decision),
+        }
+    }
+
+    // Near misses are allowed
+    let allowed = [
+        AccessRequest::new(),
+        AccessRequest::new().email("fraud@example.co"),
+        AccessRequest::new().ip("203.0.114.1".parse().unwrap()),
+        AccessRequest::new().host("notproxy.example.net"),
+        AccessRequest::new().email("anyone@notspam.test"),
+        AccessRequest::new().country("GB").asn(15170),
+    ];
+    for request in allowed {
+        assert_eq!(policy.check(&request), AccessDecision::Allowed, "{:?} was blocked", request);
+    }
+
+    // Whitelists override blacklists
+    let request = AccessRequest::new().ip("203.0.113.7".parse().unwrap()).country("KP");
+    match policy.check(&request) {
+        AccessDecision::Whitelisted { blocked, whitelisted } => {
+            assert_eq!(blocked.uniqid, "6488f0a1c3b2f");
+            assert_eq!(whitelisted.note, "Office");
+        },
+        decision => panic!("not whitelisted: {:?}", decision),
+    }
+    assert!(!policy.is_blocked(&request));
+    assert!(policy.is_blocked(&AccessRequest::new().ip("203.0.113.8".parse().unwrap())));
+}
@@ -0,0 +1,111 @@
+// Dependencies
+use std::fs;
+use serde_json::{Value, json};
+use sellix_rs::sellix_api_models::{Currencies, Money, Timestamp};
+use sellix_rs::sellix_api_models::analytics::{Period, SalesReport};
+use sellix_rs::sellix_api_models::money::Decimal;
+use sellix_rs::sellix_api_models::order::OrderRaw;
+
+/// An order from the fixtures, which are a completed order of 9.99 EUR and a disputed order of 25 USD with a coupon.
+fn order_with(index: usize, edit: impl FnOnce(&mut Value)) -> OrderRaw {
+    let path = format!("{}/tests/fixtures/order_list.json", env!("CARGO_MANIFEST_DIR"));
+    let json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let mut order = json["data"]["orders"][index].clone();
+    edit(&mut order);
+    serde_json::from_value(order).unwrap()
+}
+
+fn usd(amount: &str) -> Money {
+    Money::new(amount.parse::<Decimal>().unwrap(), Currencies::USD)
+}
+
+fn rate(value: &str) -> Option<Decimal> {
+    Some(value.parse().unwrap())
+}
+
+// Entrypoint
+#[test]
+fn analytics() {
+    let orders = vec![
+        // Completed in June, in EUR
+        order_with(0, |_| {}),
+        // Disputed
+        order_with(1, |_| {}),
+        // Refunded after being completed
+        order_with(0, |x| {
+            x["status"] = json!("REFUNDED");
+            x["status_history"].as_array_mut().unwrap().push(json!({ "id": 3, "invoice_id": "6488f5c1e8a13", "status": "REFUNDED", "details": "", "created_at": 1686100000 }));
+        }),
+        // Completed in July, in USD, with a coupon and no gateway
+        order_with(0, |x| {
+            x["created_at"] = json!(1689292800);
+            x["currency"] = json!("USD");
+            x["total_display"] = json!("9.00");
+            x["exchange_rate"] = json!("1");
+            x["discount"] = json!("1.00");
+            x["coupon_id"] = json!("6488eb12c7d31");
+            x["gateway"] = Value::Null;
+            x["country"] = json!("GB");
+        }),
+        // Never paid
+        order_with(0, |x| {
+            x["status"] = json!("PENDING");
+            x["status_history"].as_array_mut().unwrap().truncate(1);
+        }),
+    ];
+    let report = SalesReport::from_orders(Period::Month, &orders);
+
+    // Only completed orders make revenue, normalized to USD
+    assert_eq!((report.orders, report.paid, report.refunded, report.disputed), (5, 4, 1, 1));
+    assert_eq!(report.total.orders, 2);
+    assert_eq!(report.total.revenue, usd("19.870119"));
+    assert_eq!(report.total.revenue.to_string(), "19.87 USD");
+
+    // Grouped by month, product, gateway and country
+    let months: Vec<(i64, u64)> = report.by_period.iter().map(|(x, sales)| (x.unix(), sales.orders)).collect();
+    assert_eq!(months, [(1685577600, 1), (1688169600, 1)]);
+    assert_eq!(report.by_product["6488e1b7d2c90"].orders, 2);
+    assert!(!report.by_product.contains_key("6488e1b7d2c91"));
+    assert_eq!(report.by_gateway["BITCOIN"].revenue, usd("10.870119"));
+    assert_eq!(report.by_gateway[""].revenue, usd("9.00"));
+    assert_eq!(report.by_country["GB"].orders, 1);
+    assert_eq!(report.by_country["US"].orders, 1);
+
+    // Rates
+    assert_eq!(report.refund_rate(), rate("0.25"));
+    assert_eq!(report.dispute_rate(), rate("0.25"));
+    assert_eq!(report.coupon_rate(), rate("0.5"));
+    let coupon = &report.coupons["6488eb12c7d31"];
+    assert_eq!((coupon.orders, &coupon.revenue, &coupon.discount), (1, &usd("9.00"), &usd("1.00")));
+    assert_eq!(coupon.discount_rate(), rate("0.1"));
+
+    // Orders can also be added one at a time
+    let mut streamed = SalesReport::new(Period::Month);
+    for order in &orders {
+        streamed.add(order);
+    }
+    assert_eq!(streamed, report);
+
+    // Without any paid orders, there are no rates
+    let empty = SalesReport::from_orders(Period::Day, Vec::<OrderRaw>::new());
+    assert_eq!((empty.refund_rate(), empty.dispute_rate(), empty.coupon_rate()), (None, None, None));
+}
+
+#[test]
+fn analytics_periods() {
+    let start = |period: Period, unix: i64| period.start(Timestamp::from_unix(unix)).unix();
+
+    // Monday 5th June 2023, 21:20
+    assert_eq!(start(Period::Day, 1686000000), 1685923200);
+    assert_eq!(start(Period::Week, 1686000000), 1685923200);
+    assert_eq!(start(Period::Month, 1686000000), 1685577600);
+
+    // The last second of Sunday is still within the week
+    assert_eq!(start(Period::Week, 1685923200 + 7 * 86400 - 1), 1685923200);
+    assert_eq!(start(Period::Week, 1685923200 + 7 * 86400), 1685923200 + 7 * 86400);
+
+    // Leap days, and before the unix epoch
+    assert_eq!(start(Period::Month, 1709208000), 1706745600);
+    assert_eq!(start(Period::Month, -1468800), -2678400);
+    assert_eq!(start(Period::Day, -1), -86400);
+}
@@ -0,0 +1,105 @@
+// Dependencies
+use std::fs;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use sellix_rs::Client;
+use sellix_rs::audit::{audit, DriftReport};
+use sellix_rs::middleware::{Middleware, Next, Request};
+use sellix_rs::sellix_api_models::{RequestType, SellixError};
+use sellix_rs::sellix_api_models::blacklist::{BlacklistGetResponseRaw, BlacklistListResponseRaw};
+use serde_json::{Value, json};
+
+/// Reads a fixture, by its name.
+fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Answers every request with the same response, so nothing is sent over the network.
+struct Stub(Value);
+#[async_trait]
+impl Middleware for Stub {
+    async fn handle(&self, _request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        Ok(self.0.clone())
+    }
+}
+
+// Entrypoint
+#[test]
+fn audit_models() {
+    // A response matching its model has no drift
+    let (_, drift) = audit::<BlacklistGetResponseRaw>(&fixture("blacklist_get")).unwrap();
+    assert!(drift.is_empty(), "{}", drift);
+
+    // A field was added, and another was removed
+    let mut json = fixture("blacklist_get");
+    let blacklist = json["data"]["blacklist"].as_object_mut().unwrap();
+    blacklist.insert("risk_score".to_owned(), json!(12));
+    blacklist.remove("updated_at");
+    let (_, drift) = audit::<BlacklistGetResponseRaw>(&json).unwrap();
+    assert_eq!(drift.unknown.iter().collect::<Vec<_>>(), ["/data/blacklist/risk_score"]);
+    assert_eq!(drift.missing.iter().collect::<Vec<_>>(), ["/data/blacklist/updated_at"]);
+
+    // Within a list, each field is only reported once
+    let mut json = fixture("blacklist_list");
+    for blacklist in json["data"]["blacklists"].as_array_mut().unwrap() {
+        blacklist.as_object_mut().unwrap().insert("risk_score".to_owned(), json!(12));
+    }
+    let (list, drift) = audit::<BlacklistListResponseRaw>(&json).unwrap();
+    assert_eq!(list.data.unwrap().blacklists.len(), 2);
+    assert_eq!(drift.unknown.iter().collect::<Vec<_>>(), ["/data/blacklists/*/risk_score"]);
+    assert!(drift.missing.is_empty());
+}
+
+#[tokio::test]
+async fn audit_client() {
+    let mut json = fixture("blacklist_get");
+    json["data"]["blacklist"]["risk_score"] = json!(12);
+
+    // Each drifted response is reported
+    let reports: Arc<Mutex<Vec<DriftReport>>> = Arc::default();
+    let recorded = reports.clone();
+    let client = Client::builder("api_key")
+        .on_drift(move |report| recorded.lock().unwrap().push(report.clone()))
+        .middleware(Arc::new(Stub(json)))
+        .build();
+    let blacklist = client.blacklist.get("6488f0a1c3b2e").await.unwrap();
+    assert_eq!(blacklist.uniqid, "6488f0a1c3b2e");
+
+    let reports = reports.lock().unwrap().clone();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].request_type, RequestType::BlacklistGet);
+    assert_eq!(reports[0].path, "/blacklists/6488f0a1c3b2e");
+    assert!(reports[0].drift.unknown.contains("/data/blacklist/risk_score"));
+
+    // A response matching its model is not
+    let counted = Arc::new(Mutex::new(0));
+    let count = counted.clone();
+    let client = Client::builder("api_key")
+        .on_drift(move |_| *count.lock().unwrap() += 1)
+        .middleware(Arc::new(Stub(fixture("blacklist_get"))))
+        .build();
+    client.blacklist.get("6488f0a1c3b2e").await.unwrap();
+    assert_eq!(*counted.lock().unwrap(), 0);
+
+    // A response which cannot be deserialized is reported, then returned as an error
+    let mut json = fixture("blacklist_get");
+    json["data"]["blacklist"]["uniqid"] = json!(12);
+    let reports: Arc<Mutex<Vec<DriftReport>>> = Arc::default();
+    let recorded = reports.clone();
+    let client = Client::builder("api_key")
+        .on_drift(move |report| recorded.lock().unwrap().push(report.clone()))
+        .middleware(Arc::new(Stub(json.clone())))
+        .build();
+    let error = client.blacklist.get("6488f0a1c3b2e").await.unwrap_err();
+    assert!(error.message.contains("unable to deserialize"), "{}", error);
+    let reports = reports.lock().unwrap().clone();
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].drift.error.is_some());
+
+    // Also without auditing
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(Stub(json)))
+        .build();
+    assert!(client.blacklist.get("6488f0a1c3b2e").await.is_err());
+}
@@ -0,0 +1,164 @@
+// Dependencies
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::backup::{Backup, RestoreOutcome, BACKUP_VERSION};
+use sellix_rs::sellix_api_models::{RequestAction, RequestType, ResourceKind, SellixError};
+use serde_json::{Value, json};
+
+/// Serves the fixtures, or only the coupons when restoring, and records every change made.
+struct Shop {
+    restoring: bool,
+    changes: Mutex<Vec<(RequestType, Option<Value>)>>,
+}
+impl Shop {
+    fn new(restoring: bool) -> Self {
+        Self { restoring, changes: Mutex::default() }
+    }
+}
+#[async_trait]
+impl Middleware for Shop {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        let list = match request.request_type {
+            RequestType::OrderList => Some(("order_list", "orders")),
+            RequestType::CustomerList => Some(("customer_list", "customers")),
+            RequestType::CouponList => Some(("coupon_list", "coupons")),
+            RequestType::CategoryList => Some(("category_list", "categories")),
+            RequestType::GroupList => Some(("group_list", "groups")),
+            RequestType::BlacklistList => Some(("blacklist_list", "blacklists")),
+            RequestType::WhitelistList => Some(("whitelist_list", "whitelists")),
+            RequestType::QueryList => Some(("query_list", "queries")),
+            RequestType::SubscriptionList => Some(("subscription_list", "subscriptions")),
+            RequestType::FeedbackList => Some(("feedback_list", "feedbacks")),
+            _ => None,
+        };
+        if let Some((name, key)) = list {
+            let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+            let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+            let existing = !self.restoring || request.request_type == RequestType::CouponList;
+            if !existing || !request.path.ends_with("?page=1") {
+                json["data"][key].as_array_mut().unwrap().clear();
+            }
+            return Ok(json);
+        }
+
+        let mut changes = self.changes.lock().unwrap();
+        assert_eq!(request.request_type.action(), RequestAction::Create);
+        changes.push((request.request_type, request.body));
+        Ok(json!({ "status": 200, "data": { "uniqid": format!("new{}", changes.len()) }, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn backup() {
+    let source = Client::builder("api_key")
+        .merchant("source")
+        .middleware(Arc::new(Shop::new(false)))
+        .build();
+
+    // Every page of every resource is kept
+    let backup = Backup::create(&source).await.unwrap();
+    assert_eq!(backup.version, BACKUP_VERSION);
+    assert_eq!(backup.merchant.as_deref(), Some("source"));
+    let counts = [
+        backup.orders.len(), backup.customers.len(), backup.coupons.len(), backup.categories.len(), backup.groups.len(),
+        backup.blacklists.len(), backup.whitelists.len(), backup.queries.len(), backup.subscriptions.len(), backup.feedback.len(),
+    ];
+    assert_eq!(counts, [2, 2, 2, 2, 1, 2, 2, 2, 2, 2]);
+
+    // The archive can be read back, unless it is from a newer version
+    let archive = backup.to_json();
+    let backup = Backup::from_json(&archive).unwrap();
+    assert_eq!(backup.to_json(), archive);
+
+    let mut newer: Value = serde_json::from_str(&archive).unwrap();
+    newer["version"] = json!(BACKUP_VERSION + 1);
+    assert!(Backup::from_json(&newer.to_string()).unwrap_err().to_string().contains("newer"));
+}
+
+#[tokio::test]
+async fn restore() {
+    let source = Client::builder("api_key")
+        .middleware(Arc::new(Shop::new(false)))
+        .build();
+    let backup = Backup::create(&source).await.unwrap();
+
+    // Restore to a shop which only has the coupons, with one product remapped
+    let target = Arc::new(Shop::new(true));
+    let client = Client::builder("api_key")
+        .merchant("target")
+        .middleware(target.clone())
+        .build();
+    let products = HashMap::from([("6488e1b7d2c90".to_owned(), "new_product".to_owned())]);
+    let report = backup.restore(&client, &products).await.unwrap();
+    assert!(report.errors().is_empty());
+
+    let changes = target.changes.lock().unwrap().clone();
+    let requests: Vec<RequestType> = changes.iter().map(|(x, _)| *x).collect();
+    assert_eq!(requests, [
+        RequestType::GroupCreate,
+        RequestType::CategoryCreate,
+        RequestType::CategoryCreate,
+        RequestType::BlacklistCreate,
+        RequestType::BlacklistCreate,
+        RequestType::WhitelistCreate,
+        RequestType::WhitelistCreate,
+        RequestType::CustomerCreate,
+        RequestType::CustomerCreate,
+    ]);
+
+    // Products and groups refer to the ones on the restored shop
+    let group = changes[0].1.as_ref().unwrap();
+    assert_eq!(group["products_bound"], json!(["new_product", "6488e1b7d2c91"]));
+    let category = changes[1].1.as_ref().unwrap();
+    assert_eq!(category["title"], "Licence Keys");
+    assert_eq!(category["products_bound"], json!(["new_product"]));
+    assert_eq!(category["groups_bound"], json!(["new1"]));
+    assert_eq!(report.uniqid(ResourceKind::Group, "6488e9c0b1a77"), Some("new1"));
+
+    // Existing coupons are not created again
+    assert!(matches!(&report.items[3].outcome, RestoreOutcome::Existing(uniqid) if uniqid == "6488eb12c7d31"));
+    assert_eq!(report.uniqid(ResourceKind::Coupon, "6488eb12c7d32"), Some("6488eb12c7d32"));
+    assert_eq!(report.items.len(), 11);
+
+    // Customers only set the fields they have
+    let customer = changes[8].1.as_ref().unwrap();
+    assert_eq!((&customer["city"], &customer["country_code"]), (&json!("London"), &json!("GB")));
+    assert_eq!((&customer["state"], &customer["additional_address_info"]), (&Value::Null, &Value::Null));
+
+    // A category is created without any group which was not restored
+    let mut backup = backup;
+    backup.groups.clear();
+    let target = Arc::new(Shop::new(true));
+    let client = Client::builder("api_key")
+        .middleware(target.clone())
+        .build();
+    let report = backup.restore(&client, &products).await.unwrap();
+    let category = target.changes.lock().unwrap()[0].1.clone().unwrap();
+    assert_eq!(category["groups_bound"], json!([]));
+    assert!(matches!(
+        &report.items[0].outcome,
+        RestoreOutcome::Incomplete { uniqid, missing_groups } if uniqid == "new1" && missing_groups == &["6488e9c0b1a77"]
+    ));
+    assert_eq!(report.errors().len(), 1);
+
+    // Fixed coupons are restored as fixed, even when worth over 100
+    let mut archive: Value = serde_json::from_str(&backup.to_json()).unwrap();
+    archive["coupons"][0]["code"] = json!("BIG150");
+    archive["coupons"][0]["discount_type"] = json!("FIXED");
+    archive["coupons"][0]["discount"] = json!(150);
+    let backup = Backup::from_json(&archive.to_string()).unwrap();
+    let target = Arc::new(Shop::new(true));
+    let client = Client::builder("api_key")
+        .middleware(target.clone())
+        .build();
+    let report = backup.restore(&client, &products).await.unwrap();
+    assert!(matches!(report.items.iter().find(|x| x.kind == ResourceKind::Coupon).map(|x| &x.outcome), Some(RestoreOutcome::Created(_))));
+    let changes = target.changes.lock().unwrap().clone();
+    let (_, coupon) = changes.iter().find(|(x, _)| *x == RequestType::CouponCreate).unwrap();
+    let coupon = coupon.as_ref().unwrap();
+    assert_eq!((&coupon["code"], &coupon["discount_type"], &coupon["discount_value"]), (&json!("BIG150"), &json!("FIXED"), &json!(150)));
+}
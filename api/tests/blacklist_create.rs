@@ -22,11 +22,11 @@ async fn blacklist_create() {
     let blacklist_client = client.blacklist;
 
     // Create a blacklist
-    let blacklist = blacklist_client.create(BlacklistCreatePayload {
-        r#type: BlacklistTypes::Email,
-        data: "test@example.com",
-        note: Some("Testing")
-    }).await;
+    let blacklist = blacklist_client.create(BlacklistCreatePayload::builder(BlacklistTypes::Email, "test@example.com")
+        .note("Testing")
+        .build()
+        .expect("invalid blacklist")
+    ).await;
     assert!(blacklist.is_ok(), "unable to create blacklist");
     std::env::set_var("BLACKLIST_UNIQID", blacklist.unwrap().uniqid);
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 // Dependencies
 use dotenv::dotenv;
-use sellix_rs::{Client, sellix_api_models::blacklist::{BlacklistCreatePayload, BlacklistTypes}};
+use sellix_rs::{Client, sellix_api_models::blacklist::{BlacklistUpdatePayload, BlacklistTypes}};
 use std::env;
 
 // Entrypoint
@@ -23,9 +23,9 @@ async fn blacklist_update() {
     let blacklist_client = client.blacklist;
 
     // Update the blacklist
-    let blacklist = blacklist_client.edit(&blacklist_uniqid, BlacklistCreatePayload {
-        r#type: BlacklistTypes::Email,
-        data: "test2@example.com",
+    let blacklist = blacklist_client.edit(&blacklist_uniqid, BlacklistUpdatePayload {
+        r#type: Some(BlacklistTypes::Email),
+        data: Some("test2@example.com"),
         note: Some("Testing")
     }).await;
     assert!(blacklist.is_ok(), "unable to edit blacklist");
@@ -0,0 +1,111 @@
+// Dependencies
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::bulk::ImportOutcome;
+use sellix_rs::sellix_api_models::{RequestType, SellixError, SellixHttpCode};
+use serde_json::{Value, json};
+
+/// Serves the blacklists of the fixtures, and creates new ones, keeping track of how many are created at once.
+#[derive(Default)]
+struct Blacklists {
+    created: Mutex<Vec<Value>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+#[async_trait]
+impl Middleware for Blacklists {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        match request.request_type {
+            RequestType::BlacklistList => {
+                let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/blacklist_list.json");
+                let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+                if !request.path.ends_with("?page=1") {
+                    json["data"]["blacklists"].as_array_mut().unwrap().clear();
+                }
+                Ok(json)
+            },
+            RequestType::BlacklistCreate => {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let body = request.body.unwrap();
+                if body["data"] == "rejected@example.com" {
+                    return Err(SellixError { kind: SellixHttpCode::BadRequest.into(), message: "rejected".to_owned() });
+                }
+                let mut created = self.created.lock().unwrap();
+                created.push(body);
+                Ok(json!({ "status": 200, "data": { "uniqid": format!("new{}", created.len()) }, "message": null, "log": null, "error": null, "env": "production" }))
+            },
+            request_type => panic!("unexpected request {:?}", request_type),
+        }
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn bulk() {
+    let blacklists = Arc::new(Blacklists::default());
+    let client = Client::builder("api_key")
+        .middleware(blacklists.clone())
+        .build();
+
+    let csv = "\
+type,data,note
+email,new@example.com,\"Chargeback, twice\"
+EMAIL,FRAUD@example.com,Already blacklisted
+IP,10.0.0.0/8,
+
+IP,10.0.0.0/8,Repeated
+COUNTRY,Korea,Not a country code
+PHONE,123,Unknown type
+EMAIL,rejected@example.com,
+ASN,AS15169,
+ISP,Bad ISP,
+EMAIL,a@b.com,extra,column
+";
+    let report = client.blacklist.import_csv(csv, 2).await.unwrap();
+
+    // Every row reports its outcome, in order
+    let outcomes: Vec<(usize, String)> = report.rows.iter()
+        .map(|x| (x.line, match &x.outcome {
+            ImportOutcome::Created(_) => "created".to_owned(),
+            ImportOutcome::Duplicate(uniqid) => format!("duplicate of {}", uniqid),
+            ImportOutcome::DuplicateRow(line) => format!("duplicate of line {}", line),
+            ImportOutcome::Invalid(error) => format!("invalid {}", error.field),
+            ImportOutcome::Failed(error) => format!("failed {}", error.message),
+        }))
+        .collect();
+    assert_eq!(outcomes, [
+        (2, "created".to_owned()),
+        (3, "duplicate of 6488f0a1c3b2e".to_owned()),
+        (4, "created".to_owned()),
+        (6, "duplicate of line 4".to_owned()),
+        (7, "invalid data".to_owned()),
+        (8, "invalid type".to_owned()),
+        (9, "failed rejected".to_owned()),
+        (10, "created".to_owned()),
+        (11, "created".to_owned()),
+        (12, "invalid row".to_owned()),
+    ]);
+    assert_eq!(report.created().len(), 4);
+    assert_eq!(report.duplicates().len(), 2);
+    assert_eq!(report.errors().len(), 4);
+
+    // The payloads were built from the rows, with bounded concurrency
+    let created = blacklists.created.lock().unwrap().clone();
+    assert_eq!(created[0], json!({ "type": "EMAIL", "data": "new@example.com", "note": "Chargeback, twice" }));
+    assert_eq!(blacklists.max_in_flight.load(Ordering::SeqCst), 2);
+
+    // Exports can be imported again, without creating anything
+    let export = client.blacklist.export_csv().await.unwrap();
+    assert_eq!(export, "type,data,note\nEMAIL,fraud@example.com,Chargeback on a previous order\nCOUNTRY,KP,\n");
+    let report = client.blacklist.import_csv(&export, 2).await.unwrap();
+    assert!(report.created().is_empty());
+    assert_eq!(report.duplicates().len(), 2);
+}
@@ -0,0 +1,106 @@
+// Dependencies
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use async_trait::async_trait;
+use sellix_rs::{Client, transport::Transport};
+use sellix_rs::cache::{Cache, MemoryLru};
+use sellix_rs::middleware::{Middleware, Next, Request};
+use sellix_rs::sellix_api_models::{RequestAction, ResourceKind, SellixError};
+use serde_json::{Value, json};
+
+/// Answers every request itself, counting how many reach it.
+#[derive(Default)]
+struct Stub {
+    hits: AtomicU64,
+}
+#[async_trait]
+impl Middleware for Stub {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        let data = match request.request_type.action() {
+            RequestAction::List => json!({ "blacklists": [], "whitelists": [] }),
+            _ => Value::Null
+        };
+        Ok(json!({
+            "status": 200,
+            "data": data,
+            "message": null,
+            "log": null,
+            "error": null,
+            "env": "production"
+        }))
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn cache() {
+    // Put the cache in front of the stub
+    let stub = Arc::new(Stub::default());
+    let cache = Arc::new(Cache::new(MemoryLru::new(16)).ttl(ResourceKind::Whitelist, Duration::ZERO));
+    let client = Client::builder("api_key")
+        .middleware(cache.clone())
+        .middleware(stub.clone())
+        .build();
+
+    // The second list is served from the cache
+    client.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    client.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 1);
+    assert_eq!(cache.backend().len(), 1);
+
+    // Another merchant does not share the entry
+    let other = client.for_merchant("other_shop");
+    other.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 2);
+
+    // Deleting invalidates the resource, for that merchant only
+    client.blacklist.delete("uniqid").await.expect("unable to delete blacklist");
+    assert_eq!(cache.backend().len(), 1);
+    client.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 4);
+
+    // Whitelists expire straight away
+    client.whitelist.get_list(Some(1)).await.expect("unable to list whitelists");
+    client.whitelist.get_list(Some(1)).await.expect("unable to list whitelists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 6);
+}
+
+#[tokio::test]
+async fn cache_api_keys() {
+    // Two API keys share the same transport, and so the same cache
+    let stub = Arc::new(Stub::default());
+    let cache = Arc::new(Cache::new(MemoryLru::new(16)));
+    let transport = Transport::with_middleware(reqwest::Client::new(), vec![cache.clone(), stub.clone()]);
+    let first = Client::with_transport("first_key", None, transport.clone());
+    let second = Client::with_transport("second_key", None, transport);
+
+    // Each key has its own entry
+    first.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    second.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 2);
+    assert_eq!(cache.backend().len(), 2);
+    second.blacklist.get_list(Some(1)).await.expect("unable to list blacklists");
+    assert_eq!(stub.hits.load(Ordering::Relaxed), 2);
+
+    // Deleting only invalidates the entries of the key it was made with
+    first.blacklist.delete("uniqid").await.expect("unable to delete blacklist");
+    assert_eq!(cache.backend().len(), 1);
+}
+
+#[tokio::test]
+async fn cache_lru_eviction() {
+    use sellix_rs::cache::CacheBackend;
+
+    // Fill the cache, then touch the first entry so the second is evicted
+    let lru = MemoryLru::new(2);
+    lru.insert("a".into(), json!(1), Duration::from_secs(60)).await;
+    lru.insert("b".into(), json!(2), Duration::from_secs(60)).await;
+    assert_eq!(lru.get("a").await, Some(json!(1)));
+    lru.insert("c".into(), json!(3), Duration::from_secs(60)).await;
+
+    assert_eq!(lru.len(), 2);
+    assert_eq!(lru.get("b").await, None);
+    assert_eq!(lru.get("c").await, Some(json!(3)));
+}
@@ -22,13 +22,11 @@ async fn category_create() {
     let category_client = client.category;
 
     // Create a category
-    let category = category_client.create(CategoryCreatePayload {
-        title: "Software",
-        unlisted: Some(false),
-        products_bound: None,
-        groups_array: None,
-        sort_priority: None
-    }).await;
+    let category = category_client.create(CategoryCreatePayload::builder("Software")
+        .unlisted(false)
+        .build()
+        .expect("invalid category")
+    ).await;
     assert!(category.is_ok(), "unable to create categories");
     std::env::set_var("CATEGORY_UNIQID", category.unwrap().uniqid);
 }
\ No newline at end of file
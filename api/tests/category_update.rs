@@ -1,6 +1,6 @@
 // Dependencies
 use dotenv::dotenv;
-use sellix_rs::{Client, sellix_api_models::category::CategoryCreatePayload};
+use sellix_rs::{Client, sellix_api_models::category::CategoryUpdatePayload};
 use std::env;
 
 // Entrypoint
@@ -23,12 +23,10 @@ async fn category_update() {
     let category_client = client.category;
 
     // Edit the category
-    let category = category_client.edit(&category_uniqid, CategoryCreatePayload {
-        title: "Software",
+    let category = category_client.edit(&category_uniqid, CategoryUpdatePayload {
+        title: Some("Software"),
         unlisted: Some(false),
-        products_bound: None,
-        groups_array: None,
-        sort_priority: None
+        ..Default::default()
     }).await;
     assert!(category.is_ok(), "unable to edit category");
 }
\ No newline at end of file
@@ -22,17 +22,10 @@ async fn category_create() {
     let coupon_client = client.coupon;
 
     // Create a coupon
-    let coupon = coupon_client.create(CouponCreatePayload {
-        code: "test_coupon",
-        discount_value: 3,
-        max_uses: None,
-        products_bound: None,
-        discount_type: None,
-        discount_order_type: None,
-        disabled_with_volume_discounts: None,
-        all_recurring_bill_invoices: None,
-        expire_at: None
-    }).await;
+    let coupon = coupon_client.create(CouponCreatePayload::builder("test_coupon", 3)
+        .build()
+        .expect("invalid coupon")
+    ).await;
     assert!(coupon.is_ok(), "unable to create coupons");
     std::env::set_var("COUPON_UNIQID", coupon.unwrap().uniqid);
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 // Dependencies
 use dotenv::dotenv;
-use sellix_rs::{Client, sellix_api_models::coupon::CouponCreatePayload};
+use sellix_rs::{Client, sellix_api_models::coupon::CouponUpdatePayload};
 use std::env;
 
 // Entrypoint
@@ -23,16 +23,9 @@ async fn coupon_update() {
     let coupon_client = client.coupon;
 
     // Update the coupon
-    let coupon = coupon_client.edit(&coupon_uniqid, CouponCreatePayload {
-        code: "test_coupon",
-        discount_value: 3,
-        max_uses: None,
-        products_bound: None,
-        discount_type: None,
-        discount_order_type: None,
-        disabled_with_volume_discounts: None,
-        all_recurring_bill_invoices: None,
-        expire_at: None
+    let coupon = coupon_client.edit(&coupon_uniqid, CouponUpdatePayload {
+        max_uses: Some(10),
+        ..Default::default()
     }).await;
     assert!(coupon.is_ok(), "unable to edit coupon");
 }
\ No newline at end of file
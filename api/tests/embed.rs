@@ -0,0 +1,55 @@
+// Dependencies
+use std::fs;
+use sellix_rs::EmbedButton;
+use sellix_rs::embed::{EmbedTheme, EMBED_SCRIPT, EMBED_STYLESHEET};
+use sellix_rs::sellix_api_models::group::GroupOneRaw;
+use sellix_rs::sellix_api_models::payment::PaymentGateway;
+use sellix_rs::sellix_api_models::product::ProductRaw;
+use sellix_rs::sellix_api_models::webhook::RawWebsocketRequest;
+use sellix_rs::sellix_api_models::RawAPIResponse;
+
+/// Reads a fixture.
+fn fixture(name: &str) -> String {
+    fs::read_to_string(format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+// Entrypoint
+#[test]
+fn embed() {
+    // Every option, with everything escaped
+    let button = EmbedButton::product("product1")
+        .label("<b>Buy</b> & save")
+        .quantity(3)
+        .gateway(PaymentGateway::Litecoin)
+        .coupon_code("\"SAVE\"")
+        .theme(EmbedTheme::Light)
+        .add_custom_field("discord", "user#1234 'x'")
+        .add_custom_field("age", "18");
+    assert_eq!(
+        button.build().unwrap(),
+        concat!(
+            r#"<button type="submit" alt="Buy Now with sellix.io" data-sellix-product="product1" data-sellix-quantity="3" data-sellix-gateway="LITECOIN" "#,
+            r#"data-sellix-coupon="&quot;SAVE&quot;" data-sellix-theme="light" data-sellix-custom-age="18" data-sellix-custom-discord="user#1234 &#39;x&#39;">"#,
+            r#"&lt;b&gt;Buy&lt;/b&gt; &amp; save</button>"#
+        )
+    );
+
+    // The snippet includes the script and stylesheet
+    let snippet = EmbedButton::group("group1").snippet().unwrap();
+    assert!(snippet.starts_with(&format!("<script src=\"{}\"></script>\n<link href=\"{}\"", EMBED_SCRIPT, EMBED_STYLESHEET)));
+    assert!(snippet.ends_with(r#"data-sellix-group="group1">Purchase</button>"#));
+
+    // Straight from the models
+    let product: RawWebsocketRequest<ProductRaw> = serde_json::from_str(&fixture("webhooks/product_created")).unwrap();
+    let button = EmbedButton::from(&product.data).build().unwrap();
+    assert!(button.contains(&format!("data-sellix-product=\"{}\"", product.data.uniqid)));
+    assert!(button.ends_with(&format!(">{}</button>", product.data.title)));
+
+    let group: RawAPIResponse<GroupOneRaw> = serde_json::from_str(&fixture("group_get")).unwrap();
+    let group = group.data.unwrap().group;
+    assert_eq!(EmbedButton::from(&group), EmbedButton::group(&group.uniqid).label(&group.title));
+
+    // Invalid buttons are rejected
+    assert_eq!(EmbedButton::product("").build().unwrap_err().field, "uniqid");
+    assert_eq!(EmbedButton::product("product1").add_custom_field("a\" onclick=\"x", "").build().unwrap_err().field, "custom_fields");
+}
@@ -0,0 +1,40 @@
+// Dependencies
+use std::sync::Arc;
+use async_trait::async_trait;
+use reqwest::Method;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::sellix_api_models::{RequestType, SellixError};
+use sellix_rs::sellix_api_models::feedback::FeedbackReplyPayload;
+use serde_json::{Value, json};
+
+/// Makes sure the reply is sent to the right route, then answers it without going over the network.
+struct Stub;
+#[async_trait]
+impl Middleware for Stub {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        assert_eq!(request.request_type, RequestType::FeedbackReply);
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.path, "/feedback/reply/abc%2F123");
+        assert_eq!(request.body, Some(json!({ "reply": "Thank you!" })));
+        Ok(json!({ "status": 200, "data": null, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn endpoint() {
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(Stub))
+        .build();
+
+    // Generated from `#[endpoint(...)]`
+    let payload = FeedbackReplyPayload {
+        reply: "Thank you!"
+    };
+    let replied = client.feedback.reply("abc/123", payload).await;
+    assert!(replied.unwrap(), "unable to reply through the stub");
+
+    // The reply alone is still accepted, as before `#[endpoint(...)]`
+    let replied = client.feedback.reply("abc/123", "Thank you!").await;
+    assert!(replied.unwrap(), "unable to reply with a string through the stub");
+}
@@ -0,0 +1,112 @@
+// Dependencies
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use sellix_rs::sellix_api_models::*;
+
+/// Makes sure the value is the same after going through JSON, and through a string.
+fn round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + Display + FromStr + PartialEq + Debug,
+    T::Err: Debug,
+{
+    let json = serde_json::to_value(value).unwrap();
+    assert_eq!(json, Value::String(value.to_string()));
+    assert_eq!(&serde_json::from_value::<T>(json).unwrap(), value);
+    assert_eq!(&value.to_string().parse::<T>().unwrap(), value);
+}
+
+/// Makes sure each enum round-trips every known variant, along with a variant it has not seen before.
+macro_rules! assert_forward_compatible {
+    ($($ty:ty),* $(,)?) => {$(
+        for known in <$ty>::KNOWN {
+            assert!(!known.is_unknown(), "{:?} is unknown", known);
+            round_trip(known);
+        }
+
+        let unseen: $ty = serde_json::from_value(json!("SOMETHING_NEW")).unwrap();
+        assert_eq!(unseen, <$ty>::Unknown("SOMETHING_NEW".to_owned()));
+        round_trip(&unseen);
+    )*};
+}
+
+/// Makes sure each enum of codes round-trips every known variant, along with a code it has not seen before.
+macro_rules! assert_forward_compatible_codes {
+    ($($ty:ty),* $(,)?) => {$(
+        for known in <$ty>::KNOWN {
+            assert!(!known.is_unknown(), "{:?} is unknown", known);
+            assert_eq!(<$ty>::from(known.code()), *known);
+            let json = serde_json::to_value(known).unwrap();
+            assert_eq!(json, json!(known.code()));
+            assert_eq!(&serde_json::from_value::<$ty>(json).unwrap(), known);
+        }
+
+        let unseen: $ty = serde_json::from_value(json!(999)).unwrap();
+        assert_eq!(unseen, <$ty>::Unknown(999));
+        assert_eq!(serde_json::to_value(&unseen).unwrap(), json!(999));
+    )*};
+}
+
+// Entrypoint
+#[test]
+fn enums() {
+    assert_forward_compatible!(
+        Currencies,
+        WeekDays,
+        YearMonths,
+        blacklist::BlacklistScope,
+        blacklist::BlacklistTypes,
+        coupon::CouponType,
+        coupon::CouponUseType,
+        dispute::DisputeReason,
+        dispute::DisputeStatus,
+        dispute::DisputeOutcome,
+        dispute::DisputeStage,
+        dispute::DisputeMessage,
+        events::Order,
+        events::Query,
+        events::Feedback,
+        events::Product,
+        events::Subscription,
+        events::Event,
+        feedback::AppealOutcome,
+        invoice::InvoiceStatus,
+        invoice::InvoiceStatusDetails,
+        invoice::InvoiceVoidDetails,
+        order::OrderType,
+        order::OrderHistoryStatus,
+        payment::DiscountType,
+        payment::PaymentGateway,
+        payment::PaymentGateways,
+        payment::PayPalAPM,
+        product::ProductType,
+        product::ProductSubType,
+        query::QueryStatus,
+        subscription::RecurringBillingIntervals,
+        subscription::SubscriptionStatus,
+        whitelist::WhitelistTypes,
+    );
+    assert_forward_compatible_codes!(
+        SellixHttpCode,
+        feedback::FeedbackScore,
+    );
+
+    // Known values keep their wire format
+    assert_eq!(serde_json::to_value(payment::PaymentGateway::UsdtErc20).unwrap(), "USDT:ERC20");
+    assert_eq!(serde_json::to_value(events::Event::SubscriptionTrialStarted).unwrap(), "subscription:trial:started");
+
+    // An unseen value does not fail the rest of the response
+    let gateways: Vec<payment::PaymentGateway> = serde_json::from_value(json!(["BITCOIN", "DOGECOIN"])).unwrap();
+    assert_eq!(gateways, vec![
+        payment::PaymentGateway::Bitcoin,
+        payment::PaymentGateway::Unknown("DOGECOIN".to_owned()),
+    ]);
+    let response: RawAPIResponse<Value> = serde_json::from_value(json!({ "status": 418, "data": null, "message": null, "log": null, "error": null, "env": "production" })).unwrap();
+    assert_eq!(response.status, SellixHttpCode::Unknown(418));
+
+    // Scores are written as names, and parsed from names or codes
+    assert_eq!(feedback::FeedbackScore::Five.to_string(), "Five");
+    assert_eq!("Five".parse::<feedback::FeedbackScore>().unwrap(), feedback::FeedbackScore::Five);
+    assert_eq!("7".parse::<feedback::FeedbackScore>().unwrap(), feedback::FeedbackScore::Unknown(7));
+}
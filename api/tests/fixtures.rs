@@ -0,0 +1,187 @@
+// Dependencies
+use std::fs;
+use std::path::Path;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use sellix_rs::sellix_api_models::*;
+use sellix_rs::sellix_api_models::events::Event;
+use sellix_rs::sellix_api_models::webhook::RawWebsocketRequest;
+
+// Constants
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Fields the API sometimes sends as `null`, as `(fixture, JSON pointer)`.
+/// Each one is set to `null` within the fixture, which must still round-trip.
+const NULLABLE: &[(&str, &str)] = &[
+    ("blacklist_get", "/data/blacklist/updated_at"),
+    ("whitelist_get", "/data/whitelist/updated_at"),
+    ("category_get", "/data/category/updated_at"),
+    ("category_get", "/data/category/products_bound/0/image_attachment"),
+    ("category_get", "/data/category/products_bound/0/subtype"),
+    ("coupon_get", "/data/coupon/expire_at"),
+    ("coupon_get", "/data/coupon/products_bound"),
+    ("coupon_get", "/data/coupon/currency"),
+    ("feedback_get", "/data/feedback/reply"),
+    ("feedback_get", "/data/feedback/appeal_outcome"),
+    ("feedback_get", "/data/feedback/updated_by"),
+    ("order_get", "/data/order/gateway"),
+    ("order_get", "/data/order/crypto_address"),
+    ("order_get", "/data/order/crypto_uri"),
+    ("order_get", "/data/order/coupon_id"),
+    ("order_get", "/data/order/paypal_dispute"),
+    ("order_get", "/data/order/product_variants"),
+    ("order_get", "/data/order/bill_info"),
+    ("order_get", "/data/order/file"),
+    ("order_get", "/data/order/crypto_transactions/0/updated_at"),
+    ("order_list", "/data/orders/1/paypal_dispute/outcome"),
+    ("query_get", "/data/query/invoice_id"),
+    ("subscription_get", "/data/subscription/gateway"),
+    ("subscription_get", "/data/subscription/coupon_id"),
+    ("subscription_get", "/data/subscription/canceled_at"),
+    ("subscription_get", "/data/subscription/customer_phone"),
+];
+
+/// Reads a fixture, by its path relative to the fixtures directory.
+fn read(path: &str) -> Value {
+    let contents = fs::read_to_string(Path::new(FIXTURES).join(path)).unwrap();
+    serde_json::from_str(&contents).unwrap()
+}
+
+/// Makes sure `actual` has the same object keys as `expected`, everywhere.
+/// Values themselves are not compared, as e.g. a decimal sent as a number is serialized back as a string.
+fn assert_same_shape(path: &str, expected: &Value, actual: &Value) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            let missing: Vec<_> = expected.keys().filter(|x| !actual.contains_key(*x)).collect();
+            let extra: Vec<_> = actual.keys().filter(|x| !expected.contains_key(*x)).collect();
+            assert!(missing.is_empty(), "{}: dropped {:?}", path, missing);
+            assert!(extra.is_empty(), "{}: not in the fixture {:?}", path, extra);
+
+            for (key, value) in expected {
+                assert_same_shape(&format!("{}/{}", path, key), value, &actual[key]);
+            }
+        },
+        (Value::Array(expected), Value::Array(actual)) => {
+            assert_eq!(expected.len(), actual.len(), "{}: length differs", path);
+            for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                assert_same_shape(&format!("{}/{}", path, i), expected, actual);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Deserializes the fixture as `T`, and makes sure nothing is lost when serializing it back.
+fn round_trip<T: Serialize + DeserializeOwned>(name: &str, json: Value) {
+    let model: T = serde_json::from_value(json.clone())
+        .unwrap_or_else(|error| panic!("{}: {}", name, error));
+    let serialized = serde_json::to_value(&model).unwrap();
+    assert_same_shape(name, &json, &serialized);
+
+    let again = serde_json::to_value(serde_json::from_value::<T>(serialized.clone()).unwrap()).unwrap();
+    assert_eq!(serialized, again, "{}: differs after a second round-trip", name);
+}
+
+/// Round-trips a response fixture, picking the model from the fixture's name.
+fn round_trip_response(name: &str, label: &str, json: Value) {
+    match name {
+        "blacklist_get" => round_trip::<blacklist::BlacklistGetResponseRaw>(label, json),
+        "blacklist_list" => round_trip::<blacklist::BlacklistListResponseRaw>(label, json),
+        "whitelist_get" => round_trip::<whitelist::WhitelistGetResponseRaw>(label, json),
+        "whitelist_list" => round_trip::<whitelist::WhitelistListResponseRaw>(label, json),
+        "category_get" => round_trip::<category::CategoryGetResponseRaw>(label, json),
+        "category_list" => round_trip::<category::CategoryListResponseRaw>(label, json),
+        "coupon_get" => round_trip::<coupon::CouponGetResponseRaw>(label, json),
+        "coupon_list" => round_trip::<coupon::CouponListResponseRaw>(label, json),
+        "feedback_get" => round_trip::<feedback::FeedbackGetResponseRaw>(label, json),
+        "feedback_list" => round_trip::<feedback::FeedbackListResponseRaw>(label, json),
+        "order_get" => round_trip::<order::OrderGetResponseRaw>(label, json),
+        "order_list" => round_trip::<order::OrderListResponseRaw>(label, json),
+        "group_get" => round_trip::<group::GroupGetResponseRaw>(label, json),
+        "group_list" => round_trip::<group::GroupListResponseRaw>(label, json),
+        "customer_get" => round_trip::<customer::CustomerGetResponseRaw>(label, json),
+        "customer_list" => round_trip::<customer::CustomerListResponseRaw>(label, json),
+        "query_get" => round_trip::<query::QueryGetResponseRaw>(label, json),
+        "query_list" => round_trip::<query::QueryListResponseRaw>(label, json),
+        "subscription_get" => round_trip::<subscription::SubscriptionGetResponseRaw>(label, json),
+        "subscription_list" => round_trip::<subscription::SubscriptionListResponseRaw>(label, json),
+        _ => panic!("{}: no model for this fixture", label),
+    }
+}
+
+/// Round-trips a webhook fixture, picking the model from its event.
+fn round_trip_webhook(name: &str, json: Value) {
+    let event = json["event"].as_str().unwrap();
+    assert_eq!(event.replace(':', "_"), name, "{}: named after a different event", name);
+
+    match event.split(':').next().unwrap() {
+        "order" => round_trip::<RawWebsocketRequest<order::OrderRaw>>(name, json),
+        "query" => round_trip::<RawWebsocketRequest<query::QueryRaw>>(name, json),
+        "feedback" => round_trip::<RawWebsocketRequest<feedback::FeedbackRaw>>(name, json),
+        "product" => round_trip::<RawWebsocketRequest<product::ProductRaw>>(name, json),
+        "subscription" => round_trip::<RawWebsocketRequest<subscription::SubscriptionRaw>>(name, json),
+        _ => panic!("{}: no model for this event", name),
+    }
+}
+
+/// The names of the fixtures within a directory, without their extension.
+fn fixtures(directory: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(Path::new(FIXTURES).join(directory)).unwrap()
+        .map(|x| x.unwrap().path())
+        .filter(|x| x.extension().is_some_and(|x| x == "json"))
+        .map(|x| x.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+// Entrypoint
+#[test]
+fn fixtures_responses() {
+    let names = fixtures("");
+    for resource in ["blacklist", "whitelist", "category", "coupon", "feedback", "order", "group", "customer", "query", "subscription"] {
+        for action in ["get", "list"] {
+            let name = format!("{}_{}", resource, action);
+            assert!(names.contains(&name), "missing the {} fixture", name);
+        }
+    }
+
+    for name in names {
+        let json = read(&format!("{}.json", name));
+        round_trip_response(&name, &name, json);
+    }
+}
+
+#[test]
+fn fixtures_webhooks() {
+    let names = fixtures("webhooks");
+    for event in Event::KNOWN {
+        // Product events send the same payload as the event they are for, so are built from its fixture
+        let event = event.to_string();
+        let Some(base) = event.strip_suffix(":product") else {
+            assert!(names.contains(&event.replace(':', "_")), "missing the {} webhook fixture", event);
+            continue;
+        };
+        let base = base.replace(':', "_");
+        assert!(names.contains(&base), "missing the {} webhook fixture, which {} is built from", base, event);
+        let mut json = read(&format!("webhooks/{}.json", base));
+        json["event"] = Value::String(event.clone());
+        round_trip_webhook(&event.replace(':', "_"), json);
+    }
+
+    for name in names {
+        let json = read(&format!("webhooks/{}.json", name));
+        round_trip_webhook(&name, json);
+    }
+}
+
+#[test]
+fn fixtures_nullable() {
+    for (name, pointer) in NULLABLE {
+        let mut json = read(&format!("{}.json", name));
+        let field = json.pointer_mut(pointer).unwrap_or_else(|| panic!("{}: {} does not exist", name, pointer));
+        *field = Value::Null;
+
+        round_trip_response(name, &format!("{} with {} as null", name, pointer), json);
+    }
+}
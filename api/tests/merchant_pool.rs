@@ -0,0 +1,27 @@
+// Dependencies
+use sellix_rs::{Client, MerchantPool};
+
+// Entrypoint
+#[tokio::test]
+async fn merchant_pool() {
+    // Create a pool of merchants
+    let pool = MerchantPool::new("api_key", &["shop_a", "shop_b"]);
+
+    // Each client is for its own merchant, but they all share the same transport
+    let client_a = pool.client("shop_a");
+    let client_b = client_a.for_merchant("shop_b");
+    assert_eq!(client_b.merchant(), Some("shop_b"));
+    assert_eq!(client_b.order.merchant.as_deref(), Some("shop_b"));
+    assert!(client_a.transport().same_as(client_b.transport()));
+    assert!(client_b.coupon.transport.same_as(client_a.transport()));
+
+    // Fan out to every merchant
+    let merchants = pool.fan_out(|client| async move { Ok(client.merchant().map(|x| x.to_owned())) }).await;
+    assert_eq!(merchants.len(), 2);
+    assert_eq!(merchants["shop_a"].as_ref().unwrap().as_deref(), Some("shop_a"));
+    assert_eq!(merchants["shop_b"].as_ref().unwrap().as_deref(), Some("shop_b"));
+
+    // A standalone client has its own transport
+    let standalone = Client::new("api_key", None);
+    assert!(!standalone.transport().same_as(client_a.transport()));
+}
@@ -0,0 +1,68 @@
+// Dependencies
+use std::sync::Arc;
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request, ReadOnly, RequestCounter}};
+use sellix_rs::sellix_api_models::{RequestType, SellixError, SellixErrorKind, SellixHttpCode};
+use serde_json::{Value, json};
+
+/// Answers every request itself, so nothing is sent over the network.
+struct Stub;
+#[async_trait]
+impl Middleware for Stub {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        assert_eq!(request.request_type, RequestType::CouponDestroy);
+        assert_eq!(request.merchant.as_deref(), Some("my_shop"));
+        Ok(json!({ "status": 200, "data": null, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+// Entrypoint
+#[tokio::test]
+async fn middleware() {
+    // Requests pass through the counter first, then get answered by the stub
+    let counter = Arc::new(RequestCounter::default());
+    let client = Client::builder("api_key")
+        .merchant("my_shop")
+        .middleware(counter.clone())
+        .middleware(Arc::new(Stub))
+        .build();
+    let deleted = client.coupon.delete("uniqid").await;
+    assert!(deleted.unwrap(), "unable to delete through the stub");
+    assert_eq!(counter.count(), 1);
+
+    // Read-only mode short-circuits before the stub is reached
+    let client = Client::builder("api_key")
+        .merchant("my_shop")
+        .middleware(counter.clone())
+        .middleware(Arc::new(ReadOnly))
+        .middleware(Arc::new(Stub))
+        .build();
+    let deleted = client.coupon.delete("uniqid").await;
+    assert_eq!(deleted.unwrap_err().kind, SellixHttpCode::Forbidden);
+    assert_eq!(counter.count(), 2);
+}
+
+/// Answers every request successfully, but without any data.
+struct Empty;
+#[async_trait]
+impl Middleware for Empty {
+    async fn handle(&self, _request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        Ok(json!({ "status": 200, "data": null, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+#[tokio::test]
+async fn middleware_errors() {
+    // A response without data is an error, rather than a panic
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(Empty))
+        .build();
+    assert_eq!(client.coupon.get("uniqid").await.unwrap_err().kind, SellixErrorKind::Decode);
+    assert_eq!(client.coupon.get_list(None).await.unwrap_err().kind, SellixErrorKind::Decode);
+
+    // Requests which never get a response are not mistaken for an API status
+    let error = reqwest::get("http://127.0.0.1:9/").await.unwrap_err();
+    let error = SellixError::from(error);
+    assert_eq!(error.kind, SellixErrorKind::Transport);
+    assert_ne!(error.kind, SellixHttpCode::ServiceUnavailable);
+}
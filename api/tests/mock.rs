@@ -0,0 +1,102 @@
+#![cfg(feature = "mock")]
+
+// Dependencies
+use std::fs;
+use std::sync::Arc;
+use serde_json::{Value, json};
+use sellix_rs::api::{MockCoupon, MockFeedback, MockOrder};
+use sellix_rs::resource::{Gettable, Listable, Editable, Deletable};
+use sellix_rs::sellix_api_models::{SellixError, SellixHttpCode};
+use sellix_rs::sellix_api_models::coupon::{CouponRaw, CouponUpdatePayload};
+use sellix_rs::sellix_api_models::feedback::FeedbackReplyPayload;
+use sellix_rs::sellix_api_models::order::{OrderArray, OrderRaw};
+
+/// A coupon, used `used` times out of 100.
+fn coupon(uniqid: &str, used: u64) -> CouponRaw {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/coupon_get.json");
+    let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    json["data"]["coupon"]["uniqid"] = json!(uniqid);
+    json["data"]["coupon"]["used"] = json!(used);
+    serde_json::from_value(json["data"]["coupon"].take()).unwrap()
+}
+
+/// Business logic under test: deletes a coupon once it has been used up, otherwise raises its limit by 10.
+async fn retire_coupon<R>(coupons: &R, uniqid: &str) -> Result<bool, SellixError>
+where
+    R: Gettable<Raw = CouponRaw> + Deletable + for<'a> Editable<UpdatePayload<'a> = CouponUpdatePayload<'a>>
+{
+    let coupon = coupons.get(uniqid).await?;
+    if coupon.used as i64 >= coupon.max_uses {
+        return coupons.delete(uniqid).await;
+    }
+
+    let payload = CouponUpdatePayload {
+        max_uses: Some(coupon.max_uses + 10),
+        ..Default::default()
+    };
+    coupons.edit(uniqid, payload).await.map(|_| false)
+}
+
+// Entrypoint
+#[tokio::test]
+async fn mock() {
+    let coupons = MockCoupon::new();
+    coupons.get.expect().with("used_up".to_owned()).returning(|_| Ok(coupon("used_up", 100)));
+    coupons.get.expect().with("in_use".to_owned()).returning(|_| Ok(coupon("in_use", 3)));
+    coupons.delete.expect().times(1).returning(|_| Ok(true));
+    coupons.edit.expect().returning(|_| Ok(true));
+
+    // A used up coupon is deleted, the other is edited
+    assert!(retire_coupon(&coupons, "used_up").await.unwrap());
+    assert!(!retire_coupon(&coupons, "in_use").await.unwrap());
+    assert_eq!(coupons.get.call_count(), 2);
+    assert_eq!(coupons.delete.calls(), ["used_up"]);
+    assert_eq!(coupons.edit.calls(), [("in_use".to_owned(), json!({ "max_uses": 110 }))]);
+    coupons.verify();
+
+    // Errors are returned as the API would
+    let coupons = MockCoupon::new();
+    coupons.get.expect().returning_error(SellixHttpCode::NotFound);
+    let error = retire_coupon(&coupons, "missing").await.unwrap_err();
+    assert_eq!(error.kind, SellixHttpCode::NotFound);
+    assert_eq!(coupons.delete.call_count(), 0);
+
+    // Generic helpers work through the mock too
+    let orders = MockOrder::new();
+    orders.get_list.expect().with(Some(1)).returning(|_| Ok(serde_json::from_value::<OrderArray>(json!({ "orders": [] })).unwrap()));
+    let all: Vec<OrderRaw> = orders.list_all().await.unwrap();
+    assert!(all.is_empty());
+}
+
+#[tokio::test]
+async fn mock_endpoint() {
+    let feedback = MockFeedback::new();
+    feedback.reply.expect().times(1).returning(|_| Ok(true));
+
+    // Custom endpoints are mocked like the default methods
+    let payload = FeedbackReplyPayload {
+        reply: "Thank you!"
+    };
+    assert!(feedback.reply("abc", payload).await.unwrap());
+    assert_eq!(feedback.reply.calls(), [("abc".to_owned(), json!({ "reply": "Thank you!" }))]);
+    feedback.verify();
+}
+
+#[tokio::test]
+async fn mock_reentrant() {
+    // A responder can call back into the mock it belongs to
+    let coupons = Arc::new(MockCoupon::new());
+    let inner = Arc::downgrade(&coupons);
+    coupons.get.expect().with("in_use".to_owned()).returning(|_| Ok(coupon("in_use", 3)));
+    coupons.get.expect().with("alias".to_owned()).returning(move |_| inner.upgrade().unwrap().get.call("in_use".to_owned()));
+
+    assert_eq!(coupons.get("alias").await.unwrap().uniqid, "in_use");
+    assert_eq!(coupons.get.calls(), ["alias", "in_use"]);
+}
+
+#[tokio::test]
+#[should_panic(expected = "unexpected call to MockCoupon::delete(\"abc\")")]
+async fn mock_unexpected() {
+    let coupons = MockCoupon::new();
+    let _ = coupons.delete("abc").await;
+}
@@ -0,0 +1,42 @@
+// Dependencies
+use std::str::FromStr;
+use sellix_rs::sellix_api_models::{Currencies, Money};
+use sellix_rs::sellix_api_models::money::Decimal;
+use sellix_rs::sellix_api_models::order::{ProductVariant, VolumeDiscount};
+
+// Entrypoint
+#[test]
+fn money() {
+    // Both the string and number forms deserialize, without losing precision
+    let variant: ProductVariant = serde_json::from_value(serde_json::json!({
+        "price": 0.1,
+        "title": "Basic",
+        "description": "",
+    })).unwrap();
+    assert_eq!(variant.price, Decimal::from_str("0.1").unwrap());
+
+    let discount: VolumeDiscount = serde_json::from_value(serde_json::json!({
+        "type": "FIXED",
+        "value": "2.50",
+        "quantity": 3,
+    })).unwrap();
+    assert_eq!(discount.value, Decimal::new(250, 2));
+
+    // Serializes as a string
+    assert_eq!(serde_json::to_value(&variant).unwrap()["price"], "0.1");
+
+    // Arithmetic is exact
+    let price = Money::new(variant.price, Currencies::USD);
+    let total = price.clone() + price.clone() + price.clone();
+    assert_eq!(total.amount, Decimal::from_str("0.3").unwrap());
+    assert_eq!(total.to_minor_units(), Some(30));
+
+    // Different currencies do not mix
+    assert_eq!(price.checked_add(&Money::zero(Currencies::EUR)), None);
+
+    // Formatted to each currency's minor units
+    assert_eq!(total.to_string(), "0.30 USD");
+    assert_eq!(Money::new(Decimal::new(12345, 1), Currencies::JPY).to_string(), "1235 JPY");
+    assert_eq!(Money::from_minor_units(1234, Currencies::GBP).to_string(), "12.34 GBP");
+    assert_eq!(Money::from_minor_units(1234, Currencies::KRW).to_string(), "1234 KRW");
+}
@@ -0,0 +1,172 @@
+#![cfg(feature="sqlite")]
+
+// Dependencies
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::order_sync::{OrderStore, OrderSyncError};
+use sellix_rs::resource::MAX_PAGES;
+use sellix_rs::sellix_api_models::{RequestType, SellixError, SellixErrorKind, SellixHttpCode, Timestamp};
+use sellix_rs::sellix_api_models::order::OrderRaw;
+use sellix_rs::sellix_api_models::webhook::RawWebsocketRequest;
+use serde_json::{Value, json};
+
+/// Serves the orders, newest first, two to a page.
+struct Orders {
+    orders: Mutex<Vec<Value>>,
+    /// A page which fails to be retrieved.
+    failing_page: Mutex<Option<usize>>,
+    /// Whether the page number is ignored, and the first page always served.
+    ignore_page: Mutex<bool>,
+    /// Whether every page is served with a new order, created after the others.
+    endless: Mutex<bool>,
+}
+impl Orders {
+    /// Orders `o1` to `o5`, created one after another.
+    fn new() -> Self {
+        let path = format!("{}/tests/fixtures/order_list.json", env!("CARGO_MANIFEST_DIR"));
+        let json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        let orders = (1..=5).rev()
+            .map(|x| {
+                let mut order = json["data"]["orders"][0].clone();
+                order["uniqid"] = json!(format!("o{}", x));
+                order["created_at"] = json!(x * 100);
+                order["updated_at"] = Value::Null;
+                order
+            })
+            .collect();
+        Self { orders: Mutex::new(orders), failing_page: Mutex::default(), ignore_page: Mutex::default(), endless: Mutex::default() }
+    }
+}
+#[async_trait]
+impl Middleware for Orders {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        assert_eq!(request.request_type, RequestType::OrderList);
+        let mut page: usize = request.path.rsplit('=').next().unwrap().parse().unwrap();
+        if *self.ignore_page.lock().unwrap() {
+            page = 1;
+        }
+        if *self.failing_page.lock().unwrap() == Some(page) {
+            return Err(SellixError { kind: SellixHttpCode::InternalServerError.into(), message: "unavailable".to_owned() });
+        }
+
+        if *self.endless.lock().unwrap() {
+            let mut order = self.orders.lock().unwrap()[0].clone();
+            order["uniqid"] = json!(format!("p{}", page));
+            order["created_at"] = json!(1000 + page);
+            return Ok(json!({ "status": 200, "data": { "orders": [order] }, "message": null, "log": null, "error": null, "env": "production" }));
+        }
+
+        let orders: Vec<Value> = self.orders.lock().unwrap().iter().skip((page - 1) * 2).take(2).cloned().collect();
+        Ok(json!({ "status": 200, "data": { "orders": orders }, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+/// Counts the rows of a table.
+fn count(store: &OrderStore, table: &str) -> u64 {
+    store.connection().query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0)).unwrap()
+}
+
+// Entrypoint
+#[tokio::test]
+async fn order_sync() {
+    let orders = Arc::new(Orders::new());
+    let client = Client::builder("api_key")
+        .middleware(orders.clone())
+        .build();
+    let mut store = OrderStore::open_in_memory().unwrap().look_back(Duration::from_secs(100));
+
+    // An interrupted sync keeps the pages already written
+    *orders.failing_page.lock().unwrap() = Some(2);
+    assert!(matches!(store.sync(&client).await, Err(OrderSyncError::Api(_))));
+    assert_eq!(count(&store, "orders"), 2);
+    assert_eq!(store.checkpoint().unwrap(), None);
+
+    // Then resumes from the page which failed
+    *orders.failing_page.lock().unwrap() = None;
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.fetched, report.changed), (3, 3, 3));
+    assert_eq!(report.checkpoint, Some(Timestamp::from_unix(500)));
+    assert_eq!(store.checkpoint().unwrap(), Some(Timestamp::from_unix(500)));
+    assert_eq!(count(&store, "orders"), 5);
+    assert_eq!(count(&store, "order_status_history"), 10);
+    assert_eq!(count(&store, "order_crypto_transactions"), 5);
+    assert_eq!(count(&store, "order_custom_fields"), 5);
+
+    // Nothing has changed since, so only the pages with orders created within the look-back window are retrieved
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.changed), (2, 0));
+
+    // A changed order is written again
+    orders.orders.lock().unwrap()[0]["updated_at"] = json!(600);
+    orders.orders.lock().unwrap()[0]["status"] = json!("REFUNDED");
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.changed), (2, 1));
+    assert_eq!(store.checkpoint().unwrap(), Some(Timestamp::from_unix(600)));
+    let status: String = store.connection().query_row("SELECT status FROM orders WHERE uniqid = 'o5'", [], |row| row.get(0)).unwrap();
+    assert_eq!(status, "REFUNDED");
+    assert_eq!(count(&store, "order_status_history"), 10);
+
+    // As is an older order on the second page
+    orders.orders.lock().unwrap()[2]["updated_at"] = json!(650);
+    orders.orders.lock().unwrap()[2]["status"] = json!("REFUNDED");
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.changed), (2, 1));
+    assert_eq!(store.checkpoint().unwrap(), Some(Timestamp::from_unix(650)));
+    let status: String = store.connection().query_row("SELECT status FROM orders WHERE uniqid = 'o3'", [], |row| row.get(0)).unwrap();
+    assert_eq!(status, "REFUNDED");
+
+    // But not once it was created before the look-back window
+    orders.orders.lock().unwrap()[4]["updated_at"] = json!(700);
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.changed), (1, 0));
+
+    // Webhooks are written, unless they are older than the order already written
+    let mut event: RawWebsocketRequest<OrderRaw> = serde_json::from_value(json!({
+        "event": "order:updated",
+        "data": orders.orders.lock().unwrap()[0].clone(),
+    })).unwrap();
+    event.data.updated_at = Some(Timestamp::from_unix(550));
+    assert!(!store.upsert_event(&event).unwrap());
+    event.data.updated_at = Some(Timestamp::from_unix(700));
+    event.data.custom_fields.insert("Telegram".to_owned(), "@jane".to_owned());
+    assert!(store.upsert_event(&event).unwrap());
+    assert!(!store.upsert_event(&event).unwrap());
+    assert_eq!(count(&store, "order_custom_fields"), 6);
+
+    // Every page is retrieved again once reset
+    store.reset().unwrap();
+    let report = store.sync(&client).await.unwrap();
+    assert_eq!((report.pages, report.fetched), (4, 5));
+}
+
+#[tokio::test]
+async fn order_sync_endless() {
+    let orders = Arc::new(Orders::new());
+    let client = Client::builder("api_key")
+        .middleware(orders.clone())
+        .build();
+    let mut store = OrderStore::open_in_memory().unwrap();
+
+    // A page repeating the one before gives up, rather than retrieving it forever
+    *orders.ignore_page.lock().unwrap() = true;
+    match store.sync(&client).await {
+        Err(OrderSyncError::Api(x)) => assert_eq!(x.kind, SellixErrorKind::TooManyPages),
+        x => panic!("expected too many pages, got {:?}", x),
+    }
+    assert_eq!(count(&store, "orders"), 2);
+    assert_eq!(store.checkpoint().unwrap(), None);
+
+    // As do new pages past the bound
+    *orders.ignore_page.lock().unwrap() = false;
+    *orders.endless.lock().unwrap() = true;
+    store.reset().unwrap();
+    match store.sync(&client).await {
+        Err(OrderSyncError::Api(x)) => assert_eq!(x.kind, SellixErrorKind::TooManyPages),
+        x => panic!("expected too many pages, got {:?}", x),
+    }
+    assert_eq!(count(&store, "orders"), 2 + MAX_PAGES);
+    assert_eq!(store.checkpoint().unwrap(), None);
+}
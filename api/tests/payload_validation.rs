@@ -0,0 +1,36 @@
+// Dependencies
+use sellix_rs::sellix_api_models::blacklist::{BlacklistCreatePayload, BlacklistTypes};
+use sellix_rs::sellix_api_models::coupon::CouponCreatePayload;
+use sellix_rs::sellix_api_models::customer::CustomerCreatePayload;
+use sellix_rs::sellix_api_models::payment::DiscountType;
+
+// Entrypoint
+#[test]
+fn payload_validation() {
+    // Coupons
+    assert!(CouponCreatePayload::builder("SUMMER", 20).build().is_ok());
+    assert_eq!(CouponCreatePayload::builder(" ", 20).build().unwrap_err().field, "code");
+    assert_eq!(CouponCreatePayload::builder("SUMMER", 120).build().unwrap_err().field, "discount_value");
+    assert!(CouponCreatePayload::builder("SUMMER", 120).discount_type(DiscountType::Fixed).build().is_ok());
+
+    // Customers
+    let customer = CustomerCreatePayload::builder("John", "Doe", "john@example.com")
+        .country_code("GB")
+        .city("London")
+        .build()
+        .expect("invalid customer");
+    assert_eq!(customer.city.as_deref(), Some("London"));
+    assert!(customer.phone.is_none());
+    assert_eq!(CustomerCreatePayload::builder("John", "Doe", "john.example.com").build().unwrap_err().field, "email");
+    assert_eq!(CustomerCreatePayload::builder("John", "Doe", "john@example.com").country_code("England").build().unwrap_err().field, "country_code");
+
+    // Blacklists are validated against their type
+    assert!(BlacklistCreatePayload::builder(BlacklistTypes::IP, "10.0.0.0/8").build().is_ok());
+    assert!(BlacklistCreatePayload::builder(BlacklistTypes::IP, "10.0.0.0/33").build().is_err());
+    assert!(BlacklistCreatePayload::builder(BlacklistTypes::Country, "RU").build().is_ok());
+    assert!(BlacklistCreatePayload::builder(BlacklistTypes::Email, "not an email").build().is_err());
+
+    // Built payloads are owned, so they can be sent across tasks
+    fn assert_send<T: Send + 'static>(_: &T) {}
+    assert_send(&customer);
+}
@@ -0,0 +1,103 @@
+// Dependencies
+use std::fs;
+use serde_json::{Value, json};
+use sellix_rs::sellix_api_models::{Currencies, Money, Timestamp};
+use sellix_rs::sellix_api_models::coupon::CouponRaw;
+use sellix_rs::sellix_api_models::money::Decimal;
+use sellix_rs::sellix_api_models::payment::DiscountType;
+use sellix_rs::sellix_api_models::pricing::{AppliedDiscount, CouponRejection, PricingEngine, PricingError};
+use sellix_rs::sellix_api_models::product::ProductRaw;
+
+/// Reads a fixture, at a JSON pointer.
+fn fixture(name: &str, pointer: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    json.pointer(pointer).unwrap().clone()
+}
+
+/// A product costing 9.99 EUR, with 5% off 10 or more.
+fn product_with(edit: impl FnOnce(&mut Value)) -> ProductRaw {
+    let mut json = fixture("webhooks/product_created", "/data");
+    edit(&mut json);
+    serde_json::from_value(json).unwrap()
+}
+
+/// A coupon for 10% off the product, used 3 times out of 100.
+fn coupon_with(edit: impl FnOnce(&mut Value)) -> CouponRaw {
+    let mut json = fixture("coupon_get", "/data/coupon");
+    edit(&mut json);
+    serde_json::from_value(json).unwrap()
+}
+
+fn eur(amount: &str) -> Money {
+    Money::new(amount.parse::<Decimal>().unwrap(), Currencies::EUR)
+}
+
+// Entrypoint
+#[test]
+fn pricing() {
+    // Before the coupon expires
+    let engine = PricingEngine::at(Timestamp::from_unix(1690000000));
+    let product = product_with(|_| {});
+
+    // Just the coupon
+    let quote = engine.quote(&product, 2, Some(&coupon_with(|_| {}))).unwrap();
+    assert_eq!(quote.subtotal, eur("19.98"));
+    assert_eq!(quote.discounts, [AppliedDiscount::Coupon { code: "SUMMER10".to_owned(), type_field: DiscountType::Percentage, value: Decimal::from(10u64), amount: eur("2.00") }]);
+    assert_eq!(quote.total, eur("17.98"));
+    assert_eq!(quote.coupon_rejection, None);
+
+    // The volume discount, then the coupon on what is left
+    let quote = engine.quote(&product, 10, Some(&coupon_with(|_| {}))).unwrap();
+    assert_eq!(quote.discounts.iter().map(|x| x.amount().clone()).collect::<Vec<_>>(), [eur("5.00"), eur("9.49")]);
+    assert_eq!(quote.total, eur("85.41"));
+    assert_eq!(quote.discount_total(), eur("14.49"));
+
+    // Rejected coupons are reported, and the price is quoted without them
+    let rejections = [
+        (PricingEngine::at(Timestamp::from_unix(1691184000)), 2, coupon_with(|_| {}), CouponRejection::Expired(Timestamp::from_unix(1691184000))),
+        (engine.clone(), 2, coupon_with(|x| x["used"] = json!(100)), CouponRejection::UsedUp { max_uses: 100 }),
+        (engine.clone(), 2, coupon_with(|x| x["products_bound"] = json!(["other"])), CouponRejection::NotForProduct),
+        (engine.clone(), 10, coupon_with(|x| x["disabled_with_volume_discounts"] = json!(true)), CouponRejection::DisabledWithVolumeDiscounts),
+    ];
+    for (engine, quantity, coupon, rejection) in rejections {
+        let quote = engine.quote(&product, quantity, Some(&coupon)).unwrap();
+        assert_eq!(quote.coupon_rejection, Some(rejection));
+        assert!(quote.discounts.iter().all(|x| !matches!(x, AppliedDiscount::Coupon { .. })));
+    }
+
+    // Fixed coupons take their discount off as an amount, even over 100, and never below zero
+    let fixed = coupon_with(|x| x["discount_type"] = json!("FIXED"));
+    let quote = engine.quote(&product, 2, Some(&fixed)).unwrap();
+    assert_eq!(quote.discounts, [AppliedDiscount::Coupon { code: "SUMMER10".to_owned(), type_field: DiscountType::Fixed, value: Decimal::from(10u64), amount: eur("10.00") }]);
+    assert_eq!(quote.total, eur("9.98"));
+    let fixed = coupon_with(|x| { x["discount_type"] = json!("FIXED"); x["discount"] = json!(150); });
+    let quote = engine.quote(&product, 2, Some(&fixed)).unwrap();
+    assert_eq!(quote.coupon_rejection, None);
+    assert_eq!(quote.total, eur("0"));
+    let percentage = coupon_with(|x| x["discount"] = json!(150));
+    assert_eq!(engine.quote(&product, 2, Some(&percentage)).unwrap().coupon_rejection, Some(CouponRejection::InvalidDiscount(150)));
+    let unknown = coupon_with(|x| x["discount_type"] = json!("BOGO"));
+    assert_eq!(engine.quote(&product, 2, Some(&unknown)).unwrap().coupon_rejection, Some(CouponRejection::UnknownDiscountType("BOGO".to_owned())));
+
+    // Unlimited coupons, for any product
+    let unlimited = coupon_with(|x| {
+        x["use_type"] = json!("ANY");
+        x["products_bound"] = json!(null);
+        x["used"] = json!(1000);
+        x["max_uses"] = json!(-1);
+    });
+    assert_eq!(engine.quote(&product, 1, Some(&unlimited)).unwrap().total, eur("8.99"));
+
+    // Fixed volume discounts never take the total below zero
+    let fixed = product_with(|x| x["volume_discounts"] = json!([{ "type": "FIXED", "value": "3", "quantity": 2 }, { "type": "FIXED", "value": "50", "quantity": 3 }]));
+    assert_eq!(engine.quote(&fixed, 2, None).unwrap().total, eur("16.98"));
+    assert_eq!(engine.quote(&fixed, 3, None).unwrap().total, eur("0"));
+
+    // Products which cannot be priced
+    let limited = product_with(|x| { x["quantity_min"] = json!(2); x["quantity_max"] = json!(5); });
+    assert_eq!(engine.quote(&limited, 6, None).unwrap_err(), PricingError::InvalidQuantity { quantity: 6, min: 2, max: Some(5) });
+    assert_eq!(engine.quote(&product, 0, None).unwrap_err(), PricingError::InvalidQuantity { quantity: 0, min: 1, max: None });
+    let unknown = product_with(|x| x["volume_discounts"][0]["type"] = json!("BOGO"));
+    assert_eq!(engine.quote(&unknown, 10, None).unwrap_err(), PricingError::UnknownDiscountType("BOGO".to_owned()));
+}
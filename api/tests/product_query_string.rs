@@ -1,5 +1,7 @@
 // Dependencies
-use sellix_rs::ProductQuerystringBuilder;
+use std::collections::BTreeMap;
+use sellix_rs::{ProductQuerystringBuilder, QueryStep};
+use sellix_rs::sellix_api_models::payment::PaymentGateway;
 
 // Entrypoint
 #[test]
@@ -7,9 +9,36 @@ fn product_query_string() {
     // Initialise the builder and add all of the stuff
     let builder = ProductQuerystringBuilder::new("test_store", "uniqid")
         .quantity(50)
-        .email("example@example.com")
-        .add_custom_field("customField", "hello");
+        .gateway(PaymentGateway::Litecoin)
+        .step(QueryStep::EmailAndPay)
+        .email("a+b@x.com")
+        .coupon_code("SAVE 10")
+        .add_custom_field("zeta", "last")
+        .add_custom_field("customField", "a&b=c");
 
-    // Log it
-    println!("built: {}", builder.build());
-}
\ No newline at end of file
+    // Values are encoded, and custom fields are sorted
+    let built = builder.build();
+    assert_eq!(built, "https://test_store.mysellix.io/product/uniqid?quantity=50&gateway=LITECOIN&step=3&email=a%2Bb%40x.com&couponCode=SAVE+10&customField=a%26b%3Dc&zeta=last");
+
+    // Parsing recovers everything
+    let parsed = ProductQuerystringBuilder::parse(&built).unwrap();
+    assert_eq!(parsed, builder);
+    assert_eq!(parsed.store_name(), "test_store");
+    assert_eq!(parsed.get_email(), Some("a+b@x.com"));
+    assert_eq!(parsed.custom_fields, BTreeMap::from([
+        ("customField".to_owned(), "a&b=c".to_owned()),
+        ("zeta".to_owned(), "last".to_owned()),
+    ]));
+
+    // Without any parameters, there is no querystring
+    let builder = ProductQuerystringBuilder::new("store", "a/b");
+    assert_eq!(builder.build(), "https://store.mysellix.io/product/a%2Fb");
+    assert_eq!(ProductQuerystringBuilder::parse("https://store.mysellix.io/product/a%2Fb").unwrap(), builder);
+
+    // Invalid links are rejected
+    assert_eq!(ProductQuerystringBuilder::new("my.store", "uniqid").try_build().unwrap_err().field, "store_name");
+    assert!(ProductQuerystringBuilder::parse("https://example.com/product/uniqid").is_err());
+    assert!(ProductQuerystringBuilder::parse("https://store.mysellix.io/products").is_err());
+    assert!(ProductQuerystringBuilder::parse("http://store.mysellix.io/product/uniqid").is_err());
+    assert_eq!(ProductQuerystringBuilder::parse("https://store.mysellix.io/product/uniqid?step=2").unwrap_err().field, "step");
+}
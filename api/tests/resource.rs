@@ -0,0 +1,96 @@
+// Dependencies
+use std::fs;
+use std::sync::Arc;
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::resource::{Resource, Gettable, Listable, Creatable, Editable, Deletable, MAX_PAGES};
+use sellix_rs::sellix_api_models::{RequestType, ResourceKind, SellixError, SellixErrorKind, SellixHttpCode};
+use serde_json::Value;
+
+/// Serves three pages of blacklists: two, then one, then none.
+struct Pages;
+#[async_trait]
+impl Middleware for Pages {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        assert_eq!(request.request_type, RequestType::BlacklistList);
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/blacklist_list.json");
+        let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+        let blacklists = json["data"]["blacklists"].as_array_mut().unwrap();
+        match request.path.as_str() {
+            "/blacklists?page=1" => {},
+            "/blacklists?page=2" => blacklists.truncate(1),
+            "/blacklists?page=3" => blacklists.clear(),
+            path => panic!("unexpected request to {}", path),
+        }
+        Ok(json)
+    }
+}
+
+/// Serves the same page of blacklists, whatever page is asked for.
+struct SamePage;
+#[async_trait]
+impl Middleware for SamePage {
+    async fn handle(&self, _request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/blacklist_list.json");
+        Ok(serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap())
+    }
+}
+
+/// Serves a new page of blacklists, whatever page is asked for.
+struct EndlessPages;
+#[async_trait]
+impl Middleware for EndlessPages {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/blacklist_list.json");
+        let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        for (i, blacklist) in json["data"]["blacklists"].as_array_mut().unwrap().iter_mut().enumerate() {
+            blacklist["uniqid"] = Value::from(format!("{}-{}", request.path, i));
+        }
+        Ok(json)
+    }
+}
+
+/// Written once, for any sub-API that can be listed.
+async fn count<R: Listable>(resource: &R) -> Result<(ResourceKind, usize), SellixError> {
+    Ok((R::KIND, resource.list_all().await?.len()))
+}
+
+/// Only compiles if `R` supports every capability.
+fn assert_crud<R: Gettable + Listable + Creatable + Editable + Deletable>() {}
+
+/// Only compiles if `R` is a resource with the given raw model.
+fn assert_raw<R: Resource<Raw = T>, T>() {}
+
+// Entrypoint
+#[tokio::test]
+async fn resource() {
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(Pages))
+        .build();
+
+    // Every page is requested, until an empty one
+    let (kind, count) = count(&client.blacklist).await.unwrap();
+    assert_eq!(kind, ResourceKind::Blacklist);
+    assert_eq!(count, 3);
+
+    // A page which repeats the one before ends the list, rather than looping forever
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(SamePage))
+        .build();
+    assert_eq!(client.blacklist.list_all().await.unwrap().len(), 2);
+
+    // As does giving up on a list which never ends, which is not mistaken for being rate limited
+    let client = Client::builder("api_key")
+        .middleware(Arc::new(EndlessPages))
+        .build();
+    let error = client.blacklist.list_all().await.unwrap_err();
+    assert_eq!(error.kind, SellixErrorKind::TooManyPages);
+    assert_ne!(error.kind, SellixHttpCode::TooManyRequests);
+    assert!(error.message.contains(&MAX_PAGES.to_string()));
+
+    // Capabilities follow `api_methods(...)`
+    assert_crud::<sellix_rs::api::Blacklist>();
+    assert_crud::<sellix_rs::api::Coupon>();
+    assert_raw::<sellix_rs::api::Order, sellix_rs::sellix_api_models::order::OrderRaw>();
+}
@@ -0,0 +1,25 @@
+// Dependencies
+use sellix_rs::sellix_api_models::{RequestAction, RequestType, ResourceKind, Route};
+use reqwest::Method;
+
+// Entrypoint
+#[test]
+fn route() {
+    // Path parameters are escaped
+    let route = Route::coupon_get("abc/../def ghi");
+    assert_eq!(route.path(), "/coupons/abc%2F..%2Fdef%20ghi");
+    assert_eq!(route.method(), Method::GET);
+    assert_eq!(route.request_type(), RequestType::CouponGet);
+
+    // Missing query parameters are left out
+    assert_eq!(Route::order_list(None).path(), "/orders");
+    assert_eq!(Route::order_list(Some(2)).path(), "/orders?page=2");
+
+    // Each request type's details match its route
+    assert_eq!(Route::feedback_reply("uniqid").path(), "/feedback/reply/uniqid");
+    assert_eq!(RequestType::FeedbackReply.request_details(), (Method::POST, "/feedback/reply/{uniqid}"));
+    assert_eq!(RequestType::GroupList.query_parameter(), Some("page"));
+    assert_eq!(RequestType::GroupDestroy.query_parameter(), None);
+    assert_eq!(RequestType::FeedbackReply.resource(), ResourceKind::Feedback);
+    assert_eq!(RequestType::FeedbackReply.action(), RequestAction::Reply);
+}
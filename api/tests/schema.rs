@@ -0,0 +1,84 @@
+#![cfg(feature = "schemars")]
+
+// Dependencies
+use std::collections::HashSet;
+use std::fs;
+use serde_json::Value;
+use sellix_rs::sellix_api_models::{schema, RequestType};
+
+/// Follows a `$ref` (or an `allOf` wrapping one) to the schema it points to.
+fn resolve<'a>(document: &'a Value, schema: &'a Value) -> &'a Value {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name = reference.strip_prefix("#/components/schemas/").unwrap();
+        return resolve(document, &document["components"]["schemas"][name]);
+    }
+    match schema["allOf"].as_array() {
+        Some(all_of) => resolve(document, &all_of[0]),
+        None => schema,
+    }
+}
+
+/// Makes sure every key within the JSON is declared by the schema, recursively.
+fn assert_declared(document: &Value, path: &str, schema: &Value, json: &Value) {
+    let schema = resolve(document, schema);
+    match json {
+        Value::Object(json) if schema["properties"].is_object() => {
+            for (key, value) in json {
+                let property = &schema["properties"][key];
+                assert!(!property.is_null(), "{}/{} is not declared by the schema", path, key);
+                assert_declared(document, &format!("{}/{}", path, key), property, value);
+            }
+        },
+        Value::Array(json) if schema["items"].is_object() => {
+            for (i, value) in json.iter().enumerate() {
+                assert_declared(document, &format!("{}/{}", path, i), &schema["items"], value);
+            }
+        },
+        _ => {},
+    }
+}
+
+// Entrypoint
+#[test]
+fn schema_openapi() {
+    let document = schema::openapi();
+    assert_eq!(document["openapi"], "3.0.3");
+
+    // Every request type has its own operation
+    let mut operation_ids = HashSet::new();
+    for request_type in RequestType::ALL {
+        let (method, path) = request_type.request_details();
+        let operation = &document["paths"][path][method.as_str().to_lowercase()];
+        assert!(operation.is_object(), "{:?} is missing", request_type);
+        assert!(operation_ids.insert(operation["operationId"].as_str().unwrap().to_owned()));
+    }
+
+    // Every reference resolves
+    let serialized = document.to_string();
+    for reference in serialized.split("\"#/components/schemas/").skip(1) {
+        let name = &reference[..reference.find('"').unwrap()];
+        assert!(document["components"]["schemas"][name].is_object(), "{} is not defined", name);
+    }
+
+    // Each fixture only has fields the schema declares
+    let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+    for (request_type, name) in [
+        (RequestType::BlacklistGet, "blacklist_get"),
+        (RequestType::CategoryGet, "category_get"),
+        (RequestType::CouponList, "coupon_list"),
+        (RequestType::FeedbackGet, "feedback_get"),
+        (RequestType::OrderList, "order_list"),
+        (RequestType::SubscriptionGet, "subscription_get"),
+    ] {
+        let json: Value = serde_json::from_str(&fs::read_to_string(format!("{}/{}.json", fixtures, name)).unwrap()).unwrap();
+        let (method, path) = request_type.request_details();
+        let response = &document["paths"][path][method.as_str().to_lowercase()]["responses"]["200"]["content"]["application/json"]["schema"];
+        assert_declared(&document, name, response, &json);
+    }
+
+    // Timestamps and unknown enum values are described as they are serialized
+    let timestamp = &document["components"]["schemas"]["Timestamp"];
+    assert_eq!(timestamp["type"], "integer");
+    let scope = &document["components"]["schemas"]["BlacklistScope"];
+    assert_eq!(scope["anyOf"][1]["type"], "string");
+}
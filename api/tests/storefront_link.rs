@@ -0,0 +1,53 @@
+// Dependencies
+use sellix_rs::{LinkTarget, ProductQuerystringBuilder, QueryStep, Storefront, StorefrontLink};
+use sellix_rs::sellix_api_models::payment::PaymentGateway;
+
+// Entrypoint
+#[test]
+fn storefront_link() {
+    let store = Storefront::subdomain("test_store");
+    let domain = Storefront::custom_domain("shop.example.com");
+
+    // Every target, with the same presets
+    let links = [
+        (StorefrontLink::group(store.clone(), "group1"), "https://test_store.mysellix.io/group/group1?gateway=BITCOIN&couponCode=SAVE10"),
+        (StorefrontLink::category(domain.clone(), "cat 1"), "https://shop.example.com/category/cat%201?gateway=BITCOIN&couponCode=SAVE10"),
+        (StorefrontLink::invoice(domain.clone(), "invoice1"), "https://shop.example.com/invoice/invoice1?gateway=BITCOIN&couponCode=SAVE10"),
+        (StorefrontLink::product(domain.clone(), "product1").quantity(2), "https://shop.example.com/product/product1?quantity=2&gateway=BITCOIN&couponCode=SAVE10"),
+        (
+            StorefrontLink::cart(store.clone()).add_to_cart("product1", 2).add_to_cart("product2", 1),
+            "https://test_store.mysellix.io/cart?item=product1%3A2&item=product2%3A1&gateway=BITCOIN&couponCode=SAVE10"
+        ),
+    ];
+    for (link, expected) in links {
+        let link = link.gateway(PaymentGateway::Bitcoin).coupon_code("SAVE10");
+        let built = link.build().unwrap();
+        assert_eq!(built, expected);
+
+        // Parsing recovers everything
+        assert_eq!(StorefrontLink::parse(&built).unwrap(), link);
+    }
+
+    // Product links are the same as the product builder's
+    let builder = ProductQuerystringBuilder::new("test_store", "product1")
+        .step(QueryStep::Choice)
+        .add_custom_field("ref", "newsletter");
+    let link = StorefrontLink::product(store.clone(), "product1")
+        .step(QueryStep::Choice)
+        .add_custom_field("ref", "newsletter");
+    assert_eq!(builder.link(), link);
+    assert_eq!(builder.try_build(), link.build());
+
+    // Only mysellix.io product links parse back to the product builder
+    let built = StorefrontLink::product(domain.clone(), "product1").build().unwrap();
+    assert_eq!(StorefrontLink::parse(&built).unwrap().target(), &LinkTarget::Product("product1".to_owned()));
+    assert!(ProductQuerystringBuilder::parse(&built).is_err());
+
+    // Invalid links are rejected
+    assert_eq!(StorefrontLink::cart(store.clone()).build().unwrap_err().field, "cart");
+    assert_eq!(StorefrontLink::group(store.clone(), "group1").quantity(2).build().unwrap_err().field, "quantity");
+    assert_eq!(StorefrontLink::group(Storefront::custom_domain("localhost"), "group1").build().unwrap_err().field, "domain");
+    assert_eq!(StorefrontLink::group(store.clone(), "").build().unwrap_err().field, "uniqid");
+    assert_eq!(StorefrontLink::parse("https://shop.example.com/cart?item=product1").unwrap_err().field, "item");
+    assert!(StorefrontLink::parse("https://shop.example.com/blog/post").is_err());
+}
@@ -0,0 +1,260 @@
+// Dependencies
+use std::fs;
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use sellix_rs::{Client, middleware::{Middleware, Next, Request}};
+use sellix_rs::sellix_api_models::{RequestAction, RequestType, SellixError};
+use sellix_rs::sellix_api_models::payment::DiscountType;
+use sellix_rs::sync::{Action, ShopConfig};
+use serde_json::{Value, json};
+
+/// Serves the fixtures as the live shop, and records every change made to it.
+#[derive(Default)]
+struct Shop {
+    changes: Mutex<Vec<(RequestType, String, Option<Value>)>>,
+    /// Edits each list served, given its key.
+    edit: Option<fn(&str, &mut Value)>,
+}
+#[async_trait]
+impl Middleware for Shop {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Value, SellixError> {
+        let list = match request.request_type {
+            RequestType::GroupList => Some(("group_list", "groups")),
+            RequestType::CategoryList => Some(("category_list", "categories")),
+            RequestType::CouponList => Some(("coupon_list", "coupons")),
+            RequestType::BlacklistList => Some(("blacklist_list", "blacklists")),
+            RequestType::WhitelistList => Some(("whitelist_list", "whitelists")),
+            _ => None,
+        };
+        if let Some((name, key)) = list {
+            let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+            let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+            if !request.path.ends_with("?page=1") {
+                json["data"][key].as_array_mut().unwrap().clear();
+            }
+            if let Some(edit) = self.edit {
+                edit(key, &mut json["data"][key]);
+            }
+            return Ok(json);
+        }
+
+        let mut changes = self.changes.lock().unwrap();
+        let created = request.request_type.action() == RequestAction::Create;
+        changes.push((request.request_type, request.path, request.body));
+        let data = if created { json!({ "uniqid": format!("new{}", changes.len()) }) } else { Value::Null };
+        Ok(json!({ "status": 200, "data": data, "message": null, "log": null, "error": null, "env": "production" }))
+    }
+}
+
+/// Changes to most of the fixtures, leaving blacklists unmanaged.
+fn config_json() -> Value {
+    json!({
+        "groups": [
+            { "title": "Bundles", "sort_priority": 3, "products": ["6488e1b7d2c90", "6488e1b7d2c91"] },
+            { "title": "New Group", "products": ["6488e1b7d2c91"] }
+        ],
+        "categories": [
+            { "title": "Licence Keys", "sort_priority": 1, "products": ["6488e1b7d2c90"], "groups": ["Bundles", "New Group"] },
+            { "title": "Extras", "groups": ["New Group"] }
+        ],
+        "coupons": [
+            { "code": "SUMMER10", "discount": 10, "max_uses": 100, "products": ["6488e1b7d2c90"], "expire_at": 1691184000 },
+            { "code": "NEW20", "discount": 20 }
+        ],
+        "whitelists": [
+            { "type": "IP", "data": "203.0.113.7", "note": "HQ" }
+        ]
+    })
+}
+
+// Entrypoint
+#[tokio::test]
+async fn sync() {
+    let shop = Arc::new(Shop::default());
+    let client = Client::builder("api_key")
+        .middleware(shop.clone())
+        .build();
+
+    // Planning makes no changes
+    let config: ShopConfig = serde_json::from_value(config_json()).unwrap();
+    let plan = config.plan(&client).await.unwrap();
+    assert_eq!(plan.to_string(), "\
+~ group \"Bundles\" (sort_priority)
++ group \"New Group\"
+~ category \"Licence Keys\" (groups)
++ category \"Extras\"
++ coupon \"NEW20\"
+~ whitelist \"IP 203.0.113.7\" (note)
+- category \"Services\" (6488e5a4f1c03)
+- coupon \"ANY5\" (6488eb12c7d32)
+- whitelist \"EMAIL vip@example.com\" (6488f3d2a1e45)
+");
+    assert!(matches!(&plan.changes[0].action, Action::Update { uniqid, .. } if uniqid == "6488e9c0b1a77"));
+    assert!(shop.changes.lock().unwrap().is_empty());
+
+    // Applying makes them in order, referring to new groups by their uniqid
+    plan.apply(&client).await.unwrap();
+    let changes = shop.changes.lock().unwrap().clone();
+    let requests: Vec<(RequestType, &str)> = changes.iter().map(|(x, path, _)| (*x, path.as_str())).collect();
+    assert_eq!(requests, [
+        (RequestType::GroupUpdate, "/groups/6488e9c0b1a77"),
+        (RequestType::GroupCreate, "/groups"),
+        (RequestType::CategoryUpdate, "/categories/6488e5a4f1c02"),
+        (RequestType::CategoryCreate, "/categories"),
+        (RequestType::CouponCreate, "/coupons"),
+        (RequestType::WhitelistUpdate, "/whitelists/6488f3d2a1e44"),
+        (RequestType::CategoryDestroy, "/categories/6488e5a4f1c03"),
+        (RequestType::CouponDestroy, "/coupons/6488eb12c7d32"),
+        (RequestType::WhitelistDestroy, "/whitelists/6488f3d2a1e45"),
+    ]);
+    assert_eq!(changes[0].2, Some(json!({ "sort_priority": 3 })));
+    assert_eq!(changes[2].2, Some(json!({ "groups_bound": ["6488e9c0b1a77", "new2"] })));
+    assert_eq!(changes[3].2.as_ref().unwrap()["groups_bound"], json!(["new2"]));
+    assert_eq!(changes[5].2, Some(json!({ "note": "HQ" })));
+
+    // The live shop's own configuration needs no changes
+    let live = ShopConfig::load(&client).await.unwrap();
+    assert!(live.plan(&client).await.unwrap().is_empty());
+    assert_eq!(live.coupons.unwrap()[1].max_uses, -1);
+
+    // Products and groups are compared in any order, and entries regardless of case
+    let mut reordered = serde_json::to_value(ShopConfig::load(&client).await.unwrap()).unwrap();
+    reordered["groups"][0]["products"].as_array_mut().unwrap().reverse();
+    reordered["whitelists"][1]["type"] = json!("email");
+    reordered["whitelists"][1]["data"] = json!("VIP@Example.com");
+    let reordered: ShopConfig = serde_json::from_value(reordered).unwrap();
+    assert!(reordered.plan(&client).await.unwrap().is_empty());
+
+    // Invalid configurations are refused before anything is planned
+    let mut invalid = config_json();
+    invalid["categories"][1]["groups"] = json!(["Missing"]);
+    let invalid: ShopConfig = serde_json::from_value(invalid).unwrap();
+    assert!(invalid.plan(&client).await.unwrap_err().message.contains("\"Missing\""));
+
+    let mut invalid = config_json();
+    invalid["coupons"][1]["code"] = json!("SUMMER10");
+    let invalid: ShopConfig = serde_json::from_value(invalid).unwrap();
+    assert!(invalid.plan(&client).await.unwrap_err().message.contains("more than once"));
+
+    let mut invalid = config_json();
+    invalid["whitelists"][0]["data"] = json!("not an ip");
+    let invalid: ShopConfig = serde_json::from_value(invalid).unwrap();
+    assert!(invalid.plan(&client).await.is_err());
+}
+
+#[tokio::test]
+async fn sync_fixed_coupons() {
+    // The first coupon takes 150 off
+    let shop = Arc::new(Shop {
+        edit: Some(|key, list| if key == "coupons" && !list[0].is_null() {
+            list[0]["discount_type"] = json!("FIXED");
+            list[0]["discount"] = json!(150);
+        }),
+        ..Default::default()
+    });
+    let client = Client::builder("api_key")
+        .middleware(shop.clone())
+        .build();
+
+    // Its discount type is kept, so it needs no changes
+    let live = ShopConfig::load(&client).await.unwrap();
+    let coupons = live.coupons.clone().unwrap();
+    assert_eq!(coupons[0].discount_type, Some(DiscountType::Fixed));
+    assert_eq!(coupons[0].discount, 150);
+    assert!(live.plan(&client).await.unwrap().is_empty());
+
+    // New fixed coupons are created as such, and changing the type updates it
+    let mut config = serde_json::to_value(&live).unwrap();
+    config["coupons"][1]["discount_type"] = json!("PERCENTAGE");
+    config["coupons"].as_array_mut().unwrap().push(json!({ "code": "BIG200", "discount": 200, "discount_type": "FIXED" }));
+    let config: ShopConfig = serde_json::from_value(config).unwrap();
+    let plan = config.plan(&client).await.unwrap();
+    assert_eq!(plan.to_string(), "\
+~ coupon \"ANY5\" (discount_type)
++ coupon \"BIG200\"
+");
+    plan.apply(&client).await.unwrap();
+    let changes = shop.changes.lock().unwrap().clone();
+    assert_eq!(changes[0].2, Some(json!({ "discount_type": "PERCENTAGE" })));
+    let created = changes[1].2.as_ref().unwrap();
+    assert_eq!((&created["discount_type"], &created["discount_value"]), (&json!("FIXED"), &json!(200)));
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn sync_toml() {
+    let file = r#"
+        [[groups]]
+        title = "Bundles"
+        sort_priority = 3
+        products = ["6488e1b7d2c90", "6488e1b7d2c91"]
+
+        [[groups]]
+        title = "New Group"
+        products = ["6488e1b7d2c91"]
+
+        [[categories]]
+        title = "Licence Keys"
+        sort_priority = 1
+        products = ["6488e1b7d2c90"]
+        groups = ["Bundles", "New Group"]
+
+        [[categories]]
+        title = "Extras"
+        groups = ["New Group"]
+
+        [[coupons]]
+        code = "SUMMER10"
+        discount = 10
+        max_uses = 100
+        products = ["6488e1b7d2c90"]
+        expire_at = 1691184000
+
+        [[coupons]]
+        code = "NEW20"
+        discount = 20
+
+        [[whitelists]]
+        type = "IP"
+        data = "203.0.113.7"
+        note = "HQ"
+    "#;
+    let config = ShopConfig::from_toml(file).unwrap();
+    assert_eq!(config, serde_json::from_value(config_json()).unwrap());
+    assert_eq!(ShopConfig::from_toml(&config.to_toml().unwrap()).unwrap(), config);
+}
+
+#[test]
+#[cfg(feature = "yaml")]
+fn sync_yaml() {
+    let file = "
+groups:
+  - title: Bundles
+    sort_priority: 3
+    products: [\"6488e1b7d2c90\", \"6488e1b7d2c91\"]
+  - title: New Group
+    products: [\"6488e1b7d2c91\"]
+categories:
+  - title: Licence Keys
+    sort_priority: 1
+    products: [\"6488e1b7d2c90\"]
+    groups: [Bundles, New Group]
+  - title: Extras
+    groups: [New Group]
+coupons:
+  - code: SUMMER10
+    discount: 10
+    max_uses: 100
+    products: [\"6488e1b7d2c90\"]
+    expire_at: 1691184000
+  - code: NEW20
+    discount: 20
+whitelists:
+  - type: IP
+    data: 203.0.113.7
+    note: HQ
+";
+    let config = ShopConfig::from_yaml(file).unwrap();
+    assert_eq!(config, serde_json::from_value(config_json()).unwrap());
+    assert_eq!(ShopConfig::from_yaml(&config.to_yaml().unwrap()).unwrap(), config);
+}
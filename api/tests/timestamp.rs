@@ -0,0 +1,74 @@
+// Dependencies
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde_json::json;
+use sellix_rs::sellix_api_models::{Timestamp, Timestamped};
+use sellix_rs::sellix_api_models::blacklist::BlacklistRaw;
+
+/// A blacklist, created and updated at the given times.
+fn blacklist(created_at: serde_json::Value, updated_at: serde_json::Value) -> BlacklistRaw {
+    serde_json::from_value(json!({
+        "id": 1,
+        "uniqid": "abc",
+        "scope": "PRIVATE",
+        "shop_id": 1,
+        "type": "EMAIL",
+        "data": "test@example.com",
+        "note": "",
+        "created_at": created_at,
+        "updated_at": updated_at,
+        "updated_by": 0,
+    })).unwrap()
+}
+
+// Entrypoint
+#[test]
+fn timestamp() {
+    // Numbers and numeric strings are both accepted
+    let created = Timestamp::from_unix(1686000000);
+    assert_eq!(blacklist(json!(1686000000), json!(null)).created_at, created);
+    assert_eq!(blacklist(json!("1686000000"), json!(null)).created_at, created);
+
+    // Every form of empty timestamp is nullable
+    for empty in [json!(null), json!(0), json!("")] {
+        assert_eq!(blacklist(json!(1686000000), empty).updated_at(), None);
+    }
+    assert_eq!(blacklist(json!(1686000000), json!(1686000100)).updated_at(), Some(Timestamp::from_unix(1686000100)));
+
+    // Except where a timestamp is required
+    let missing: Result<Timestamp, _> = serde_json::from_value(json!(null));
+    assert!(missing.is_err());
+
+    // Serialized as an integer
+    assert_eq!(serde_json::to_value(created).unwrap(), json!(1686000000));
+
+    // Converts to and from a SystemTime
+    let system_time = UNIX_EPOCH + Duration::from_secs(1686000000);
+    assert_eq!(Timestamp::from(system_time), created);
+    assert_eq!(SystemTime::from(created), system_time);
+
+    // Ranges
+    let blacklist = blacklist(json!(1686000000), json!(null));
+    assert!(blacklist.created_within(Timestamp::from_unix(1685000000)..));
+    assert!(blacklist.created_within(Timestamp::from_unix(1685000000)..=created));
+    assert!(!blacklist.created_within(..created));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn timestamp_chrono() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    let datetime: DateTime<Utc> = Timestamp::from_unix(1686000000).into();
+    assert_eq!(datetime, Utc.with_ymd_and_hms(2023, 6, 5, 21, 20, 0).unwrap());
+    assert_eq!(Timestamp::from(datetime), Timestamp::from_unix(1686000000));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn timestamp_time() {
+    use time::{macros::datetime, OffsetDateTime};
+
+    let datetime: OffsetDateTime = Timestamp::from_unix(1686000000).into();
+    assert_eq!(datetime, datetime!(2023-06-05 21:20:00 UTC));
+    assert_eq!(Timestamp::from(datetime), Timestamp::from_unix(1686000000));
+}
@@ -0,0 +1,12 @@
+// Dependencies
+use sellix_rs::sellix_api_models::coupon::CouponCreatePayload;
+
+/// A create payload is only built through its builder, which validates it.
+fn parse(json: &str) -> CouponCreatePayload {
+    serde_json::from_str(json).unwrap()
+}
+
+// Entrypoint
+fn main() {
+    parse(r#"{ "code": "", "discount_value": 500 }"#);
+}
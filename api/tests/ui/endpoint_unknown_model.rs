@@ -0,0 +1,19 @@
+// Dependencies
+use async_trait::async_trait;
+use sellix_macros::{WithAPIKey, WithDoRequest, DefaultAPI};
+use sellix_rs::api::{WithAPIKey, DoRequest};
+use sellix_rs::resource::{Resource, Gettable, Listable};
+use sellix_rs::transport::Transport;
+
+/// The body is not a model of the feedback module.
+#[derive(WithAPIKey, WithDoRequest, DefaultAPI)]
+#[api_methods(create=false,edit=false,delete=false)]
+#[endpoint(name = "reply", method = POST, path = "/feedback/reply/{uniqid}", body = FeedbackAnswerPayload, returns = ())]
+pub struct Feedback {
+    pub api_key: String,
+    pub merchant: Option<String>,
+    pub transport: Transport
+}
+
+// Entrypoint
+fn main() {}
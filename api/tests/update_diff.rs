@@ -0,0 +1,69 @@
+// Dependencies
+use sellix_rs::sellix_api_models::blacklist::{BlacklistRaw, BlacklistUpdatePayload};
+use sellix_rs::sellix_api_models::Timestamp;
+use sellix_rs::sellix_api_models::coupon::{CouponRaw, CouponUpdatePayload};
+use sellix_rs::sellix_api_models::payment::DiscountType;
+use serde_json::json;
+
+// Entrypoint
+#[test]
+fn update_diff() {
+    // Only the note of the blacklist changes
+    let old: BlacklistRaw = serde_json::from_value(json!({
+        "id": 1,
+        "uniqid": "blacklist_uniqid",
+        "scope": "PRIVATE",
+        "shop_id": 1,
+        "type": "EMAIL",
+        "data": "test@example.com",
+        "note": "Testing",
+        "created_at": 1686000000,
+        "updated_at": 1686000000,
+        "updated_by": 0
+    })).unwrap();
+    let mut new: BlacklistRaw = serde_json::from_value(serde_json::to_value(&old).unwrap()).unwrap();
+    new.note = "Chargeback".to_owned();
+
+    // Unset fields are left out of the JSON
+    let update = BlacklistUpdatePayload::diff(&old, &new);
+    assert_eq!(serde_json::to_value(&update).unwrap(), json!({ "note": "Chargeback" }));
+    assert!(BlacklistUpdatePayload::diff(&old, &old).is_empty());
+
+    // Only the coupon's max uses and bound products change
+    let mut old: CouponRaw = serde_json::from_value(json!({
+        "id": 1,
+        "uniqid": "coupon_uniqid",
+        "shop_id": 1,
+        "type": "PRODUCT",
+        "code": "test_coupon",
+        "use_type": "ANY",
+        "discount": 3,
+        "currency": null,
+        "used": 0,
+        "disabled_with_volume_discounts": false,
+        "all_recurring_bill_invoices": false,
+        "max_uses": -1,
+        "expire_at": null,
+        "products_bound": null,
+        "products_count": 0,
+        "created_at": 1686000000,
+        "updated_at": 1686000000,
+        "updated_by": 0
+    })).unwrap();
+    let mut new = old.clone();
+    new.max_uses = 10;
+    new.products_bound = Some(vec!["product_uniqid".to_owned()]);
+
+    let update = CouponUpdatePayload::diff(&old, &new).unwrap();
+    assert_eq!(serde_json::to_value(&update).unwrap(), json!({ "max_uses": 10, "products_bound": ["product_uniqid"] }));
+
+    // As does its discount type
+    new.discount_type = Some(DiscountType::Fixed);
+    let update = CouponUpdatePayload::diff(&old, &new).unwrap();
+    assert_eq!(update.discount_type, Some(DiscountType::Fixed));
+
+    // An expiry date cannot be removed by an update
+    old.expire_at = Some(Timestamp::from_unix(1700000000));
+    let error = CouponUpdatePayload::diff(&old, &new).unwrap_err();
+    assert_eq!(error.field, "expire_at");
+}
@@ -22,11 +22,11 @@ async fn whitelist_create() {
     let whitelist_client = client.whitelist;
 
     // Create a whitelist
-    let whitelist = whitelist_client.create(WhitelistCreatePayload {
-        r#type: WhitelistTypes::Email,
-        data: "test@example.com",
-        note: Some("Testing")
-    }).await;
+    let whitelist = whitelist_client.create(WhitelistCreatePayload::builder(WhitelistTypes::Email, "test@example.com")
+        .note("Testing")
+        .build()
+        .expect("invalid whitelist")
+    ).await;
     assert!(whitelist.is_ok(), "unable to create whitelist");
     std::env::set_var("WHITELIST_UNIQID", whitelist.unwrap().uniqid);
 }
\ No newline at end of file
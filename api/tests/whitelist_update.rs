@@ -1,6 +1,6 @@
 // Dependencies
 use dotenv::dotenv;
-use sellix_rs::{Client, sellix_api_models::whitelist::{WhitelistCreatePayload, WhitelistTypes}};
+use sellix_rs::{Client, sellix_api_models::whitelist::{WhitelistUpdatePayload, WhitelistTypes}};
 use std::env;
 
 // Entrypoint
@@ -23,9 +23,9 @@ async fn whitelist_update() {
     let whitelist_client = client.whitelist;
 
     // Update the whitelist
-    let whitelist = whitelist_client.edit(&whitelist_uniqid, WhitelistCreatePayload {
-        r#type: WhitelistTypes::Email,
-        data: "test2@example.com",
+    let whitelist = whitelist_client.edit(&whitelist_uniqid, WhitelistUpdatePayload {
+        r#type: Some(WhitelistTypes::Email),
+        data: Some("test2@example.com"),
         note: Some("Testing")
     }).await;
     assert!(whitelist.is_ok(), "unable to edit whitelists");
@@ -0,0 +1,8 @@
+//! Prints the OpenAPI document for every route, e.g. to generate clients in other languages.
+//!
+//! `cargo run -p sellix_api_models --example openapi --features schemars > openapi.json`
+
+fn main() {
+    let document = sellix_api_models::schema::openapi();
+    println!("{}", serde_json::to_string_pretty(&document).unwrap());
+}
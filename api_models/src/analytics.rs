@@ -0,0 +1,242 @@
+// Dependencies
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use super::Currencies;
+use super::invoice::InvoiceStatus;
+use super::order::{OrderHistoryStatus, OrderRaw};
+use crate::money::{Decimal, Money};
+use crate::timestamp::Timestamp;
+
+// Constants
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// How revenue is grouped over time, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Day,
+    /// Weeks start on Monday.
+    Week,
+    Month,
+}
+impl Period {
+    /// The start of the period the timestamp is within.
+    pub fn start(&self, timestamp: Timestamp) -> Timestamp {
+        let days = timestamp.unix().div_euclid(SECONDS_PER_DAY);
+        let start = match self {
+            Self::Day => days,
+            // The unix epoch was a Thursday
+            Self::Week => days - (days + 3).rem_euclid(7),
+            Self::Month => {
+                let (year, month) = year_month(days);
+                days_from_civil(year, month, 1)
+            }
+        };
+        Timestamp::from_unix(start * SECONDS_PER_DAY)
+    }
+}
+
+/// The year and month of a day, counting from the unix epoch.
+fn year_month(days: i64) -> (i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+/// The day of a date, counting from the unix epoch.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// How many completed orders there were, and how much they made in USD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sales {
+    pub orders: u64,
+    pub revenue: Money,
+}
+impl Default for Sales {
+    fn default() -> Self {
+        Self { orders: 0, revenue: Money::zero(Currencies::USD) }
+    }
+}
+impl Sales {
+    fn add(&mut self, revenue: &Money) {
+        self.orders += 1;
+        self.revenue = self.revenue.clone() + revenue.clone();
+    }
+}
+
+/// How a coupon performed, over the completed orders it was used on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouponStats {
+    pub orders: u64,
+    /// What the orders made, in USD.
+    pub revenue: Money,
+    /// How much was taken off the orders, in USD, including any volume discounts.
+    pub discount: Money,
+}
+impl Default for CouponStats {
+    fn default() -> Self {
+        Self { orders: 0, revenue: Money::zero(Currencies::USD), discount: Money::zero(Currencies::USD) }
+    }
+}
+impl CouponStats {
+    /// The share of the full price that was discounted, from 0 to 1.
+    /// `None` if the orders were free.
+    pub fn discount_rate(&self) -> Option<Decimal> {
+        let full_price = self.revenue.amount + self.discount.amount;
+        (!full_price.is_zero()).then(|| self.discount.amount / full_price)
+    }
+}
+
+/// Revenue and rates over a stream of orders, with every amount normalized to USD using its `exchange_rate`.
+///
+/// ```
+/// # use sellix_api_models::analytics::{Period, SalesReport};
+/// # use sellix_api_models::order::OrderRaw;
+/// fn monthly_revenue(orders: &[OrderRaw]) {
+///     let report = SalesReport::from_orders(Period::Month, orders);
+///     for (month, sales) in &report.by_period {
+///         println!("{}: {} from {} orders", month, sales.revenue, sales.orders);
+///     }
+///     if let Some(rate) = report.refund_rate() {
+///         println!("Refunded: {:.1}%", rate * sellix_api_models::money::Decimal::ONE_HUNDRED);
+///     }
+/// }
+/// ```
+///
+/// Only completed orders count towards revenue, grouped by when they were created.
+/// An order counts as paid if it was completed, even if it was later refunded or disputed,
+/// which is what the refund, dispute and coupon rates are out of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SalesReport {
+    pub period: Period,
+    /// How many orders were added, whatever their status.
+    pub orders: u64,
+    /// How many orders were paid for.
+    pub paid: u64,
+    /// How many paid orders were refunded.
+    pub refunded: u64,
+    /// How many paid orders were disputed or reversed.
+    pub disputed: u64,
+    /// Every completed order.
+    pub total: Sales,
+    /// Completed orders by the start of the period they were created within.
+    pub by_period: BTreeMap<Timestamp, Sales>,
+    /// Completed orders by product uniqid.
+    pub by_product: BTreeMap<String, Sales>,
+    /// Completed orders by gateway, where orders without one are under an empty string.
+    pub by_gateway: BTreeMap<String, Sales>,
+    /// Completed orders by the customer's country.
+    pub by_country: BTreeMap<String, Sales>,
+    /// Completed orders by coupon uniqid.
+    pub coupons: BTreeMap<String, CouponStats>,
+}
+impl SalesReport {
+    /// Creates an empty report.
+    pub fn new(period: Period) -> Self {
+        Self {
+            period,
+            orders: 0,
+            paid: 0,
+            refunded: 0,
+            disputed: 0,
+            total: Sales::default(),
+            by_period: BTreeMap::new(),
+            by_product: BTreeMap::new(),
+            by_gateway: BTreeMap::new(),
+            by_country: BTreeMap::new(),
+            coupons: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a report from every order.
+    pub fn from_orders<I>(period: Period, orders: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<OrderRaw>,
+    {
+        let mut report = Self::new(period);
+        for order in orders {
+            report.add(order.borrow());
+        }
+        report
+    }
+
+    /// Adds an order, e.g. from a webhook.
+    pub fn add(&mut self, order: &OrderRaw) {
+        self.orders += 1;
+
+        // Work out what happened to the order, from its status and history
+        let history: Vec<OrderHistoryStatus> = order.status_history.iter()
+            .map(|x| OrderHistoryStatus::from(x.status.clone()))
+            .collect();
+        let refunded = order.status == InvoiceStatus::Refunded || history.contains(&OrderHistoryStatus::Refunded);
+        let disputed = matches!(order.status, InvoiceStatus::CustomerDisputeOngoing | InvoiceStatus::Reversed)
+            || history.iter().any(|x| matches!(x,
+                OrderHistoryStatus::CustomerDisputeOngoing
+                | OrderHistoryStatus::CustomerDisputeUpdated
+                | OrderHistoryStatus::CustomerDisputeResolved
+                | OrderHistoryStatus::CustomerDisputeCancelled
+                | OrderHistoryStatus::PaymentCaptureReversed
+            ));
+        let completed = order.status == InvoiceStatus::Completed;
+        if !(completed || refunded || disputed || history.contains(&OrderHistoryStatus::Completed)) {
+            return;
+        }
+        self.paid += 1;
+        self.refunded += u64::from(refunded);
+        self.disputed += u64::from(disputed);
+        if !completed {
+            return;
+        }
+
+        // Add its revenue
+        let revenue = order.total_money().convert(order.exchange_rate, Currencies::USD);
+        self.total.add(&revenue);
+        self.by_period.entry(self.period.start(order.created_at)).or_default().add(&revenue);
+        self.by_product.entry(order.product_id.clone()).or_default().add(&revenue);
+        self.by_gateway.entry(order.gateway.clone().unwrap_or_default()).or_default().add(&revenue);
+        self.by_country.entry(order.country.clone()).or_default().add(&revenue);
+        if let Some(coupon_id) = &order.coupon_id {
+            let coupon = self.coupons.entry(coupon_id.clone()).or_default();
+            coupon.orders += 1;
+            coupon.revenue = coupon.revenue.clone() + revenue;
+            coupon.discount = coupon.discount.clone() + order.discount_money().convert(order.exchange_rate, Currencies::USD);
+        }
+    }
+
+    /// The share of paid orders which were refunded, from 0 to 1.
+    /// `None` if no orders were paid.
+    pub fn refund_rate(&self) -> Option<Decimal> {
+        self.rate(self.refunded)
+    }
+
+    /// The share of paid orders which were disputed or reversed, from 0 to 1.
+    /// `None` if no orders were paid.
+    pub fn dispute_rate(&self) -> Option<Decimal> {
+        self.rate(self.disputed)
+    }
+
+    /// The share of completed orders which used a coupon, from 0 to 1.
+    /// `None` if no orders were completed.
+    pub fn coupon_rate(&self) -> Option<Decimal> {
+        let used: u64 = self.coupons.values().map(|x| x.orders).sum();
+        (self.total.orders > 0).then(|| Decimal::from(used) / Decimal::from(self.total.orders))
+    }
+
+    fn rate(&self, count: u64) -> Option<Decimal> {
+        (self.paid > 0).then(|| Decimal::from(count) / Decimal::from(self.paid))
+    }
+}
@@ -0,0 +1,154 @@
+/// Declares an enum of string values sent by the API, along with how each variant is written over the wire.
+///
+/// An `Unknown(String)` variant is added, which holds any value the API sends that is not listed,
+/// so new values added by Sellix do not fail deserialization. Every value, including unknown ones, round-trips.
+///
+/// Generates `Display`, `FromStr` (which never fails), `From<String>`, `Serialize`, `Deserialize`,
+/// along with `KNOWN` (every listed variant), `as_str` and `is_unknown`.
+/// With the `schemars` feature, also implements `JsonSchema`.
+///
+/// `Variant = "WIRE_VALUE",`
+macro_rules! api_enum {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $( $(#[$variant_meta:meta])* $variant:ident = $value:literal ),* $(,)? }) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $( $(#[$variant_meta])* $variant, )*
+            /// A value not known by this version of the crate, as it was sent.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Every known variant, i.e. all but [`Self::Unknown`].
+            pub const KNOWN: &'static [$name] = &[$( $name::$variant ),*];
+
+            /// The value as it is sent over the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(value) => value,
+                }
+            }
+
+            /// Whether the value is not known by this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $( $value => $name::$variant, )*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(value.to_owned()))
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl ::schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_owned()
+            }
+
+            fn json_schema(_: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
+                $crate::schema::api_enum(&[$( $value ),*])
+            }
+        }
+    };
+}
+
+/// Declares an enum of integer values sent by the API, like [`api_enum`] but for codes rather than strings.
+///
+/// An `Unknown(u16)` variant is added, which holds any code the API sends that is not listed,
+/// so new codes added by Sellix do not fail deserialization. Every code, including unknown ones, round-trips.
+///
+/// Generates `From<u16>`, `Serialize`, `Deserialize`, along with `KNOWN` (every listed variant), `code` and `is_unknown`.
+/// With the `schemars` feature, also implements `JsonSchema`.
+///
+/// `Variant = CODE,`
+macro_rules! api_code_enum {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $( $(#[$variant_meta:meta])* $variant:ident = $value:literal ),* $(,)? }) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $( $(#[$variant_meta])* $variant, )*
+            /// A code not known by this version of the crate, as it was sent.
+            Unknown(u16),
+        }
+
+        impl $name {
+            /// Every known variant, i.e. all but [`Self::Unknown`].
+            pub const KNOWN: &'static [$name] = &[$( $name::$variant ),*];
+
+            /// The code as it is sent over the API.
+            pub fn code(&self) -> u16 {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(value) => *value,
+                }
+            }
+
+            /// Whether the code is not known by this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, $name::Unknown(_))
+            }
+        }
+
+        impl From<u16> for $name {
+            fn from(value: u16) -> Self {
+                match value {
+                    $( $value => $name::$variant, )*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u16(self.code())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                u16::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl ::schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_owned()
+            }
+
+            fn json_schema(_: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
+                $crate::schema::api_code_enum(&[$( $value ),*])
+            }
+        }
+    };
+}
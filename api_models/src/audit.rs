@@ -0,0 +1,79 @@
+// Dependencies
+use std::collections::BTreeSet;
+use std::fmt;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// How a response differs from the model it was deserialized into.
+///
+/// Each field is given as a JSON pointer, e.g. `/data/order/gateway`.
+/// Indices within arrays are written as `*`, so a field is only reported once for a whole list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Drift {
+    /// Fields sent by the API which the model does not declare, and so were dropped.
+    pub unknown: BTreeSet<String>,
+    /// Fields declared by the model which the API did not send, and so were defaulted.
+    pub missing: BTreeSet<String>,
+    /// Why the response could not be deserialized into the model at all, in which case no fields are compared.
+    pub error: Option<String>,
+}
+impl Drift {
+    /// Whether the response matched the model exactly.
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty() && self.missing.is_empty() && self.error.is_none()
+    }
+
+    /// The drift of a response which could not be deserialized.
+    pub fn invalid(error: &serde_json::Error) -> Self {
+        Self { error: Some(error.to_string()), ..Self::default() }
+    }
+
+    /// Compares the keys of every object within both values, recursively.
+    fn compare(&mut self, path: &str, sent: &Value, declared: &Value) {
+        match (sent, declared) {
+            (Value::Object(sent), Value::Object(declared)) => {
+                for key in sent.keys().filter(|x| !declared.contains_key(*x)) {
+                    self.unknown.insert(format!("{}/{}", path, key));
+                }
+                for key in declared.keys().filter(|x| !sent.contains_key(*x)) {
+                    self.missing.insert(format!("{}/{}", path, key));
+                }
+                for (key, value) in sent {
+                    if let Some(declared) = declared.get(key) {
+                        self.compare(&format!("{}/{}", path, key), value, declared);
+                    }
+                }
+            },
+            (Value::Array(sent), Value::Array(declared)) => {
+                let path = format!("{}/*", path);
+                for (sent, declared) in sent.iter().zip(declared) {
+                    self.compare(&path, sent, declared);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |fields: &BTreeSet<String>| fields.iter().map(String::as_str).collect::<Vec<_>>().join(", ");
+        write!(f, "unknown: [{}], missing: [{}]", join(&self.unknown), join(&self.missing))?;
+        if let Some(error) = &self.error {
+            write!(f, ", error: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes the JSON as `T`, while recording every field that was unknown to `T` or missing from the JSON.
+///
+/// Fields are found by serializing the model back, and comparing its keys against the original JSON.
+/// Values themselves are not compared, so a type change that still deserializes is not reported.
+pub fn audit<T: Serialize + DeserializeOwned>(json: &Value) -> Result<(T, Drift), serde_json::Error> {
+    let model: T = serde_json::from_value(json.clone())?;
+    let declared = serde_json::to_value(&model)?;
+
+    let mut drift = Drift::default();
+    drift.compare("", json, &declared);
+    Ok((model, drift))
+}
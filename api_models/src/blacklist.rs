@@ -1,38 +1,37 @@
 // Dependencies
 use serde::{Serialize, Deserialize};
-use super::{RawAPIResponse, UniqidDict};
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
+use super::{changed, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
+use crate::timestamp::Timestamp;
 
-/// The scope of a blacklist.
-/// Used by [`BlacklistRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum BlacklistScope {
-    Private,
-    Shared
+api_enum! {
+    /// The scope of a blacklist.
+    /// Used by [`BlacklistRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum BlacklistScope {
+        Private = "PRIVATE",
+        Shared = "SHARED",
+    }
 }
 
-/// The types of blacklist.
-/// Used by [`BlacklistRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum BlacklistTypes {
-    Email,
-    IP,
-    Country,
-    ISP,
-    ASN,
-    Host
+api_enum! {
+    /// The types of blacklist.
+    /// Used by [`BlacklistRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum BlacklistTypes {
+        Email = "EMAIL",
+        IP = "IP",
+        Country = "COUNTRY",
+        ISP = "ISP",
+        ASN = "ASN",
+        Host = "HOST",
+    }
 }
 
 /// Represents the raw API response for a blacklist object.
 /// <https://developers.sellix.io/#blacklists>.
-#[serde_with::serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BlacklistRaw {
     /// ID of the resource
     pub id: u64,
@@ -50,11 +49,11 @@ pub struct BlacklistRaw {
     /// Additional note provided on blacklist creation.
     pub note: String,
     /// Creation date of the blacklist.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Date, available if the blacklist has been edited.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the blacklist has been edited.
     pub updated_by: u64,
 }
@@ -63,6 +62,7 @@ pub struct BlacklistRaw {
 /// <https://developers.sellix.io/#blacklist-get>.
 /// Used in [`BlacklistGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BlacklistOneRaw {
     pub blacklist: BlacklistRaw
 }
@@ -75,6 +75,7 @@ pub type BlacklistGetResponseRaw = RawAPIResponse<BlacklistOneRaw>;
 /// <https://developers.sellix.io/#blacklist-list>.
 /// Used for [`BlacklistListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BlacklistArray {
     pub blacklists: Vec<BlacklistRaw>
 }
@@ -82,18 +83,91 @@ pub struct BlacklistArray {
 /// <https://developers.sellix.io/#blacklist-list>.
 pub type BlacklistListResponseRaw = RawAPIResponse<BlacklistArray>;
 
-/// Represents the payload for creating (or updating) a blacklist.
+/// Represents the payload for creating a blacklist.
 /// <https://developers.sellix.io/#blacklist-create>.
-/// <https://developers.sellix.io/#blacklist-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct BlacklistCreatePayload<'a> {
+/// Use [`BlacklistCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct BlacklistCreatePayload {
     /// The type of data of this blacklist.
     pub r#type: BlacklistTypes,
     /// Blocked data. Either country code, email or IP address
-    pub data: &'a str,
+    pub data: String,
+    /// Internal note for the reasoning of the blacklist
+    pub note: Option<String>
+}
+impl BlacklistCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(r#type: BlacklistTypes, data: &str) -> BlacklistCreatePayloadBuilder {
+        BlacklistCreatePayloadBuilder {
+            payload: Self {
+                r#type,
+                data: data.to_owned(),
+                note: None
+            }
+        }
+    }
+}
+
+/// Builds a [`BlacklistCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct BlacklistCreatePayloadBuilder {
+    payload: BlacklistCreatePayload
+}
+impl BlacklistCreatePayloadBuilder {
+    /// Sets the note.
+    pub fn note(mut self, note: &str) -> Self {
+        self.payload.note = Some(note.to_owned());
+        self
+    }
+
+    /// Validates the data against its type, then builds the payload.
+    pub fn build(self) -> Result<BlacklistCreatePayload, ValidationError> {
+        let data = &self.payload.data;
+        validation::non_empty("data", data)?;
+        match self.payload.r#type {
+            BlacklistTypes::Email => validation::email("data", data)?,
+            BlacklistTypes::IP => validation::ip("data", data)?,
+            BlacklistTypes::Country => validation::country_code("data", data)?,
+            BlacklistTypes::ASN => validation::asn("data", data)?,
+            BlacklistTypes::ISP | BlacklistTypes::Host | BlacklistTypes::Unknown(_) => {},
+        }
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a blacklist.
+/// Only the fields that are set are sent.
+/// <https://developers.sellix.io/#blacklist-update>.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlacklistUpdatePayload<'a> {
+    /// The type of data of this blacklist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<BlacklistTypes>,
+    /// Blocked data. Either country code, email or IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<&'a str>,
     /// Internal note for the reasoning of the blacklist
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<&'a str>
 }
+impl<'a> BlacklistUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &BlacklistRaw, new: &'a BlacklistRaw) -> Self {
+        Self {
+            r#type: changed(&old.r#type, &new.r#type).cloned(),
+            data: changed(&old.data, &new.data).map(String::as_str),
+            note: changed(&old.note, &new.note).map(String::as_str),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.r#type.is_none() && self.data.is_none() && self.note.is_none()
+    }
+}
 
 /// Represents the response after creating a blacklist.
 /// <https://developers.sellix.io/#blacklist-create>.
@@ -1,14 +1,15 @@
 // Dependencies
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::formats::Flexible;
-use serde_with::TimestampSeconds;
-use std::time::SystemTime;
+use crate::money::Decimal;
+use crate::timestamp::Timestamp;
 
-use super::{payment::DiscountType, product::ProductRaw, RawAPIResponse, UniqidDict};
+use super::{changed, payment::DiscountType, product::ProductRaw, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
 
 /// Used in [`CategoryRaw`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(rename = "CategoryFeedback"))]
 pub struct Feedback {
     pub total: u64,
     pub positive: u64,
@@ -18,29 +19,30 @@ pub struct Feedback {
 
 /// Used in [`CategoryRaw`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(rename = "CategoryVolumeDiscount"))]
 pub struct VolumeDiscount {
     #[serde(rename = "type")]
     pub type_field: DiscountType,
-    pub value: u64,
+    pub value: Decimal,
     pub quantity: u64,
 }
 
 /// Used in [`CategoryRaw`].
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(rename = "CategoryGroupsBound"))]
 pub struct GroupsBound {
     pub uniqid: String,
     pub title: String,
     pub image_attachment: Value,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Represents the raw API response for a category object.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CategoryRaw {
     // ID of the resource.
     pub id: u64,
@@ -65,11 +67,11 @@ pub struct CategoryRaw {
     /// How many groups are present in the groups_bound array.
     pub groups_count: u64,
     /// Creation date of the category.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Creation date of the category.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the category has been edited.
     pub updated_by: u64,
 }
@@ -78,6 +80,7 @@ pub struct CategoryRaw {
 /// <https://developers.sellix.io/#category-get>.
 /// Used in [`CategoryGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CategoryOneRaw {
     pub category: CategoryRaw,
 }
@@ -90,6 +93,7 @@ pub type CategoryGetResponseRaw = RawAPIResponse<CategoryOneRaw>;
 /// <https://developers.sellix.io/#category-list>.
 /// Used for [`CategoryListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CategoryArray {
     pub categories: Vec<CategoryRaw>,
 }
@@ -101,14 +105,108 @@ pub type CategoryListResponseRaw = RawAPIResponse<CategoryArray>;
 /// <https://developers.sellix.io/#category-create>.
 pub type CategoryCreateResponseRaw = RawAPIResponse<UniqidDict>;
 
-/// Represents the payload for creating (or updating) a category.
+/// Represents the payload for creating a category.
 /// <https://developers.sellix.io/#category-create>.
+/// Use [`CategoryCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CategoryCreatePayload {
+    pub title: String,
+    pub unlisted: Option<bool>,
+    /// Array of product uniqids the category will contain.
+    pub products_bound: Option<Vec<String>>,
+    /// Array of group uniqids the category will contain.
+    pub groups_bound: Option<Vec<String>>,
+    pub sort_priority: Option<u64>,
+}
+impl CategoryCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(title: &str) -> CategoryCreatePayloadBuilder {
+        CategoryCreatePayloadBuilder {
+            payload: Self {
+                title: title.to_owned(),
+                unlisted: None,
+                products_bound: None,
+                groups_bound: None,
+                sort_priority: None,
+            }
+        }
+    }
+}
+
+/// Builds a [`CategoryCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct CategoryCreatePayloadBuilder {
+    payload: CategoryCreatePayload
+}
+impl CategoryCreatePayloadBuilder {
+    /// Sets whether the category is unlisted.
+    pub fn unlisted(mut self, unlisted: bool) -> Self {
+        self.payload.unlisted = Some(unlisted);
+        self
+    }
+
+    /// Sets the products the category will contain.
+    pub fn products_bound(mut self, products_bound: &[&str]) -> Self {
+        self.payload.products_bound = Some(products_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the groups the category will contain.
+    pub fn groups_bound(mut self, groups_bound: &[&str]) -> Self {
+        self.payload.groups_bound = Some(groups_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the sort priority.
+    pub fn sort_priority(mut self, sort_priority: u64) -> Self {
+        self.payload.sort_priority = Some(sort_priority);
+        self
+    }
+
+    /// Validates, then builds the payload.
+    pub fn build(self) -> Result<CategoryCreatePayload, ValidationError> {
+        validation::non_empty("title", &self.payload.title)?;
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a category.
+/// Only the fields that are set are sent.
 /// <https://developers.sellix.io/#category-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CategoryCreatePayload<'a> {
-    pub title: &'a str,
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CategoryUpdatePayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unlisted: Option<bool>,
-    pub products_bound: Option<Vec<ProductRaw>>,
-    pub groups_array: Option<Vec<GroupsBound>>,
+    /// Array of product uniqids the category will contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products_bound: Option<Vec<&'a str>>,
+    /// Array of group uniqids the category will contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups_bound: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_priority: Option<u64>,
 }
+impl<'a> CategoryUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &CategoryRaw, new: &'a CategoryRaw) -> Self {
+        Self {
+            title: changed(&old.title, &new.title).map(String::as_str),
+            unlisted: changed(&old.unlisted, &new.unlisted).copied(),
+            products_bound: Some(new.products_bound.iter().map(|x| x.uniqid.as_str()).collect())
+                .filter(|x: &Vec<&str>| !x.iter().eq(old.products_bound.iter().map(|x| &x.uniqid))),
+            groups_bound: Some(new.groups_bound.iter().map(|x| x.uniqid.as_str()).collect())
+                .filter(|x: &Vec<&str>| !x.iter().eq(old.groups_bound.iter().map(|x| &x.uniqid))),
+            sort_priority: changed(&old.sort_priority, &new.sort_priority).copied(),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.unlisted.is_none() && self.products_bound.is_none() && self.groups_bound.is_none() && self.sort_priority.is_none()
+    }
+}
@@ -1,33 +1,32 @@
 // Dependencies
 use serde::{Serialize, Deserialize};
 use super::payment::DiscountType;
-use super::{Currencies, RawAPIResponse, UniqidDict};
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
-
-/// Possible coupon types.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum CouponType {
-    Product,
-    Subscription
+use super::{changed, Currencies, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
+use crate::timestamp::Timestamp;
+
+api_enum! {
+    /// Possible coupon types.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum CouponType {
+        Product = "PRODUCT",
+        Subscription = "SUBSCRIPTION",
+    }
 }
 
-/// Describes how many times the coupon can be used.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum CouponUseType {
-    Limited,
-    Any
+api_enum! {
+    /// Describes how many times the coupon can be used.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum CouponUseType {
+        Limited = "LIMITED",
+        Any = "ANY",
+    }
 }
 
 /// Represents the raw API response for a coupon object.
 /// <https://developers.sellix.io/#coupon-object>.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CouponRaw {
     /// ID of the resource.
     pub id: u64,
@@ -46,6 +45,10 @@ pub struct CouponRaw {
     pub use_type: CouponUseType,
     /// Discount value for this coupon.
     pub discount: u64,
+    /// Whether the discount is a percentage or a fixed amount.
+    /// Not always available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_type: Option<DiscountType>,
     /// Deprecated
     pub currency: Option<Currencies>,
     /// How many times this coupon has been used.
@@ -57,8 +60,9 @@ pub struct CouponRaw {
     /// Maximum usage for this coupon.
     pub max_uses: i64,
     /// If set, the coupon will expire at that date.
-    #[serde_as(as = "Option<TimestampSeconds<String, Flexible>>")]
-    pub expire_at: Option<SystemTime>,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub expire_at: Option<Timestamp>,
     /// Array of product uniqids.
     /// Differs from the categories API as this endpoint does not need specific details about a product.
     /// Use the products API to get details about a single product.
@@ -66,11 +70,11 @@ pub struct CouponRaw {
     /// How many products are present in the products_bound array.
     pub products_count: u64,
     /// Creation date of the category.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Date, available if the category has been edited.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the category has been edited.
     pub updated_by: u64,
 }
@@ -79,6 +83,7 @@ pub struct CouponRaw {
 /// <https://developers.sellix.io/#coupon-get>.
 /// Used in [`CouponGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CouponOneRaw {
     pub coupon: CouponRaw
 }
@@ -91,6 +96,7 @@ pub type CouponGetResponseRaw = RawAPIResponse<CouponOneRaw>;
 /// <https://developers.sellix.io/#coupon-list>.
 /// Used for [`CouponListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CouponArray {
     pub coupons: Vec<CouponRaw>
 }
@@ -98,31 +104,171 @@ pub struct CouponArray {
 /// <https://developers.sellix.io/#coupon-list>.
 pub type CouponListResponseRaw = RawAPIResponse<CouponArray>;
 
-/// Represents the payload for creating (or updating) a coupon.
+/// Represents the payload for creating a coupon.
 /// <https://developers.sellix.io/#coupon-create>.
-/// <https://developers.sellix.io/#coupon-update>.
-#[serde_with::serde_as]
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CouponCreatePayload<'a> {
+/// Use [`CouponCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CouponCreatePayload {
     /// Code of the Coupon.
-    pub code: &'a str,
+    pub code: String,
     /// Percentage amount of the discount.
     pub discount_value: u64,
     /// How many times can the coupon be used, defaulted to -1.
-    pub max_uses: Option<i32>,
+    pub max_uses: Option<i64>,
     /// Array of products uniqids that the category will contain.
-    pub products_bound: Option<Vec<&'a str>>,
+    pub products_bound: Option<Vec<String>>,
     pub discount_type: Option<DiscountType>,
     /// Deprecated.
-    pub discount_order_type: Option<&'a str>,
+    pub discount_order_type: Option<String>,
     /// Whether or not this coupon is valid if a volume discount is applied.
     pub disabled_with_volume_discounts: Option<bool>,
     /// Whether or not this coupon should be applied for each product `SUBSCRIPTION` renewal.
     pub all_recurring_bill_invoices: Option<bool>,
-    #[serde_as(as = "Option<TimestampSeconds<String, Flexible>>")]
-    pub expire_at: Option<SystemTime>
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub expire_at: Option<Timestamp>
+}
+impl CouponCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(code: &str, discount_value: u64) -> CouponCreatePayloadBuilder {
+        CouponCreatePayloadBuilder {
+            payload: Self {
+                code: code.to_owned(),
+                discount_value,
+                max_uses: None,
+                products_bound: None,
+                discount_type: None,
+                discount_order_type: None,
+                disabled_with_volume_discounts: None,
+                all_recurring_bill_invoices: None,
+                expire_at: None
+            }
+        }
+    }
+}
+
+/// Builds a [`CouponCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct CouponCreatePayloadBuilder {
+    payload: CouponCreatePayload
+}
+impl CouponCreatePayloadBuilder {
+    /// Sets the maximum number of uses.
+    pub fn max_uses(mut self, max_uses: i64) -> Self {
+        self.payload.max_uses = Some(max_uses);
+        self
+    }
+
+    /// Sets the products the coupon can be used with.
+    pub fn products_bound(mut self, products_bound: &[&str]) -> Self {
+        self.payload.products_bound = Some(products_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the discount type.
+    pub fn discount_type(mut self, discount_type: DiscountType) -> Self {
+        self.payload.discount_type = Some(discount_type);
+        self
+    }
+
+    /// Sets whether the coupon is valid if a volume discount is applied.
+    pub fn disabled_with_volume_discounts(mut self, disabled_with_volume_discounts: bool) -> Self {
+        self.payload.disabled_with_volume_discounts = Some(disabled_with_volume_discounts);
+        self
+    }
+
+    /// Sets whether the coupon is applied for each subscription renewal.
+    pub fn all_recurring_bill_invoices(mut self, all_recurring_bill_invoices: bool) -> Self {
+        self.payload.all_recurring_bill_invoices = Some(all_recurring_bill_invoices);
+        self
+    }
+
+    /// Sets when the coupon expires.
+    pub fn expire_at(mut self, expire_at: impl Into<Timestamp>) -> Self {
+        self.payload.expire_at = Some(expire_at.into());
+        self
+    }
+
+    /// Validates, then builds the payload.
+    /// The discount is treated as a percentage unless the discount type is `FIXED`.
+    pub fn build(self) -> Result<CouponCreatePayload, ValidationError> {
+        validation::non_empty("code", &self.payload.code)?;
+        if self.payload.discount_type != Some(DiscountType::Fixed) && self.payload.discount_value > 100 {
+            return Err(ValidationError::new("discount_value", "a percentage discount must not be over 100"));
+        }
+        if matches!(self.payload.max_uses, Some(x) if x < -1 || x == 0) {
+            return Err(ValidationError::new("max_uses", "must be positive, or -1 for unlimited"));
+        }
+        Ok(self.payload)
+    }
 }
 
 /// Represents the response after creating a coupon.
 /// <https://developers.sellix.io/#coupon-create>.
-pub type CouponCreateResponseRaw = RawAPIResponse<UniqidDict>;
\ No newline at end of file
+pub type CouponCreateResponseRaw = RawAPIResponse<UniqidDict>;
+
+/// Represents the payload for updating a coupon.
+/// Only the fields that are set are sent, so an expiry date cannot be removed this way.
+/// <https://developers.sellix.io/#coupon-update>.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CouponUpdatePayload<'a> {
+    /// Code of the Coupon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    /// Percentage amount of the discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_value: Option<u64>,
+    /// How many times can the coupon be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<i64>,
+    /// Array of products uniqids that the coupon is bound to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products_bound: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_type: Option<DiscountType>,
+    /// Whether or not this coupon is valid if a volume discount is applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_with_volume_discounts: Option<bool>,
+    /// Whether or not this coupon should be applied for each product `SUBSCRIPTION` renewal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_recurring_bill_invoices: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub expire_at: Option<Timestamp>
+}
+impl<'a> CouponUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    ///
+    /// Unset fields are not sent, so an update cannot remove the expiry date or discount type of a coupon.
+    /// Returns an error if `new` removes either, rather than an update which leaves them as they were.
+    pub fn diff(old: &CouponRaw, new: &'a CouponRaw) -> Result<Self, ValidationError> {
+        if old.expire_at.is_some() && new.expire_at.is_none() {
+            return Err(ValidationError::new("expire_at", "cannot be removed by an update"));
+        }
+        if old.discount_type.is_some() && new.discount_type.is_none() {
+            return Err(ValidationError::new("discount_type", "cannot be removed by an update"));
+        }
+
+        Ok(Self {
+            code: changed(&old.code, &new.code).map(String::as_str),
+            discount_value: changed(&old.discount, &new.discount).copied(),
+            max_uses: changed(&old.max_uses, &new.max_uses).copied(),
+            products_bound: changed(&old.products_bound, &new.products_bound)
+                .map(|x| x.iter().flatten().map(String::as_str).collect()),
+            discount_type: changed(&old.discount_type, &new.discount_type).cloned().flatten(),
+            disabled_with_volume_discounts: changed(&old.disabled_with_volume_discounts, &new.disabled_with_volume_discounts).copied(),
+            all_recurring_bill_invoices: changed(&old.all_recurring_bill_invoices, &new.all_recurring_bill_invoices).copied(),
+            expire_at: changed(&old.expire_at, &new.expire_at).copied().flatten(),
+        })
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.code.is_none() && self.discount_value.is_none() && self.max_uses.is_none() && self.products_bound.is_none()
+            && self.discount_type.is_none() && self.disabled_with_volume_discounts.is_none()
+            && self.all_recurring_bill_invoices.is_none() && self.expire_at.is_none()
+    }
+}
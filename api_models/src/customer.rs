@@ -1,11 +1,12 @@
 // Dependencies
 use serde::{Deserialize, Serialize};
 
-use super::{RawAPIResponse, UniqidDict};
+use super::{changed, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
 
 /// Represents the raw API response for a customer object.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CustomerRaw {
     /// Customer ID
     pub id: String,
@@ -39,6 +40,7 @@ pub struct CustomerRaw {
 /// <https://developers.sellix.io/#customer-get>.
 /// Used in [`CustomerGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CustomerOneRaw {
     pub customer: CustomerRaw
 }
@@ -51,6 +53,7 @@ pub type CustomerGetResponseRaw = RawAPIResponse<CustomerOneRaw>;
 /// <https://developers.sellix.io/#customer-list>.
 /// Used for [`CustomerListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CustomerArray {
     pub customers: Vec<CustomerRaw>
 }
@@ -62,31 +65,183 @@ pub type CustomerListResponseRaw = RawAPIResponse<CustomerArray>;
 /// <https://developers.sellix.io/#customer-create>.
 pub type CustomerCreateResponseRaw = RawAPIResponse<UniqidDict>;
 
-/// Represents the payload for creating (or updating) a customer.
+/// Represents the payload for creating a customer.
 /// <https://developers.sellix.io/#customer-create>.
+/// Use [`CustomerCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct CustomerCreatePayload {
+    /// Customer name
+    pub name: String,
+    /// Customer surname
+    pub surname: String,
+    /// Customer phone
+    pub phone: Option<String>,
+    /// Customer phone country code
+    pub phone_country_code: Option<String>,
+    /// Customer country code
+    pub country_code: Option<String>,
+    /// Customer street address
+    pub address: Option<String>,
+    /// Customer street address additional info
+    pub additional_address_info: Option<String>,
+    /// Customer city
+    pub city: Option<String>,
+    /// Customer postal code
+    pub postal_code: Option<String>,
+    /// Customer state
+    pub state: Option<String>,
+    /// Customer email
+    pub email: String
+}
+impl CustomerCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(name: &str, surname: &str, email: &str) -> CustomerCreatePayloadBuilder {
+        CustomerCreatePayloadBuilder {
+            payload: Self {
+                name: name.to_owned(),
+                surname: surname.to_owned(),
+                phone: None,
+                phone_country_code: None,
+                country_code: None,
+                address: None,
+                additional_address_info: None,
+                city: None,
+                postal_code: None,
+                state: None,
+                email: email.to_owned()
+            }
+        }
+    }
+}
+
+/// Builds a [`CustomerCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct CustomerCreatePayloadBuilder {
+    payload: CustomerCreatePayload
+}
+impl CustomerCreatePayloadBuilder {
+    /// Sets the phone.
+    pub fn phone(mut self, phone: &str) -> Self {
+        self.payload.phone = Some(phone.to_owned());
+        self
+    }
+
+    /// Sets the phone country code.
+    pub fn phone_country_code(mut self, phone_country_code: &str) -> Self {
+        self.payload.phone_country_code = Some(phone_country_code.to_owned());
+        self
+    }
+
+    /// Sets the country code.
+    pub fn country_code(mut self, country_code: &str) -> Self {
+        self.payload.country_code = Some(country_code.to_owned());
+        self
+    }
+
+    /// Sets the street address.
+    pub fn address(mut self, address: &str) -> Self {
+        self.payload.address = Some(address.to_owned());
+        self
+    }
+
+    /// Sets the street address additional info.
+    pub fn additional_address_info(mut self, additional_address_info: &str) -> Self {
+        self.payload.additional_address_info = Some(additional_address_info.to_owned());
+        self
+    }
+
+    /// Sets the city.
+    pub fn city(mut self, city: &str) -> Self {
+        self.payload.city = Some(city.to_owned());
+        self
+    }
+
+    /// Sets the postal code.
+    pub fn postal_code(mut self, postal_code: &str) -> Self {
+        self.payload.postal_code = Some(postal_code.to_owned());
+        self
+    }
+
+    /// Sets the state.
+    pub fn state(mut self, state: &str) -> Self {
+        self.payload.state = Some(state.to_owned());
+        self
+    }
+
+    /// Validates, then builds the payload.
+    pub fn build(self) -> Result<CustomerCreatePayload, ValidationError> {
+        validation::non_empty("name", &self.payload.name)?;
+        validation::non_empty("surname", &self.payload.surname)?;
+        validation::email("email", &self.payload.email)?;
+        if let Some(country_code) = &self.payload.country_code {
+            validation::country_code("country_code", country_code)?;
+        }
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a customer.
+/// Only the fields that are set are sent.
 /// <https://developers.sellix.io/#customer-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CustomerCreatePayload<'a> {
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CustomerUpdatePayload<'a> {
     /// Customer name
-    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
     /// Customer surname
-    pub surname: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surname: Option<&'a str>,
     /// Customer phone
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone: Option<&'a str>,
     /// Customer phone country code
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_country_code: Option<&'a str>,
     /// Customer country code
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<&'a str>,
     /// Customer street address
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<&'a str>,
     /// Customer street address additional info
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_address_info: Option<&'a str>,
     /// Customer city
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub city: Option<&'a str>,
     /// Customer postal code
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub postal_code: Option<&'a str>,
     /// Customer state
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<&'a str>,
     /// Customer email
-    pub email: &'a str,
-}
\ No newline at end of file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+}
+impl<'a> CustomerUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &CustomerRaw, new: &'a CustomerRaw) -> Self {
+        Self {
+            name: changed(&old.name, &new.name).map(String::as_str),
+            surname: changed(&old.surname, &new.surname).map(String::as_str),
+            phone: changed(&old.phone, &new.phone).map(String::as_str),
+            phone_country_code: changed(&old.phone_country_code, &new.phone_country_code).map(String::as_str),
+            country_code: changed(&old.country_code, &new.country_code).map(String::as_str),
+            address: changed(&old.address, &new.address).map(String::as_str),
+            additional_address_info: changed(&old.additional_address_info, &new.additional_address_info).map(String::as_str),
+            city: changed(&old.city, &new.city).map(String::as_str),
+            postal_code: changed(&old.postal_code, &new.postal_code).map(String::as_str),
+            state: changed(&old.state, &new.state).map(String::as_str),
+            email: changed(&old.email, &new.email).map(String::as_str),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.surname.is_none() && self.phone.is_none() && self.phone_country_code.is_none() && self.country_code.is_none() && self.address.is_none() && self.additional_address_info.is_none() && self.city.is_none() && self.postal_code.is_none() && self.state.is_none() && self.email.is_none()
+    }
+}
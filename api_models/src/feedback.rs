@@ -1,15 +1,12 @@
 // Dependencies
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
-use serde_repr::{Serialize_repr, Deserialize_repr};
-use strum_macros::FromRepr;
 use super::RawAPIResponse;
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
+use crate::timestamp::Timestamp;
 
 /// Used to total up the feedback.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Feedback {
     pub total: u64,
     pub positive: u64,
@@ -17,64 +14,94 @@ pub struct Feedback {
     pub negative: u64,
 }
 
-/// The outcome of an appeal.
-/// Used by [`FeedbackRaw`]
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum AppealOutcome {
-    NoAppealAvailable,
-    Approved,
-    Rejected
+api_enum! {
+    /// The outcome of an appeal.
+    /// Used by [`FeedbackRaw`]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum AppealOutcome {
+        NoAppealAvailable = "NOAPPEALAVAILABLE",
+        Approved = "APPROVED",
+        Rejected = "REJECTED",
+    }
 }
 
-/// The possible feedback scores.
-/// Used in [`FeedbackRaw`].
-#[derive(Debug, Serialize_repr, Deserialize_repr, PartialEq, FromRepr, strum_macros::EnumString, strum_macros::Display)]
-#[repr(u16)]
-pub enum FeedbackScore {
-    Zero = 0,
-    One = 1,
-    Two = 2,
-    Three = 3,
-    Four = 4,
-    Five = 5
+api_code_enum! {
+    /// The possible feedback scores.
+    /// Used in [`FeedbackRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum FeedbackScore {
+        Zero = 0,
+        One = 1,
+        Two = 2,
+        Three = 3,
+        Four = 4,
+        Five = 5,
+    }
+}
+impl std::fmt::Display for FeedbackScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedbackScore::Zero => f.pad("Zero"),
+            FeedbackScore::One => f.pad("One"),
+            FeedbackScore::Two => f.pad("Two"),
+            FeedbackScore::Three => f.pad("Three"),
+            FeedbackScore::Four => f.pad("Four"),
+            FeedbackScore::Five => f.pad("Five"),
+            FeedbackScore::Unknown(code) => write!(f, "{}", code),
+        }
+    }
+}
+impl std::str::FromStr for FeedbackScore {
+    type Err = std::num::ParseIntError;
+
+    /// Parses the name of a score, e.g. `Five`, or its code.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "Zero" => Ok(FeedbackScore::Zero),
+            "One" => Ok(FeedbackScore::One),
+            "Two" => Ok(FeedbackScore::Two),
+            "Three" => Ok(FeedbackScore::Three),
+            "Four" => Ok(FeedbackScore::Four),
+            "Five" => Ok(FeedbackScore::Five),
+            _ => value.parse::<u16>().map(FeedbackScore::from),
+        }
+    }
 }
 
 /// Represents the raw API response for a feedback object.
 /// <https://developers.sellix.io/#feedback-object>
-#[serde_with::serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackRaw {
     /// ID of the resource.
-    id: u64,
+    pub id: u64,
     /// Unique ID of the resource,
     /// used as reference across the API.
-    uniqid: String,
+    pub uniqid: String,
     /// Unique ID of the product for which this feedback has been posted.
-    product_id: String,
+    pub product_id: String,
     /// Unique ID of the product for which this feedback has been posted.
-    invoice_id: String,
+    pub invoice_id: String,
     /// If `true`, this feedback has been blocked after an appeal.
-    blocked: bool,
+    pub blocked: bool,
     /// If `true`, an appeal has been created for this feedback.
-    appealed: bool,
+    pub appealed: bool,
     /// Not always available.
-    appeal_outcome: AppealOutcome,
+    pub appeal_outcome: Option<AppealOutcome>,
     /// The shop ID to which this feedback belongs.
-    shop_id: u64,
+    pub shop_id: u64,
     /// Message left by the customer.
-    message: String,
+    pub message: String,
     /// Reply left by the merchant.
-    reply: String,
+    pub reply: Option<String>,
     /// Score left by the customer, if 0 no score has been left. From 0 to 5.
-    score: FeedbackScore,
+    pub score: FeedbackScore,
     /// Product title for which this feedback has been created.
-    product_title: String,
+    pub product_title: String,
     /// Deprecated
-    product_image_name: Option<String>,
+    pub product_image_name: Option<String>,
     /// Deprecated
-    product_image_storage: Option<String>,
+    pub product_image_storage: Option<String>,
     /// New field containing the cloudflare image ID of this product,
     /// replaces image_attachment and image_name.
     /// 
@@ -82,25 +109,26 @@ pub struct FeedbackRaw {
     /// where
     /// 
     /// `variant_name` can be `shopItem`, `avatar`, `icon`, `imageAvatarFeedback`, `public`, `productImageCart`.
-    cloudflare_image_id: String,
+    pub cloudflare_image_id: String,
     /// Contains the full invoice object for this feedback.
-    invoice: Value,
+    pub invoice: Value,
     /// Contains the full product object for this feedback.
-    product: Value,
+    pub product: Value,
     /// Creation data of the product.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Date, available if the product has been edited.
-    #[serde_as(as = "Option<TimestampSeconds<String, Flexible>>")]
-    updated_at: Option<SystemTime>,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the product has been edited.
-    updated_by: Option<u64>
+    pub updated_by: Option<u64>
 }
 
 /// Raw API response from here.
 /// <https://developers.sellix.io/#feedback-get>.
 /// Used in [`FeedbackGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackOneRaw {
     pub feedback: FeedbackRaw
 }
@@ -113,6 +141,7 @@ pub type FeedbackGetResponseRaw = RawAPIResponse<FeedbackOneRaw>;
 /// <https://developers.sellix.io/#feedback-list>.
 /// Used for [`FeedbackListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackArray {
     pub feedbacks: Vec<FeedbackRaw>
 }
@@ -123,6 +152,14 @@ pub type FeedbackListResponseRaw = RawAPIResponse<FeedbackArray>;
 /// Payload for replying to feedback.
 /// <https://developers.sellix.io/#feedback-reply>.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedbackReplyPayload<'a> {
     pub reply: &'a str
+}
+impl<'a> From<&'a str> for FeedbackReplyPayload<'a> {
+    fn from(reply: &'a str) -> Self {
+        Self {
+            reply
+        }
+    }
 }
\ No newline at end of file
@@ -1,28 +1,27 @@
 // Dependencies
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::formats::Flexible;
-use serde_with::TimestampSeconds;
-use std::time::SystemTime;
+use crate::timestamp::Timestamp;
 
-use super::{product::ProductRaw, RawAPIResponse, UniqidDict};
+use super::{changed, product::ProductRaw, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
 
 /// Used in [`GroupRaw`].
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GroupsBound {
     pub uniqid: String,
     pub title: String,
     pub image_attachment: Value,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Represents the raw API response for a group object.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GroupRaw {
     // ID of the resource.
     pub id: u64,
@@ -47,11 +46,11 @@ pub struct GroupRaw {
     /// How many groups are present in the groups_bound array.
     pub groups_count: u64,
     /// Creation date of the group.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Creation date of the group.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the group has been edited.
     pub updated_by: u64,
 }
@@ -60,6 +59,7 @@ pub struct GroupRaw {
 /// <https://developers.sellix.io/#group-get>.
 /// Used in [`GroupGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GroupOneRaw {
     pub group: GroupRaw,
 }
@@ -72,6 +72,7 @@ pub type GroupGetResponseRaw = RawAPIResponse<GroupOneRaw>;
 /// <https://developers.sellix.io/#group-list>.
 /// Used for [`GroupListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GroupArray {
     pub groups: Vec<GroupRaw>,
 }
@@ -83,14 +84,94 @@ pub type GroupListResponseRaw = RawAPIResponse<GroupArray>;
 /// <https://developers.sellix.io/#group-create>.
 pub type GroupCreateResponseRaw = RawAPIResponse<UniqidDict>;
 
-/// Represents the payload for creating (or updating) a group.
+/// Represents the payload for creating a group.
 /// <https://developers.sellix.io/#group-create>.
+/// Use [`GroupCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GroupCreatePayload {
+    pub title: String,
+    pub unlisted: Option<bool>,
+    /// Array of product uniqids the group will contain.
+    pub products_bound: Option<Vec<String>>,
+    pub sort_priority: Option<u64>,
+}
+impl GroupCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(title: &str) -> GroupCreatePayloadBuilder {
+        GroupCreatePayloadBuilder {
+            payload: Self {
+                title: title.to_owned(),
+                unlisted: None,
+                products_bound: None,
+                sort_priority: None,
+            }
+        }
+    }
+}
+
+/// Builds a [`GroupCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct GroupCreatePayloadBuilder {
+    payload: GroupCreatePayload
+}
+impl GroupCreatePayloadBuilder {
+    /// Sets whether the group is unlisted.
+    pub fn unlisted(mut self, unlisted: bool) -> Self {
+        self.payload.unlisted = Some(unlisted);
+        self
+    }
+
+    /// Sets the products the group will contain.
+    pub fn products_bound(mut self, products_bound: &[&str]) -> Self {
+        self.payload.products_bound = Some(products_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the sort priority.
+    pub fn sort_priority(mut self, sort_priority: u64) -> Self {
+        self.payload.sort_priority = Some(sort_priority);
+        self
+    }
+
+    /// Validates, then builds the payload.
+    pub fn build(self) -> Result<GroupCreatePayload, ValidationError> {
+        validation::non_empty("title", &self.payload.title)?;
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a group.
+/// Only the fields that are set are sent.
 /// <https://developers.sellix.io/#group-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct GroupCreatePayload<'a> {
-    pub title: &'a str,
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct GroupUpdatePayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unlisted: Option<bool>,
-    pub products_bound: Option<Vec<ProductRaw>>,
-    pub groups_array: Option<Vec<GroupsBound>>,
+    /// Array of product uniqids the group will contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products_bound: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_priority: Option<u64>,
 }
+impl<'a> GroupUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &GroupRaw, new: &'a GroupRaw) -> Self {
+        Self {
+            title: changed(&old.title, &new.title).map(String::as_str),
+            unlisted: changed(&old.unlisted, &new.unlisted).copied(),
+            products_bound: Some(new.products_bound.iter().map(|x| x.uniqid.as_str()).collect())
+                .filter(|x: &Vec<&str>| !x.iter().eq(old.products_bound.iter().map(|x| &x.uniqid))),
+            sort_priority: changed(&old.sort_priority, &new.sort_priority).copied(),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.unlisted.is_none() && self.products_bound.is_none() && self.sort_priority.is_none()
+    }
+}
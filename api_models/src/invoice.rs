@@ -1,36 +1,33 @@
-// Dependencies
-use serde::{Serialize, Deserialize};
-
-/// Represents the current status of an invoice.
-/// Used in [`InvoiceRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum InvoiceStatus {
-    Pending,
-    Completed,
-    Voided,
-    WaitingForConfirmations,
-    Partial,
-    CustomerDisputeOngoing,
-    Reversed,
-    Refunded,
-    WaitingShopAction,
-    Processing
+api_enum! {
+    /// Represents the current status of an invoice.
+    /// Used in [`InvoiceRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum InvoiceStatus {
+        Pending = "PENDING",
+        Completed = "COMPLETED",
+        Voided = "VOIDED",
+        WaitingForConfirmations = "WAITING_FOR_CONFIRMATIONS",
+        Partial = "PARTIAL",
+        CustomerDisputeOngoing = "CUSTOMER_DISPUTE_ONGOING",
+        Reversed = "REVERSED",
+        Refunded = "REFUNDED",
+        WaitingShopAction = "WAITING_SHOP_ACTION",
+        Processing = "PROCESSING",
+    }
 }
 
-/// Adds more information onto [`InvoiceStatus`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum InvoiceStatusDetails {
-    CartPartialOutOfStock    
+api_enum! {
+    /// Adds more information onto [`InvoiceStatus`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum InvoiceStatusDetails {
+        CartPartialOutOfStock = "CART_PARTIAL_OUT_OF_STOCK",
+    }
 }
 
-/// Adds more information onto [`InvoiceStatus`], if it was `VOIDED`.
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum InvoiceVoidDetails {
-    CartProductsOutOfStock    
+api_enum! {
+    /// Adds more information onto [`InvoiceStatus`], if it was `VOIDED`.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum InvoiceVoidDetails {
+        CartProductsOutOfStock = "CART_PRODUCTS_OUT_OF_STOCK",
+    }
 }
\ No newline at end of file
@@ -1,4 +1,8 @@
 // Exports
+#[macro_use]
+mod api_enum;
+pub mod analytics;
+pub mod audit;
 pub mod blacklist;
 pub mod subscription;
 pub mod license;
@@ -16,27 +20,36 @@ pub mod group;
 pub mod customer;
 pub mod query;
 pub mod webhook;
+pub mod money;
+pub mod pricing;
+pub mod route;
+#[cfg(feature = "schemars")]
+pub mod schema;
+pub mod timestamp;
+pub mod validation;
+pub use money::Money;
+pub use route::Route;
+pub use timestamp::{Timestamp, Timestamped};
 
 // Dependencies
-use reqwest::{Method, Error};
+use reqwest::Error;
 use serde::{Serialize, Deserialize};
-use serde_repr::{Serialize_repr, Deserialize_repr};
-use strum_macros::FromRepr;
 use std::fmt;
 
-/// Includes all of the base sellix http codes the API can respond with.
-#[derive(Clone, Debug, Serialize_repr, Deserialize_repr, PartialEq, FromRepr)]
-#[repr(u16)]
-pub enum SellixHttpCode {
-    Ok = 200,
-    BadRequest = 400,
-    Unauthorized = 401,
-    Forbidden = 403,
-    NotFound = 404,
-    NotAcceptable = 406,
-    TooManyRequests = 429,
-    InternalServerError = 500,
-    ServiceUnavailable = 503
+api_code_enum! {
+    /// Includes all of the base sellix http codes the API can respond with.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum SellixHttpCode {
+        Ok = 200,
+        BadRequest = 400,
+        Unauthorized = 401,
+        Forbidden = 403,
+        NotFound = 404,
+        NotAcceptable = 406,
+        TooManyRequests = 429,
+        InternalServerError = 500,
+        ServiceUnavailable = 503,
+    }
 }
 impl SellixHttpCode {
     // Returns the name of the error code
@@ -51,6 +64,7 @@ impl SellixHttpCode {
             SellixHttpCode::TooManyRequests => "Too Many Requests",
             SellixHttpCode::InternalServerError => "Internal Server Error",
             SellixHttpCode::ServiceUnavailable => "Service Unavailable",
+            SellixHttpCode::Unknown(_) => "Unknown",
         }
     }
 
@@ -66,18 +80,65 @@ impl SellixHttpCode {
             SellixHttpCode::TooManyRequests => "You have reached the rate limit",
             SellixHttpCode::InternalServerError => "We had a problem with our server. Try again later. These are rare.",
             SellixHttpCode::ServiceUnavailable => "We're temporarily offline for maintenance. Please try again later.",
+            SellixHttpCode::Unknown(_) => "The API responded with a status this version of the crate does not know.",
         }
     }
 }
 impl std::fmt::Display for SellixHttpCode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{} - {}", self.name(), self.description())
+        match self {
+            SellixHttpCode::Unknown(code) => write!(f, "{} {} - {}", self.name(), code, self.description()),
+            _ => write!(f, "{} - {}", self.name(), self.description()),
+        }
     }
 }
 impl std::error::Error for SellixHttpCode {}
 
+/// What kind of error a [`SellixError`] is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SellixErrorKind {
+    /// The API responded with this status, or the request was refused the same way the API would have.
+    Http(SellixHttpCode),
+    /// The request never got a response, e.g. the host could not be resolved, the connection failed or it timed out.
+    Transport,
+    /// A response was received, but it could not be read or did not contain what was expected.
+    Decode,
+    /// Listing every page gave up, as the API kept returning new pages, or the same page again.
+    TooManyPages,
+}
+impl SellixErrorKind {
+    /// The HTTP status, if this is one.
+    pub fn http_code(&self) -> Option<&SellixHttpCode> {
+        match self {
+            Self::Http(code) => Some(code),
+            _ => None,
+        }
+    }
+}
+impl From<SellixHttpCode> for SellixErrorKind {
+    fn from(code: SellixHttpCode) -> Self {
+        Self::Http(code)
+    }
+}
+impl PartialEq<SellixHttpCode> for SellixErrorKind {
+    fn eq(&self, other: &SellixHttpCode) -> bool {
+        self.http_code() == Some(other)
+    }
+}
+impl fmt::Display for SellixErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(code) => write!(f, "{}", code),
+            Self::Transport => write!(f, "Transport - The request did not get a response from the API."),
+            Self::Decode => write!(f, "Decode - The response could not be read into what was expected."),
+            Self::TooManyPages => write!(f, "Too Many Pages - The API kept returning new pages."),
+        }
+    }
+}
+
 /// A base API response from the Sellix API.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RawAPIResponse<T> {
     pub status: SellixHttpCode,
     pub data: Option<T>,
@@ -86,8 +147,58 @@ pub struct RawAPIResponse<T> {
     pub error: Option<String>,
     pub env: String,
 }
+impl<T> RawAPIResponse<T> {
+    /// The data of the response, or an error if it has none, e.g. a successful response with `"data": null`.
+    pub fn into_data(self) -> Result<T, SellixError> {
+        self.data.ok_or_else(|| SellixError {
+            kind: SellixErrorKind::Decode,
+            message: format!("expected the response to contain data, but it had none (status {})", self.status.code()),
+        })
+    }
+}
+
+/// Implemented by every model which can be listed, giving what identifies it.
+pub trait Identified {
+    /// The uniqid, or the id of a model without one, e.g. a customer.
+    fn uniqid(&self) -> &str;
+}
+
+/// Implements [`IntoIterator`] for each list response, over the items it contains,
+/// and [`Identified`] for the items, by the given field.
+macro_rules! list_items {
+    ($( $array:ty => $field:ident: $raw:ty => $id:ident ),* $(,)?) => {$(
+        impl IntoIterator for $array {
+            type Item = $raw;
+            type IntoIter = std::vec::IntoIter<$raw>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.$field.into_iter()
+            }
+        }
+
+        impl Identified for $raw {
+            fn uniqid(&self) -> &str {
+                &self.$id
+            }
+        }
+    )*};
+}
+
+list_items!(
+    blacklist::BlacklistArray => blacklists: blacklist::BlacklistRaw => uniqid,
+    whitelist::WhitelistArray => whitelists: whitelist::WhitelistRaw => uniqid,
+    category::CategoryArray => categories: category::CategoryRaw => uniqid,
+    coupon::CouponArray => coupons: coupon::CouponRaw => uniqid,
+    feedback::FeedbackArray => feedbacks: feedback::FeedbackRaw => uniqid,
+    order::OrderArray => orders: order::OrderRaw => uniqid,
+    group::GroupArray => groups: group::GroupRaw => uniqid,
+    customer::CustomerArray => customers: customer::CustomerRaw => id,
+    query::QueryArray => queries: query::QueryRaw => uniqid,
+    subscription::SubscriptionArray => subscriptions: subscription::SubscriptionRaw => id,
+);
 
 /// All of the possible requests you can make to the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RequestType {
     BlacklistGet,
     BlacklistList,
@@ -146,110 +257,89 @@ pub enum RequestType {
     SubscriptionCreate,
     SubscriptionDestroy,
 }
-impl RequestType {
-    /// Returns a tuple that describes the method and path corrosponding to the [`RequestType`].
-    pub fn request_details(&self) -> (Method, &str) {
-        match self {
-            RequestType::BlacklistGet => (Method::GET, "/blacklists/{{uniqid}}"),
-            RequestType::BlacklistList => (Method::GET, "/blacklists?page={{page}}"),
-            RequestType::BlacklistCreate => (Method::POST, "/blacklists"),
-            RequestType::BlacklistUpdate => (Method::PUT, "/blacklists/{{uniqid}}"),
-            RequestType::BlacklistDestroy => (Method::DELETE, "/blacklists/{{uniqid}}"),
-
-            RequestType::WhitelistGet => (Method::GET, "/whitelists/{{uniqid}}"),
-            RequestType::WhitelistList => (Method::GET, "/whitelists?page={{page}}"),
-            RequestType::WhitelistCreate => (Method::POST, "/whitelists"),
-            RequestType::WhitelistUpdate => (Method::PUT, "/whitelists/{{uniqid}}"),
-            RequestType::WhitelistDestroy => (Method::DELETE, "/whitelists/{{uniqid}}"),
-
-            RequestType::CategoryGet => (Method::GET, "/categories/{{uniqid}}"),
-            RequestType::CategoryList => (Method::GET, "/categories?page={{page}}"),
-            RequestType::CategoryCreate => (Method::POST, "/categories"),
-            RequestType::CategoryUpdate => (Method::PUT, "/categories/{{uniqid}}"),
-            RequestType::CategoryDestroy => (Method::DELETE, "/categories/{{uniqid}}"),
 
-            RequestType::CouponGet => (Method::GET, "/coupons/{{uniqid}}"),
-            RequestType::CouponList => (Method::GET, "/coupons?page={{page}}"),
-            RequestType::CouponCreate => (Method::POST, "/coupons"),
-            RequestType::CouponUpdate => (Method::PUT, "/coupons/{{uniqid}}"),
-            RequestType::CouponDestroy => (Method::DELETE, "/coupons/{{uniqid}}"),
-
-            RequestType::FeedbackGet => (Method::GET, "/feedback/{{uniqid}}"),
-            RequestType::FeedbackList => (Method::GET, "/feedback?page={{page}}"),
-            RequestType::FeedbackReply => (Method::POST, "/feedback/reply/{{uniqid}}"),
-
-            RequestType::OrderGet => (Method::GET, "/orders/{{uniqid}}"),
-            RequestType::OrderList => (Method::GET, "/orders?page={{page}}"),
-            RequestType::OrderCreate => (Method::POST, "/orders"),
-            RequestType::OrderUpdate => (Method::PUT, "/orders/{{uniqid}}"),
-            RequestType::OrderDestroy => (Method::DELETE, "/orders/{{uniqid}}"),
-
-            RequestType::GroupGet => (Method::GET, "/groups/{{uniqid}}"),
-            RequestType::GroupList => (Method::GET, "/groups?page={{page}}"),
-            RequestType::GroupCreate => (Method::POST, "/groups"),
-            RequestType::GroupUpdate => (Method::PUT, "/groups/{{uniqid}}"),
-            RequestType::GroupDestroy => (Method::DELETE, "/groups/{{uniqid}}"),
-    
-            RequestType::CustomerGet => (Method::GET, "/customers/{{uniqid}}"),
-            RequestType::CustomerList => (Method::GET, "/customers?page={{page}}"),
-            RequestType::CustomerCreate => (Method::POST, "/customers"),
-            RequestType::CustomerUpdate => (Method::PUT, "/customers/{{uniqid}}"),
-            RequestType::CustomerDestroy => (Method::DELETE, "/customers/{{uniqid}}"),
+/// The resources (or "sub-APIs") a [`RequestType`] can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all="lowercase")]
+pub enum ResourceKind {
+    Blacklist,
+    Whitelist,
+    Category,
+    Coupon,
+    Feedback,
+    Order,
+    Group,
+    Customer,
+    Query,
+    Subscription
+}
 
-            RequestType::QueryGet => (Method::GET, "/queries/{{uniqid}}"),
-            RequestType::QueryList => (Method::GET, "/queries?page={{page}}"),
-            RequestType::QueryCreate => (Method::POST, "/queries"),
-            RequestType::QueryUpdate => (Method::PUT, "/queries/{{uniqid}}"),
-            RequestType::QueryDestroy => (Method::DELETE, "/queries/{{uniqid}}"),
+/// What a [`RequestType`] does to its resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all="lowercase")]
+pub enum RequestAction {
+    Get,
+    List,
+    Create,
+    Update,
+    Destroy,
+    Reply
+}
+impl RequestAction {
+    /// Whether the action only reads data.
+    pub fn is_read(&self) -> bool {
+        matches!(self, RequestAction::Get | RequestAction::List)
+    }
+}
 
-            RequestType::SubscriptionGet => (Method::GET, "/subscriptions/{{uniqid}}"),
-            RequestType::SubscriptionList => (Method::GET, "/subscriptions?page={{page}}"),
-            RequestType::SubscriptionCreate => (Method::POST, "/subscriptions"),
-            RequestType::SubscriptionDestroy => (Method::DELETE, "/subscriptions/{{uniqid}}"),
-        }
+api_enum! {
+    /// All of the supported currencies.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum Currencies {
+        CAD = "CAD",
+        HKD = "HKD",
+        ISK = "ISK",
+        PHP = "PHP",
+        DKK = "DKK",
+        HUF = "HUF",
+        CZK = "CZK",
+        GBP = "GBP",
+        RON = "RON",
+        SEK = "SEK",
+        IDR = "IDR",
+        INR = "INR",
+        BRL = "BRL",
+        RUB = "RUB",
+        HRK = "HRK",
+        JPY = "JPY",
+        THB = "THB",
+        CHF = "CHF",
+        EUR = "EUR",
+        MYR = "MYR",
+        BGN = "BGN",
+        TRY = "TRY",
+        CNY = "CNY",
+        NOK = "NOK",
+        NZD = "NZD",
+        ZAR = "ZAR",
+        USD = "USD",
+        MXN = "MXN",
+        SGD = "SGD",
+        AUD = "AUD",
+        ILS = "ILS",
+        KRW = "KRW",
+        PLN = "PLN",
     }
 }
 
-/// All of the supported currencies.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-pub enum Currencies {
-    CAD,
-    HKD,
-    ISK,
-    PHP,
-    DKK,
-    HUF,
-    CZK,
-    GBP,
-    RON,
-    SEK,
-    IDR,
-    INR,
-    BRL,
-    RUB,
-    HRK,
-    JPY,
-    THB,
-    CHF,
-    EUR,
-    MYR,
-    BGN,
-    TRY,
-    CNY,
-    NOK,
-    NZD,
-    ZAR,
-    USD,
-    MXN,
-    SGD,
-    AUD,
-    ILS,
-    KRW,
-    PLN
+/// Returns `new` if it differs from `old`.
+/// Used to build the minimal update payloads.
+pub(crate) fn changed<'a, T: PartialEq + ?Sized>(old: &T, new: &'a T) -> Option<&'a T> {
+    if old == new { None } else { Some(new) }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UniqidDict {
     pub uniqid: String
 }
@@ -257,7 +347,7 @@ pub struct UniqidDict {
 /// Custom error, since Sellix does not send error codes over HTTP
 #[derive(Debug)]
 pub struct SellixError {
-    pub kind: SellixHttpCode,
+    pub kind: SellixErrorKind,
     pub message: String,
 }
 impl fmt::Display for SellixError {
@@ -268,38 +358,48 @@ impl fmt::Display for SellixError {
 impl std::error::Error for SellixError {}
 impl From<Error> for SellixError {
     fn from(error: Error) -> Self {
-        println!("{}", error);
+        // Errors without a status either never got a response, e.g. timeouts, or could not read it
+        let kind = match error.status() {
+            Some(status) => SellixErrorKind::Http(SellixHttpCode::from(status.as_u16())),
+            None if error.is_decode() || error.is_body() => SellixErrorKind::Decode,
+            None => SellixErrorKind::Transport,
+        };
         SellixError {
-            kind: SellixHttpCode::from_repr(error.status().unwrap().as_u16()).unwrap(),
+            kind,
             message: error.to_string(),
         }
     }
 }
 
-/// All of the days in the week
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-pub enum WeekDays {
-    Mon,
-    Tue,
-    Wed,
-    Thu,
-    Fri,
-    Sat,
-    Sun
+api_enum! {
+    /// All of the days in the week
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum WeekDays {
+        Mon = "Mon",
+        Tue = "Tue",
+        Wed = "Wed",
+        Thu = "Thu",
+        Fri = "Fri",
+        Sat = "Sat",
+        Sun = "Sun",
+    }
 }
 
-/// All of the months in the year
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-pub enum YearMonths {
-    Jan,
-    Feb,
-    Apr,
-    May,
-    Jun,
-    Jul,
-    Aug,
-    Sep,
-    Oct,
-    Nov,
-    Dec
+api_enum! {
+    /// All of the months in the year
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum YearMonths {
+        Jan = "Jan",
+        Feb = "Feb",
+        Mar = "Mar",
+        Apr = "Apr",
+        May = "May",
+        Jun = "Jun",
+        Jul = "Jul",
+        Aug = "Aug",
+        Sep = "Sep",
+        Oct = "Oct",
+        Nov = "Nov",
+        Dec = "Dec",
+    }
 }
\ No newline at end of file
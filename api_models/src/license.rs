@@ -4,6 +4,7 @@ use serde::{Serialize, Deserialize};
 /// Represents a License Product
 /// <https://developers.sellix.io/#license>.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LicenseProduct {
     /// License key purchased by the customer.
     key: String,
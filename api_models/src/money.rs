@@ -0,0 +1,112 @@
+// Dependencies
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use serde::{Deserialize, Serialize};
+use super::Currencies;
+
+/// An exact decimal number.
+/// Deserializes from both the string and number forms the API sends, and serializes as a string.
+pub use rust_decimal::Decimal;
+/// How a [`Money`] amount is rounded to its currency's minor units.
+pub use rust_decimal::RoundingStrategy;
+
+impl Currencies {
+    /// The number of digits after the decimal point, e.g. 2 for USD (cents) and 0 for JPY.
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Currencies::JPY | Currencies::KRW | Currencies::ISK => 0,
+            _ => 2,
+        }
+    }
+}
+
+/// An exact amount of a currency.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Money {
+    pub amount: Decimal,
+    pub currency: Currencies,
+}
+impl Money {
+    /// Creates an amount of a currency.
+    pub fn new(amount: Decimal, currency: Currencies) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Nothing of a currency.
+    pub fn zero(currency: Currencies) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// Rounds the amount to the currency's minor units, rounding half away from zero.
+    pub fn round(&self) -> Self {
+        self.round_with(RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// Rounds the amount to the currency's minor units.
+    pub fn round_with(&self, strategy: RoundingStrategy) -> Self {
+        Self::new(self.amount.round_dp_with_strategy(self.currency.minor_units(), strategy), self.currency.clone())
+    }
+
+    /// The amount in the currency's minor units (e.g. cents), once rounded.
+    /// `None` if it does not fit within an `i64`.
+    pub fn to_minor_units(&self) -> Option<i64> {
+        let scale = Decimal::from(10_i64.pow(self.currency.minor_units()));
+        i64::try_from(self.round().amount * scale).ok()
+    }
+
+    /// Creates an amount from the currency's minor units (e.g. cents).
+    pub fn from_minor_units(minor_units: i64, currency: Currencies) -> Self {
+        Self::new(Decimal::new(minor_units, currency.minor_units()), currency)
+    }
+
+    /// Adds two amounts, `None` if their currencies differ.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        (self.currency == other.currency).then(|| Self::new(self.amount + other.amount, self.currency.clone()))
+    }
+
+    /// Subtracts two amounts, `None` if their currencies differ.
+    pub fn checked_sub(&self, other: &Money) -> Option<Money> {
+        (self.currency == other.currency).then(|| Self::new(self.amount - other.amount, self.currency.clone()))
+    }
+
+    /// Converts into another currency, where `rate` is how much of `currency` one unit of this currency is worth.
+    pub fn convert(&self, rate: Decimal, currency: Currencies) -> Money {
+        Self::new(self.amount * rate, currency)
+    }
+
+    /// Whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+}
+impl Add for Money {
+    type Output = Money;
+
+    /// Panics if the currencies differ, see [`Money::checked_add`].
+    fn add(self, other: Money) -> Money {
+        self.checked_add(&other).expect("unable to add money of different currencies")
+    }
+}
+impl Sub for Money {
+    type Output = Money;
+
+    /// Panics if the currencies differ, see [`Money::checked_sub`].
+    fn sub(self, other: Money) -> Money {
+        self.checked_sub(&other).expect("unable to subtract money of different currencies")
+    }
+}
+impl Mul<Decimal> for Money {
+    type Output = Money;
+
+    fn mul(self, other: Decimal) -> Money {
+        Self::new(self.amount * other, self.currency)
+    }
+}
+impl fmt::Display for Money {
+    /// Formats to the currency's minor units, e.g. `12.30 USD` or `1230 JPY`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minor_units = self.currency.minor_units() as usize;
+        write!(f, "{:.*} {}", minor_units, self.round().amount, self.currency)
+    }
+}
@@ -1,5 +1,6 @@
 // Dependencies
 use super::Currencies;
+use crate::money::{Decimal, Money};
 use crate::{
     invoice::{InvoiceStatus, InvoiceStatusDetails, InvoiceVoidDetails},
     product::ProductRaw,
@@ -7,51 +8,51 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::{formats::Flexible, TimestampSeconds};
-use std::{collections::HashMap, time::SystemTime};
+use crate::timestamp::Timestamp;
+use std::collections::HashMap;
 
-/// The type of orders.
-/// Used in [`OrderRaw`]
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderType {
-    Product,
-    Subscription,
-    PublicRestApi,
-    MonthlyBill,
-    ShoppingCart,
+api_enum! {
+    /// The type of orders.
+    /// Used in [`OrderRaw`]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum OrderType {
+        Product = "PRODUCT",
+        Subscription = "SUBSCRIPTION",
+        PublicRestApi = "PUBLIC_REST_API",
+        MonthlyBill = "MONTHLY_BILL",
+        ShoppingCart = "SHOPPING_CART",
+    }
 }
 
-/// The history of the order.
-/// Used in [`OrderRaw`]
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderHistoryStatus {
-    Refunded,
-    PaymentCaptureCompleted,
-    PaymentCaptureDenied,
-    Partial,
-    CustomerDisputeResolved,
-    Completed,
-    PaymentCaptureReversed,
-    Voided,
-    PaymentCapturePending,
-    WaitingForConfirmations,
-    PaymentAuthorizationCreated,
-    CustomerDisputeOngoing,
-    CheckoutOrderApproved,
-    PaymentAuthorizationVoided,
-    CheckoutOrderCompleted,
-    CustomerDisputeUpdated,
-    CustomerDisputeCancelled,
+api_enum! {
+    /// The history of the order.
+    /// Used in [`OrderRaw`]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum OrderHistoryStatus {
+        Refunded = "REFUNDED",
+        PaymentCaptureCompleted = "PAYMENT_CAPTURE_COMPLETED",
+        PaymentCaptureDenied = "PAYMENT_CAPTURE_DENIED",
+        Partial = "PARTIAL",
+        CustomerDisputeResolved = "CUSTOMER_DISPUTE_RESOLVED",
+        Completed = "COMPLETED",
+        PaymentCaptureReversed = "PAYMENT_CAPTURE_REVERSED",
+        Voided = "VOIDED",
+        PaymentCapturePending = "PAYMENT_CAPTURE_PENDING",
+        WaitingForConfirmations = "WAITING_FOR_CONFIRMATIONS",
+        PaymentAuthorizationCreated = "PAYMENT_AUTHORIZATION_CREATED",
+        CustomerDisputeOngoing = "CUSTOMER_DISPUTE_ONGOING",
+        CheckoutOrderApproved = "CHECKOUT_ORDER_APPROVED",
+        PaymentAuthorizationVoided = "PAYMENT_AUTHORIZATION_VOIDED",
+        CheckoutOrderCompleted = "CHECKOUT_ORDER_COMPLETED",
+        CustomerDisputeUpdated = "CUSTOMER_DISPUTE_UPDATED",
+        CustomerDisputeCancelled = "CUSTOMER_DISPUTE_CANCELLED",
+    }
 }
 
 /// Represents the raw API response for a order object.
 /// <https://developers.sellix.io/#orders>.
-#[serde_with::serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OrderRaw {
     /// ID of the resource.
     pub id: u64,
@@ -59,14 +60,14 @@ pub struct OrderRaw {
     pub uniqid: String,
     /// Unique ID of the recurring bill.
     pub recurring_billing_id: Option<String>,
-    /// Unique ID of the recurring bill.
-    pub total: f64,
+    /// Total of the invoice in USD.
+    pub total: Decimal,
     /// Total of the invoice in the product’s currency.
-    pub total_display: u64,
+    pub total_display: Decimal,
     /// Exchange rate between currency chosen and USD.
-    pub exchange_rate: f64,
+    pub exchange_rate: Decimal,
     /// Exchange rate between the cryptocurrency chosen (if any) and USD.
-    pub crypto_exchange_rate: f64,
+    pub crypto_exchange_rate: Decimal,
     /// Available currency.
     pub currency: Currencies,
     /// The shop ID to which this invoice belongs.
@@ -114,7 +115,7 @@ pub struct OrderRaw {
     pub subscription_time: Option<String>,
     /// Gateway chosen for this invoice.
     /// If `null`, the customer will be asked for a gateway in the Sellix hosted invoice page.
-    pub gateway: String,
+    pub gateway: Option<String>,
     /// PayPal Alternative Payment Method name, such as iDEAL, used if gateway is `PAYPAL`.
     pub paypal_apm: Option<String>,
     /// Deprecated.
@@ -147,13 +148,13 @@ pub struct OrderRaw {
     /// PerfectMoney payments ID linked to the invoice.
     pub perfectmoney_id: Option<String>,
     /// Cryptocurrency address linked to this invoice.
-    pub crypto_address: String,
+    pub crypto_address: Option<String>,
     /// Cryptocurrency amount converted based on `crypto_exchange_rate`.
-    pub crypto_amount: f64,
+    pub crypto_amount: Decimal,
     /// Cryptocurrency amount received, paid by the customer.
-    pub crypto_received: f64,
+    pub crypto_received: Decimal,
     /// URI used to create the QRCODE.
-    pub crypto_uri: String,
+    pub crypto_uri: Option<String>,
     /// Crypto confirmations needed to process the invoice.
     pub crypto_confirmations_needed: u64,
     /// If true, a scheduled payout for this invoice's cryptocurrency address has been sent.
@@ -205,7 +206,7 @@ pub struct OrderRaw {
     pub void_details: Option<InvoiceVoidDetails>,
     /// If a coupon or `volume_discount` is used,
     /// the discount value presents the total amount of discount over the total cost of the invoice.
-    pub discount: u64,
+    pub discount: Decimal,
     /// What cut does Sellix take out of the total.
     /// To learn more about Sellix fees please refer to <https://sellix.io/fees>.
     pub fee_percentage: u64,
@@ -224,7 +225,7 @@ pub struct OrderRaw {
     /// Can be `NULL`, contains info about the payout transaction.
     pub crypto_payout_transaction: Option<CryptoPayoutTransaction>,
     /// Information related to the dispute (if any) opened on this order.
-    pub paypal_dispute: PaypalDispute,
+    pub paypal_dispute: Option<PaypalDispute>,
     /// Additional details on the invoice status change.
     pub status_history: Vec<StatusHistory>,
     /// Crypto transactions received to fulfill this invoice.
@@ -246,20 +247,36 @@ pub struct OrderRaw {
     /// Deprecated.
     pub year: u64,
     /// Creation date of the order.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    /// Date, available if the blacklist has been edited.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
-    /// User ID, available if the blacklist has been edited.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_by: SystemTime,
+    pub created_at: Timestamp,
+    /// Date, available if the order has been edited.
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
+    /// User ID, available if the order has been edited.
+    pub updated_by: u64,
+}
+impl OrderRaw {
+    /// The total in the order's currency.
+    pub fn total_money(&self) -> Money {
+        Money::new(self.total_display, self.currency.clone())
+    }
+
+    /// The total in USD.
+    pub fn total_usd(&self) -> Money {
+        Money::new(self.total, Currencies::USD)
+    }
+
+    /// The discount applied, in the order's currency.
+    pub fn discount_money(&self) -> Money {
+        Money::new(self.discount, self.currency.clone())
+    }
 }
 
 /// Raw API response from here.
 /// <https://developers.sellix.io/#order-get>.
 /// Used in [`OrderGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OrderOneRaw {
     pub order: OrderRaw,
 }
@@ -272,6 +289,7 @@ pub type OrderGetResponseRaw = RawAPIResponse<OrderOneRaw>;
 /// <https://developers.sellix.io/#order-list>.
 /// Used for [`OrderListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OrderArray {
     pub orders: Vec<OrderRaw>,
 }
@@ -280,13 +298,15 @@ pub struct OrderArray {
 pub type OrderListResponseRaw = RawAPIResponse<OrderArray>;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProductVariant {
-    pub price: f64,
+    pub price: Decimal,
     pub title: String,
     pub description: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct IpInfo {
     pub success: bool,
     pub message: String,
@@ -315,13 +335,13 @@ pub struct IpInfo {
     pub connection_type: String,
     pub abuse_velocity: String,
     pub zip_code: String,
-    pub latitude: u64,
-    pub longitude: u64,
+    pub latitude: f64,
+    pub longitude: f64,
     pub request_id: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct File {
     pub id: u64,
     pub uniqid: String,
@@ -332,37 +352,34 @@ pub struct File {
     pub extension: String,
     pub shop_id: u64,
     pub size: u64,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Webhook {
     pub uniqid: String,
     pub url: String,
     pub event: String,
     pub retries: u64,
     pub response_code: u64,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     pub payload: String,
     pub response: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CryptoPayoutTransaction {
     pub to_address: String,
     pub from_address: String,
-    pub crypto_amount: f64,
+    pub crypto_amount: Decimal,
     pub hash: String,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PaypalDispute {
     pub id: String,
     pub invoice_id: String,
@@ -372,50 +389,48 @@ pub struct PaypalDispute {
     pub outcome: Option<String>,
     pub messages: Vec<Message>,
     pub life_cycle_stage: String,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub seller_response_due_date: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub seller_response_due_date: Timestamp,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Message {
     pub posted_by: String,
     pub content: String,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StatusHistory {
     pub id: u64,
     pub invoice_id: String,
     pub status: String,
     pub details: String,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde_with::serde_as]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CryptoTransaction {
-    pub crypto_amount: f64,
+    pub crypto_amount: Decimal,
     pub hash: String,
     pub confirmations: u64,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct VolumeDiscount {
     #[serde(rename = "type")]
     pub type_field: String,
-    pub value: u64,
+    pub value: Decimal,
     pub quantity: u64,
 }
\ No newline at end of file
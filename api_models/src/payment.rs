@@ -1,23 +1,23 @@
 // Dependencies
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
-use super::{WeekDays, YearMonths};
+use crate::timestamp::Timestamp;
+use super::{Currencies, WeekDays, YearMonths};
+use crate::money::Decimal;
 
-/// Types of discounts.
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display, PartialEq)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum DiscountType {
-    Percentage,
-    Fixed
+api_enum! {
+    /// Types of discounts.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum DiscountType {
+        Percentage = "PERCENTAGE",
+        Fixed = "FIXED",
+    }
 }
 
 /// Represents a product, only by its id and quantity.
 /// Used for [`SellixPaymentPayload`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProductPayment {
     uniqid: String,
     unit_quantity: u64,
@@ -26,119 +26,113 @@ pub struct ProductPayment {
 /// Represents a cart for the list of products when using Sellix Pay.
 /// <https://developers.sellix.io/#sellix_checkout>
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Cart {
     products: Vec<ProductPayment>,
 }
 
 /// States how risky a customer is.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FraudShield {
     ip: String,
     user_agent: String,
     user_language: String,
 }
 
-/// Represents a singular payment gateway.
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display, PartialEq)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum PaymentGateway {
-    Paypal,
-    Ethereum,
-    BinanceCoin,
-    Bitcoin,
-    BitcoinCash,
-    Litecoin,
-    Skrill,
-    Stripe,
-    PerfectMoney,
-    CashApp,
-    LexHoldingsGroup,
-    Concordium,
-    Paydash,
-    Monero,
-    BitcoinLn,
-    Nano,
-    Solana,
-    Ripple,
-    Polygon,
-    #[serde(rename="PLZ:TRC20")]
-    PlzTrc20,
-    #[serde(rename="PLZ:BEP20")]
-    PlzBep20,
-    #[serde(rename="USDC:MATIC")]
-    UsdcMatic,
-    #[serde(rename="USDC:ERC20")]
-    UsdtErc20,
-    #[serde(rename="USDT:MATIC")]
-    UsdtMatic,
-    #[serde(rename="USDT:BEP20")]
-    UsdtBep20,
-    #[serde(rename="USDT:TRC20")]
-    UsdtTrc20,
-    #[serde(rename="USDC:ERC20")]
-    UsdcErc20,
-    #[serde(rename="USDC:BEP20")]
-    UsdcBep20,
-    Tron
+api_enum! {
+    /// Represents a singular payment gateway.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum PaymentGateway {
+        Paypal = "PAYPAL",
+        Ethereum = "ETHEREUM",
+        BinanceCoin = "BINANCE_COIN",
+        Bitcoin = "BITCOIN",
+        BitcoinCash = "BITCOIN_CASH",
+        Litecoin = "LITECOIN",
+        Skrill = "SKRILL",
+        Stripe = "STRIPE",
+        PerfectMoney = "PERFECT_MONEY",
+        CashApp = "CASH_APP",
+        LexHoldingsGroup = "LEX_HOLDINGS_GROUP",
+        Concordium = "CONCORDIUM",
+        Paydash = "PAYDASH",
+        Monero = "MONERO",
+        BitcoinLn = "BITCOIN_LN",
+        Nano = "NANO",
+        Solana = "SOLANA",
+        Ripple = "RIPPLE",
+        Polygon = "POLYGON",
+        PlzTrc20 = "PLZ:TRC20",
+        PlzBep20 = "PLZ:BEP20",
+        UsdcMatic = "USDC:MATIC",
+        UsdtErc20 = "USDT:ERC20",
+        UsdtMatic = "USDT:MATIC",
+        UsdtBep20 = "USDT:BEP20",
+        UsdtTrc20 = "USDT:TRC20",
+        UsdcErc20 = "USDC:ERC20",
+        UsdcBep20 = "USDC:BEP20",
+        Tron = "TRON",
+    }
 }
 
 
-/// All of the payment gateways.
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display, PartialEq)]
-#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
-#[serde(rename_all="SCREAMING_SNAKE_CASE")]
-pub enum PaymentGateways {
-    Paypal,
-    Ethereum,
-    BinanceCoin,
-    Bitcoin,
-    BitcoinCash,
-    Litecoin,
-    Skrill,
-    Stripe,
-    PerfectMoney,
-    CashApp,
-    LexHoldingsGroup,
-    Paydash,
-    Monero,
-    Concordium,
-    BitcoinLn,
-    Nano,
-    Solana,
-    Ripple,
-    Usdt,
-    Usdc,
-    Plz,
-    Polygon,
-    Tron,
-    Binance
+api_enum! {
+    /// All of the payment gateways.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum PaymentGateways {
+        Paypal = "PAYPAL",
+        Ethereum = "ETHEREUM",
+        BinanceCoin = "BINANCE_COIN",
+        Bitcoin = "BITCOIN",
+        BitcoinCash = "BITCOIN_CASH",
+        Litecoin = "LITECOIN",
+        Skrill = "SKRILL",
+        Stripe = "STRIPE",
+        PerfectMoney = "PERFECT_MONEY",
+        CashApp = "CASH_APP",
+        LexHoldingsGroup = "LEX_HOLDINGS_GROUP",
+        Paydash = "PAYDASH",
+        Monero = "MONERO",
+        Concordium = "CONCORDIUM",
+        BitcoinLn = "BITCOIN_LN",
+        Nano = "NANO",
+        Solana = "SOLANA",
+        Ripple = "RIPPLE",
+        Usdt = "USDT",
+        Usdc = "USDC",
+        Plz = "PLZ",
+        Polygon = "POLYGON",
+        Tron = "TRON",
+        Binance = "BINANCE",
+    }
 }
 
-/// All of possible APMs for PayPal.
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display, PartialEq)]
-#[strum(serialize_all="lowercase")]
-#[serde(rename_all="lowercase")]
-pub enum PayPalAPM {
-    Bancontact,
-    Eps,
-    Trustly,
-    Mercado,
-    Paylater,
-    Sepa,
-    Venmo,
-    Blik,
-    Giropay,
-    Ideal,
-    Mybank,
-    Sofort,
-    Przelewy24,
-    Credit
+api_enum! {
+    /// All of possible APMs for PayPal.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum PayPalAPM {
+        Bancontact = "bancontact",
+        Eps = "eps",
+        Trustly = "trustly",
+        Mercado = "mercado",
+        Paylater = "paylater",
+        Sepa = "sepa",
+        Venmo = "venmo",
+        Blik = "blik",
+        Giropay = "giropay",
+        Ideal = "ideal",
+        Mybank = "mybank",
+        Sofort = "sofort",
+        Przelewy24 = "przelewy24",
+        Credit = "credit",
+    }
 }
 
 /// Represents a Sellix Pay payload.
 /// <https://developers.sellix.io/#sellix_checkout>
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SellixPaymentPayload {
     /// Required if `product_id` and `cart` are null.
     /// Defines the title of the purchase, can be the digital good's name or a brief summary of what the customer is paying for.
@@ -173,10 +167,10 @@ pub struct SellixPaymentPayload {
     lex_payment_method: Option<String>,
     /// Required if `product_id` and `cart` are `null`.
     /// The customer will be asked to pay for this amount.
-    value: f32,
+    value: Decimal,
     /// Required if `product_id` and `cart` are null.
     /// The customer will be asked to pay for this amount.
-    currency: String,
+    currency: Currencies,
     /// Can be passed with either `product_id` `null` or not.
     /// The `value` or product's `price` will be multiplied by this amount.
     quantity: u64,
@@ -208,6 +202,7 @@ pub struct SellixPaymentPayload {
 /// Represents the response from a successful Sellix Pay payment creation.
 /// <https://developers.sellix.io/#sellix_checkout>.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SellixPaymentResponse {
     url: String,
     uniqid: String
@@ -216,30 +211,31 @@ pub struct SellixPaymentResponse {
 /// Represents a product, only by its title, price and currency.
 /// Used for [`SellixPaymentResponseWL`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProductPaymentResponse {
     title: String,
-    price_display: f32,
-    currency: String
+    price_display: Decimal,
+    currency: Currencies
 }
 
 /// Represents the response from a successful Sellix Pay payment creation, as white label.
 /// <https://developers.sellix.io/#sellix_checkout>.
-#[serde_with::serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SellixPaymentResponseWL {
     id: u64,
     uniqid: String,
-    total: f32,
-    total_display: f32,
-    currency: String,
-    exchange_rate: f32,
-    crypto_exchange_rate: f32,
+    total: Decimal,
+    total_display: Decimal,
+    currency: Currencies,
+    exchange_rate: Decimal,
+    crypto_exchange_rate: Decimal,
     shop_id: u64,
     name: String,
     customer_email: String,
     product_id: String,
     product_type: String,
-    product_price: f32,
+    product_price: Decimal,
     file_attachment_uniqid: Option<String>,
     gateway: String,
     paypal_email: Option<String>,
@@ -250,8 +246,8 @@ pub struct SellixPaymentResponseWL {
     skrill_link: Option<String>,
     perfectmoney_id: Option<String>,
     crypto_address: String,
-    crypto_amount: f32,
-    crypto_received: f32,
+    crypto_amount: Decimal,
+    crypto_received: Decimal,
     crypto_uri: String,
     crypto_confirmations_needed: u64,
     country: String,
@@ -267,17 +263,17 @@ pub struct SellixPaymentResponseWL {
     developer_webhook: String,
     developer_return_url: String,
     status: String,
-    discount: f32,
-    fee_fixed: f32,
-    fee_percentage: f32,
-    day_value: f32,
+    discount: Decimal,
+    fee_fixed: Decimal,
+    fee_percentage: u64,
+    day_value: u64,
     day: WeekDays,
     month: YearMonths,
     year: u64,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    updated_at: SystemTime,
+    created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    updated_at: Option<Timestamp>,
     updated_by: u64,
     serials: Vec<String>,
     file: Option<String>,
@@ -286,6 +282,6 @@ pub struct SellixPaymentResponseWL {
     crypto_payout_transaction: Option<String>,
     crypto_transactions: Vec<String>,
     product: ProductPaymentResponse,
-    total_conversions: HashMap<String, f32>,
+    total_conversions: HashMap<String, Decimal>,
     theme: String
 }
\ No newline at end of file
@@ -0,0 +1,261 @@
+// Dependencies
+use std::fmt;
+use super::coupon::{CouponRaw, CouponUseType};
+use super::payment::DiscountType;
+use super::product::ProductRaw;
+use crate::money::{Decimal, Money};
+use crate::timestamp::Timestamp;
+
+/// Why a coupon cannot be applied, in which case the quote is priced without it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CouponRejection {
+    /// The coupon expired at the given time.
+    Expired(Timestamp),
+    /// The coupon has been used as many times as it may be.
+    UsedUp {
+        max_uses: i64,
+    },
+    /// The coupon is limited to other products.
+    NotForProduct,
+    /// The coupon cannot be combined with the volume discount that applies.
+    DisabledWithVolumeDiscounts,
+    /// The coupon's percentage discount is over 100%.
+    InvalidDiscount(u64),
+    /// The coupon's discount has a type that is not known, so it cannot be applied.
+    UnknownDiscountType(String),
+}
+impl fmt::Display for CouponRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expired(expire_at) => write!(f, "the coupon expired at {}", expire_at),
+            Self::UsedUp { max_uses } => write!(f, "the coupon has already been used {} times", max_uses),
+            Self::NotForProduct => write!(f, "the coupon is not valid for this product"),
+            Self::DisabledWithVolumeDiscounts => write!(f, "the coupon cannot be used with a volume discount"),
+            Self::InvalidDiscount(discount) => write!(f, "the coupon's discount of {}% is over 100%", discount),
+            Self::UnknownDiscountType(kind) => write!(f, "unknown coupon discount type {}", kind),
+        }
+    }
+}
+
+/// Returned when a product cannot be priced at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PricingError {
+    /// The quantity is outside of the product's limits, where a `max` of `None` is unlimited.
+    InvalidQuantity {
+        quantity: u64,
+        min: u64,
+        max: Option<u64>,
+    },
+    /// A volume discount has a type that is not known, so it cannot be applied.
+    UnknownDiscountType(String),
+}
+impl fmt::Display for PricingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidQuantity { quantity, min, max: Some(max) } => write!(f, "a quantity of {} is not within {}..={}", quantity, min, max),
+            Self::InvalidQuantity { quantity, min, max: None } => write!(f, "a quantity of {} is below the minimum of {}", quantity, min),
+            Self::UnknownDiscountType(kind) => write!(f, "unknown volume discount type {}", kind),
+        }
+    }
+}
+impl std::error::Error for PricingError {}
+
+/// A discount which was applied to a [`Quote`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppliedDiscount {
+    /// The volume discount for buying at least `quantity`.
+    Volume {
+        type_field: DiscountType,
+        value: Decimal,
+        quantity: u64,
+        amount: Money,
+    },
+    /// A coupon, taking either a percentage or a fixed amount off what is left after the volume discount.
+    Coupon {
+        code: String,
+        type_field: DiscountType,
+        value: Decimal,
+        amount: Money,
+    },
+}
+impl AppliedDiscount {
+    /// How much was taken off the total.
+    pub fn amount(&self) -> &Money {
+        match self {
+            Self::Volume { amount, .. } | Self::Coupon { amount, .. } => amount
+        }
+    }
+}
+
+/// The expected price of a purchase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quote {
+    /// The price of a single unit.
+    pub unit_price: Money,
+    /// The number of units.
+    pub quantity: u64,
+    /// The price of every unit, before any discounts.
+    pub subtotal: Money,
+    /// Every discount applied, in order.
+    pub discounts: Vec<AppliedDiscount>,
+    /// Why the coupon was not applied, if one was given.
+    pub coupon_rejection: Option<CouponRejection>,
+    /// The price to pay, rounded to the currency's minor units.
+    pub total: Money,
+}
+impl Quote {
+    /// How much was taken off the subtotal in total.
+    pub fn discount_total(&self) -> Money {
+        self.subtotal.clone() - self.total.clone()
+    }
+}
+
+/// Prices products locally, the same way the checkout does.
+///
+/// Only the highest volume discount the quantity reaches is applied, taking either a percentage
+/// or a fixed amount off the subtotal. A coupon then takes its `discount` off what is left, as a percentage
+/// unless its discount type is `FIXED`.
+/// Every discount is rounded to the currency's minor units, and the total never goes below zero.
+///
+/// ```
+/// # use sellix_api_models::pricing::PricingEngine;
+/// # use sellix_api_models::product::ProductRaw;
+/// # use sellix_api_models::coupon::CouponRaw;
+/// fn show_price(product: &ProductRaw, quantity: u64, coupon: Option<&CouponRaw>) {
+///     match PricingEngine::new().quote(product, quantity, coupon) {
+///         Ok(quote) => {
+///             if let Some(rejection) = &quote.coupon_rejection {
+///                 println!("Coupon not applied: {}", rejection);
+///             }
+///             println!("Total: {}", quote.total);
+///         },
+///         Err(error) => println!("Unable to price: {}", error)
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PricingEngine {
+    /// When coupon expiry is checked against.
+    now: Timestamp,
+}
+impl Default for PricingEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PricingEngine {
+    /// Creates an engine which checks coupon expiry against the current time.
+    pub fn new() -> Self {
+        Self::at(Timestamp::now())
+    }
+
+    /// Creates an engine which checks coupon expiry against the given time.
+    pub fn at(now: Timestamp) -> Self {
+        Self { now }
+    }
+
+    /// Prices a quantity of a product, with an optional coupon.
+    pub fn quote(&self, product: &ProductRaw, quantity: u64, coupon: Option<&CouponRaw>) -> Result<Quote, PricingError> {
+        // Make sure the quantity can be bought, where a maximum of zero or less is unlimited
+        let min = product.quantity_min.max(1) as u64;
+        let max = (product.quantity_max > 0).then_some(product.quantity_max as u64);
+        if quantity < min || max.is_some_and(|max| quantity > max) {
+            return Err(PricingError::InvalidQuantity { quantity, min, max });
+        }
+
+        let unit_price = product.price_money();
+        let currency = unit_price.currency.clone();
+        let subtotal = unit_price.clone() * Decimal::from(quantity);
+        let mut total = subtotal.clone();
+        let mut discounts = Vec::new();
+
+        // Apply the highest volume discount reached
+        let volume_discount = product.volume_discounts.iter()
+            .filter(|x| x.quantity <= quantity)
+            .max_by_key(|x| x.quantity);
+        if let Some(volume_discount) = volume_discount {
+            let type_field = DiscountType::from(volume_discount.type_field.clone());
+            let amount = match &type_field {
+                DiscountType::Percentage => total.clone() * (volume_discount.value / Decimal::ONE_HUNDRED),
+                DiscountType::Fixed => Money::new(volume_discount.value, currency.clone()),
+                DiscountType::Unknown(kind) => return Err(PricingError::UnknownDiscountType(kind.clone()))
+            };
+            let amount = Self::clamp(amount.round(), &total);
+            total = total - amount.clone();
+            discounts.push(AppliedDiscount::Volume {
+                type_field,
+                value: volume_discount.value,
+                quantity: volume_discount.quantity,
+                amount,
+            });
+        }
+
+        // Apply the coupon, unless it is rejected
+        let mut coupon_rejection = None;
+        if let Some(coupon) = coupon {
+            match self.check_coupon(product, coupon, volume_discount.is_some()) {
+                Ok(()) => {
+                    let type_field = coupon.discount_type.clone().unwrap_or(DiscountType::Percentage);
+                    let value = Decimal::from(coupon.discount);
+                    let amount = match &type_field {
+                        DiscountType::Fixed => Money::new(value, currency.clone()),
+                        _ => total.clone() * (value / Decimal::ONE_HUNDRED),
+                    };
+                    let amount = Self::clamp(amount.round(), &total);
+                    total = total - amount.clone();
+                    discounts.push(AppliedDiscount::Coupon {
+                        code: coupon.code.clone(),
+                        type_field,
+                        value,
+                        amount,
+                    });
+                },
+                Err(rejection) => coupon_rejection = Some(rejection)
+            }
+        }
+
+        Ok(Quote {
+            unit_price,
+            quantity,
+            subtotal,
+            discounts,
+            coupon_rejection,
+            total: total.round(),
+        })
+    }
+
+    /// Checks whether a coupon can be applied to the product.
+    pub fn check_coupon(&self, product: &ProductRaw, coupon: &CouponRaw, volume_discounted: bool) -> Result<(), CouponRejection> {
+        if let Some(expire_at) = coupon.expire_at {
+            if expire_at <= self.now {
+                return Err(CouponRejection::Expired(expire_at));
+            }
+        }
+        if coupon.max_uses >= 0 && coupon.used >= coupon.max_uses as u64 {
+            return Err(CouponRejection::UsedUp { max_uses: coupon.max_uses });
+        }
+        if coupon.use_type == CouponUseType::Limited {
+            let bound = coupon.products_bound.as_ref()
+                .is_some_and(|x| x.contains(&product.uniqid));
+            if !bound {
+                return Err(CouponRejection::NotForProduct);
+            }
+        }
+        if volume_discounted && coupon.disabled_with_volume_discounts {
+            return Err(CouponRejection::DisabledWithVolumeDiscounts);
+        }
+        match &coupon.discount_type {
+            Some(DiscountType::Unknown(kind)) => return Err(CouponRejection::UnknownDiscountType(kind.clone())),
+            Some(DiscountType::Fixed) => {},
+            Some(DiscountType::Percentage) | None => if coupon.discount > 100 {
+                return Err(CouponRejection::InvalidDiscount(coupon.discount));
+            },
+        }
+        Ok(())
+    }
+
+    /// Makes sure a discount does not take the total below zero.
+    fn clamp(amount: Money, total: &Money) -> Money {
+        if amount.amount > total.amount { total.clone() } else { amount }
+    }
+}
@@ -4,39 +4,39 @@ use super::order::VolumeDiscount;
 use super::payment::PaymentGateway;
 use super::subscription::RecurringBillingIntervals;
 use super::Currencies;
+use crate::money::{Decimal, Money};
 use serde::{Deserialize, Serialize};
-use serde_with::formats::Flexible;
-use serde_with::TimestampSeconds;
-use std::time::SystemTime;
+use crate::timestamp::Timestamp;
 
-/// Types of products.
-/// Used in [`ProductRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ProductType {
-    Serials,
-    File,
-    Service,
-    Dynamic,
-    InfoCard,
-    Subscription,
+api_enum! {
+    /// Types of products.
+    /// Used in [`ProductRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum ProductType {
+        Serials = "SERIALS",
+        File = "FILE",
+        Service = "SERVICE",
+        Dynamic = "DYNAMIC",
+        InfoCard = "INFO_CARD",
+        Subscription = "SUBSCRIPTION",
+    }
 }
 
-/// Types of sub products.
-/// Used in [`ProductRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ProductSubType {
-    Serials,
-    File,
-    Service,
-    Dynamic,
+api_enum! {
+    /// Types of sub products.
+    /// Used in [`ProductRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum ProductSubType {
+        Serials = "SERIALS",
+        File = "FILE",
+        Service = "SERVICE",
+        Dynamic = "DYNAMIC",
+    }
 }
 
 /// Used in [`CategoryRaw`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CustomField {
     #[serde(rename = "type")]
     pub type_field: String,
@@ -48,8 +48,8 @@ pub struct CustomField {
 }
 
 /// Used in [`CategoryRaw`].
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProductRaw {
     pub id: u64,
     pub uniqid: String,
@@ -59,8 +59,10 @@ pub struct ProductRaw {
     pub subtype: Option<String>,
     pub title: String,
     pub currency: Currencies,
-    pub price: f64,
-    pub price_display: f64,
+    /// Price of the product in USD.
+    pub price: Decimal,
+    /// Price of the product in its currency.
+    pub price_display: Decimal,
     pub description: String,
     pub image_attachment: Option<String>,
     pub file_attachment: Option<String>,
@@ -103,9 +105,15 @@ pub struct ProductRaw {
     pub average_score: f64,
     pub sold_count: u64,
     pub lex_payment_methods: Vec<String>,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     pub updated_by: u64,
 }
+impl ProductRaw {
+    /// The price in the product's currency.
+    pub fn price_money(&self) -> Money {
+        Money::new(self.price_display, self.currency.clone())
+    }
+}
@@ -1,40 +1,36 @@
 // Dependencies
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::formats::Flexible;
-use serde_with::TimestampSeconds;
-use std::time::SystemTime;
+use crate::timestamp::Timestamp;
 
-use super::{group::GroupsBound, product::ProductRaw, RawAPIResponse, UniqidDict};
+use super::{changed, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
 
-/// The status of a Query.
-/// Used in [`QueryRaw`]
-#[derive(
-    Clone, Debug, Serialize, Deserialize, PartialEq, strum_macros::EnumString, strum_macros::Display,
-)]
-#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum QueryStatus {
-    Pending,
-    Closed,
-    ShopReply,
-    CustomerReply,
+api_enum! {
+    /// The status of a Query.
+    /// Used in [`QueryRaw`]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum QueryStatus {
+        Pending = "PENDING",
+        Closed = "CLOSED",
+        ShopReply = "SHOP_REPLY",
+        CustomerReply = "CUSTOMER_REPLY",
+    }
 }
 
 /// Represents the raw API response for a query message object.
 /// Used in [`QueryRaw`]
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct QueryMessage {
     pub role: String,
     pub message: String,
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
 }
 
 /// Represents the raw API response for a query object.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct QueryRaw {
     /// ID of the resource.
     pub id: u64,
@@ -53,11 +49,11 @@ pub struct QueryRaw {
     pub month: String,
     pub year: i64,
     /// Creation date of the query.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Creation date of the query.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the query has been edited.
     pub updated_by: u64,
 }
@@ -66,6 +62,7 @@ pub struct QueryRaw {
 /// <https://developers.sellix.io/#query-get>.
 /// Used in [`QueryGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct QueryOneRaw {
     pub query: QueryRaw,
 }
@@ -78,6 +75,7 @@ pub type QueryGetResponseRaw = RawAPIResponse<QueryOneRaw>;
 /// <https://developers.sellix.io/#query-list>.
 /// Used for [`QueryListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct QueryArray {
     pub queries: Vec<QueryRaw>,
 }
@@ -90,14 +88,98 @@ pub type QueryListResponseRaw = RawAPIResponse<QueryArray>;
 /// <https://developers.sellix.io/#query-create>.
 pub type QueryCreateResponseRaw = RawAPIResponse<UniqidDict>;
 
-/// Represents the payload for creating (or updating) a query.
+/// Represents the payload for creating a query.
 /// <https://developers.sellix.io/#query-create>.
-/// <https://developers.sellix.io/#query-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct QueryCreatePayload<'a> {
-    pub title: &'a str,
+/// Use [`QueryCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct QueryCreatePayload {
+    pub title: String,
     pub unlisted: Option<bool>,
-    pub products_bound: Option<Vec<ProductRaw>>,
-    pub groups_array: Option<Vec<GroupsBound>>,
+    /// Array of product uniqids the query will contain.
+    pub products_bound: Option<Vec<String>>,
+    /// Array of group uniqids the query will contain.
+    pub groups_bound: Option<Vec<String>>,
     pub sort_priority: Option<u64>,
 }
+impl QueryCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(title: &str) -> QueryCreatePayloadBuilder {
+        QueryCreatePayloadBuilder {
+            payload: Self {
+                title: title.to_owned(),
+                unlisted: None,
+                products_bound: None,
+                groups_bound: None,
+                sort_priority: None,
+            }
+        }
+    }
+}
+
+/// Builds a [`QueryCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct QueryCreatePayloadBuilder {
+    payload: QueryCreatePayload
+}
+impl QueryCreatePayloadBuilder {
+    /// Sets whether the query is unlisted.
+    pub fn unlisted(mut self, unlisted: bool) -> Self {
+        self.payload.unlisted = Some(unlisted);
+        self
+    }
+
+    /// Sets the products the query will contain.
+    pub fn products_bound(mut self, products_bound: &[&str]) -> Self {
+        self.payload.products_bound = Some(products_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the groups the query will contain.
+    pub fn groups_bound(mut self, groups_bound: &[&str]) -> Self {
+        self.payload.groups_bound = Some(groups_bound.iter().map(|x| x.to_string()).collect());
+        self
+    }
+
+    /// Sets the sort priority.
+    pub fn sort_priority(mut self, sort_priority: u64) -> Self {
+        self.payload.sort_priority = Some(sort_priority);
+        self
+    }
+
+    /// Validates, then builds the payload.
+    pub fn build(self) -> Result<QueryCreatePayload, ValidationError> {
+        validation::non_empty("title", &self.payload.title)?;
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a query.
+/// Only the fields that are set are sent.
+/// <https://developers.sellix.io/#query-update>.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct QueryUpdatePayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>,
+}
+impl<'a> QueryUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &QueryRaw, new: &'a QueryRaw) -> Self {
+        Self {
+            title: changed(&old.title, &new.title).map(String::as_str),
+            customer_email: changed(&old.customer_email, &new.customer_email).map(String::as_str),
+            status: changed(&old.status, &new.status).map(String::as_str),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.customer_email.is_none() && self.status.is_none()
+    }
+}
@@ -0,0 +1,199 @@
+// Dependencies
+use std::fmt;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::Method;
+use super::{RequestAction, RequestType, ResourceKind};
+
+/// Everything except the unreserved characters (RFC 3986) gets escaped.
+const ESCAPE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A typed path to an API endpoint, with its parameters escaped.
+///
+/// Each [`RequestType`] has its own constructor which only accepts the parameters that endpoint uses, e.g. [`Route::blacklist_get`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    request_type: RequestType,
+    path: String,
+    query: Vec<(&'static str, String)>,
+}
+impl Route {
+    /// Starts a route at a static path.
+    fn new(request_type: RequestType, path: &'static str) -> Self {
+        Self {
+            request_type,
+            path: path.to_owned(),
+            query: Vec::new(),
+        }
+    }
+
+    /// Appends an escaped path segment.
+    fn segment(mut self, value: &str) -> Self {
+        self.path.push('/');
+        self.path.extend(utf8_percent_encode(value, ESCAPE));
+        self
+    }
+
+    /// Adds a query parameter, unless it is `None`.
+    fn query<T: fmt::Display>(mut self, key: &'static str, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.query.push((key, utf8_percent_encode(&value.to_string(), ESCAPE).to_string()));
+        }
+        self
+    }
+
+    /// The request this route is for.
+    pub fn request_type(&self) -> RequestType {
+        self.request_type
+    }
+
+    /// The HTTP method to use.
+    pub fn method(&self) -> Method {
+        self.request_type.method()
+    }
+
+    /// The path and querystring, relative to the API base.
+    pub fn path(&self) -> String {
+        self.to_string()
+    }
+}
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path)?;
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            write!(f, "{}{}={}", if i == 0 { '?' } else { '&' }, key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Declares each [`RequestType`]'s resource, action, method and path once, generating [`RequestType::request_details`],
+/// [`RequestType::resource`], [`RequestType::action`] and a typed constructor on [`Route`].
+///
+/// `Resource { Variant(Action) => constructor: METHOD "/static/path" [/ path_param] [? query_param]; }`
+macro_rules! routes {
+    ($( $resource:ident { $( $variant:ident($action:ident) => $constructor:ident: $method:ident $path:literal $(/ $param:ident)? $(? $query:ident)?; )* } )*) => {
+        impl RequestType {
+            /// Every request type, in the order they are declared.
+            pub const ALL: &'static [RequestType] = &[$($( RequestType::$variant, )*)*];
+
+            /// Returns which resource the request is for.
+            pub fn resource(&self) -> ResourceKind {
+                match self {
+                    $($( RequestType::$variant => ResourceKind::$resource, )*)*
+                }
+            }
+
+            /// Returns what the request does to its resource.
+            pub fn action(&self) -> RequestAction {
+                match self {
+                    $($( RequestType::$variant => RequestAction::$action, )*)*
+                }
+            }
+
+            /// Returns a tuple that describes the method and path template corrosponding to the [`RequestType`].
+            /// Path parameters are written as `{name}`, query parameters are not included.
+            pub fn request_details(&self) -> (Method, &'static str) {
+                match self {
+                    $($( RequestType::$variant => (Method::$method, concat!($path $(, "/{", stringify!($param), "}")?)), )*)*
+                }
+            }
+
+            /// Returns the HTTP method corrosponding to the [`RequestType`].
+            pub fn method(&self) -> Method {
+                self.request_details().0
+            }
+
+            /// Returns the name of the query parameter the [`RequestType`] accepts, if any.
+            pub fn query_parameter(&self) -> Option<&'static str> {
+                match self {
+                    $($( RequestType::$variant => None $(.or(Some(stringify!($query))))?, )*)*
+                }
+            }
+        }
+
+        impl Route {
+            $($(
+                #[doc = concat!("The route for [`RequestType::", stringify!($variant), "`].")]
+                pub fn $constructor($($param: &str,)? $($query: Option<u64>,)?) -> Self {
+                    Route::new(RequestType::$variant, $path)
+                        $(.segment($param))?
+                        $(.query(stringify!($query), $query))?
+                }
+            )*)*
+        }
+    };
+}
+
+routes! {
+    Blacklist {
+        BlacklistGet(Get) => blacklist_get: GET "/blacklists" / uniqid;
+        BlacklistList(List) => blacklist_list: GET "/blacklists" ? page;
+        BlacklistCreate(Create) => blacklist_create: POST "/blacklists";
+        BlacklistUpdate(Update) => blacklist_update: PUT "/blacklists" / uniqid;
+        BlacklistDestroy(Destroy) => blacklist_destroy: DELETE "/blacklists" / uniqid;
+    }
+    Whitelist {
+        WhitelistGet(Get) => whitelist_get: GET "/whitelists" / uniqid;
+        WhitelistList(List) => whitelist_list: GET "/whitelists" ? page;
+        WhitelistCreate(Create) => whitelist_create: POST "/whitelists";
+        WhitelistUpdate(Update) => whitelist_update: PUT "/whitelists" / uniqid;
+        WhitelistDestroy(Destroy) => whitelist_destroy: DELETE "/whitelists" / uniqid;
+    }
+    Category {
+        CategoryGet(Get) => category_get: GET "/categories" / uniqid;
+        CategoryList(List) => category_list: GET "/categories" ? page;
+        CategoryCreate(Create) => category_create: POST "/categories";
+        CategoryUpdate(Update) => category_update: PUT "/categories" / uniqid;
+        CategoryDestroy(Destroy) => category_destroy: DELETE "/categories" / uniqid;
+    }
+    Coupon {
+        CouponGet(Get) => coupon_get: GET "/coupons" / uniqid;
+        CouponList(List) => coupon_list: GET "/coupons" ? page;
+        CouponCreate(Create) => coupon_create: POST "/coupons";
+        CouponUpdate(Update) => coupon_update: PUT "/coupons" / uniqid;
+        CouponDestroy(Destroy) => coupon_destroy: DELETE "/coupons" / uniqid;
+    }
+    Feedback {
+        FeedbackGet(Get) => feedback_get: GET "/feedback" / uniqid;
+        FeedbackList(List) => feedback_list: GET "/feedback" ? page;
+        FeedbackReply(Reply) => feedback_reply: POST "/feedback/reply" / uniqid;
+    }
+    Order {
+        OrderGet(Get) => order_get: GET "/orders" / uniqid;
+        OrderList(List) => order_list: GET "/orders" ? page;
+        OrderCreate(Create) => order_create: POST "/orders";
+        OrderUpdate(Update) => order_update: PUT "/orders" / uniqid;
+        OrderDestroy(Destroy) => order_destroy: DELETE "/orders" / uniqid;
+    }
+    Group {
+        GroupGet(Get) => group_get: GET "/groups" / uniqid;
+        GroupList(List) => group_list: GET "/groups" ? page;
+        GroupCreate(Create) => group_create: POST "/groups";
+        GroupUpdate(Update) => group_update: PUT "/groups" / uniqid;
+        GroupDestroy(Destroy) => group_destroy: DELETE "/groups" / uniqid;
+    }
+    Customer {
+        CustomerGet(Get) => customer_get: GET "/customers" / uniqid;
+        CustomerList(List) => customer_list: GET "/customers" ? page;
+        CustomerCreate(Create) => customer_create: POST "/customers";
+        CustomerUpdate(Update) => customer_update: PUT "/customers" / uniqid;
+        CustomerDestroy(Destroy) => customer_destroy: DELETE "/customers" / uniqid;
+    }
+    Query {
+        QueryGet(Get) => query_get: GET "/queries" / uniqid;
+        QueryList(List) => query_list: GET "/queries" ? page;
+        QueryCreate(Create) => query_create: POST "/queries";
+        QueryUpdate(Update) => query_update: PUT "/queries" / uniqid;
+        QueryDestroy(Destroy) => query_destroy: DELETE "/queries" / uniqid;
+    }
+    Subscription {
+        SubscriptionGet(Get) => subscription_get: GET "/subscriptions" / uniqid;
+        SubscriptionList(List) => subscription_list: GET "/subscriptions" ? page;
+        SubscriptionCreate(Create) => subscription_create: POST "/subscriptions";
+        SubscriptionDestroy(Destroy) => subscription_destroy: DELETE "/subscriptions" / uniqid;
+    }
+}
@@ -0,0 +1,241 @@
+//! JSON Schema for every model, and an OpenAPI document for every [`RequestType`].
+//!
+//! Only available with the `schemars` feature.
+//! Schemas describe the JSON as this crate serializes it, e.g. decimals are strings and timestamps are integers.
+
+// Dependencies
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, SubschemaValidation};
+use serde_json::{json, Map, Value};
+use crate::{blacklist, whitelist, category, coupon, feedback, order, group, customer, query, subscription};
+use crate::{RawAPIResponse, RequestType, UniqidDict};
+
+// Constants
+const SERVER: &str = "https://dev.sellix.io/v1";
+
+/// The schema for an `api_enum!` type: any of its known values, or any other string.
+pub(crate) fn api_enum(known: &[&str]) -> Schema {
+    let known = SchemaObject {
+        enum_values: Some(known.iter().map(|x| json!(x)).collect()),
+        ..Default::default()
+    };
+    let other = SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        ..Default::default()
+    };
+
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some("One of the known values, or a value added to the API since.".to_owned()),
+            ..Default::default()
+        })),
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![known.into(), other.into()]),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// The schema for an `api_code_enum!` type: any of its known codes, or any other integer.
+pub(crate) fn api_code_enum(known: &[u16]) -> Schema {
+    let known = SchemaObject {
+        enum_values: Some(known.iter().map(|x| json!(x)).collect()),
+        ..Default::default()
+    };
+    let other = SchemaObject {
+        instance_type: Some(InstanceType::Integer.into()),
+        ..Default::default()
+    };
+
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some("One of the known codes, or a code added to the API since.".to_owned()),
+            ..Default::default()
+        })),
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![known.into(), other.into()]),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Declares the request body (if any) and the response of each [`RequestType`].
+///
+/// `Variant [(Body)] => Response;`
+macro_rules! operations {
+    ($( $variant:ident $(($body:ty))? => $response:ty; )*) => {
+        /// The schemas for the request body and response, added to the generator's definitions.
+        fn schemas(request_type: RequestType, gen: &mut SchemaGenerator) -> (Option<Schema>, Schema) {
+            match request_type {
+                $( RequestType::$variant => (
+                    None $(.or(Some(gen.subschema_for::<$body>())))?,
+                    gen.subschema_for::<$response>()
+                ), )*
+            }
+        }
+    };
+}
+
+operations! {
+    BlacklistGet => blacklist::BlacklistGetResponseRaw;
+    BlacklistList => blacklist::BlacklistListResponseRaw;
+    BlacklistCreate(blacklist::BlacklistCreatePayload) => blacklist::BlacklistCreateResponseRaw;
+    BlacklistUpdate(blacklist::BlacklistUpdatePayload<'static>) => RawAPIResponse<()>;
+    BlacklistDestroy => RawAPIResponse<()>;
+
+    WhitelistGet => whitelist::WhitelistGetResponseRaw;
+    WhitelistList => whitelist::WhitelistListResponseRaw;
+    WhitelistCreate(whitelist::WhitelistCreatePayload) => whitelist::WhitelistCreateResponseRaw;
+    WhitelistUpdate(whitelist::WhitelistUpdatePayload<'static>) => RawAPIResponse<()>;
+    WhitelistDestroy => RawAPIResponse<()>;
+
+    CategoryGet => category::CategoryGetResponseRaw;
+    CategoryList => category::CategoryListResponseRaw;
+    CategoryCreate(category::CategoryCreatePayload) => category::CategoryCreateResponseRaw;
+    CategoryUpdate(category::CategoryUpdatePayload<'static>) => RawAPIResponse<()>;
+    CategoryDestroy => RawAPIResponse<()>;
+
+    CouponGet => coupon::CouponGetResponseRaw;
+    CouponList => coupon::CouponListResponseRaw;
+    CouponCreate(coupon::CouponCreatePayload) => coupon::CouponCreateResponseRaw;
+    CouponUpdate(coupon::CouponUpdatePayload<'static>) => RawAPIResponse<()>;
+    CouponDestroy => RawAPIResponse<()>;
+
+    FeedbackGet => feedback::FeedbackGetResponseRaw;
+    FeedbackList => feedback::FeedbackListResponseRaw;
+    FeedbackReply(feedback::FeedbackReplyPayload<'static>) => RawAPIResponse<()>;
+
+    OrderGet => order::OrderGetResponseRaw;
+    OrderList => order::OrderListResponseRaw;
+    OrderCreate => RawAPIResponse<UniqidDict>;
+    OrderUpdate => RawAPIResponse<()>;
+    OrderDestroy => RawAPIResponse<()>;
+
+    GroupGet => group::GroupGetResponseRaw;
+    GroupList => group::GroupListResponseRaw;
+    GroupCreate(group::GroupCreatePayload) => group::GroupCreateResponseRaw;
+    GroupUpdate(group::GroupUpdatePayload<'static>) => RawAPIResponse<()>;
+    GroupDestroy => RawAPIResponse<()>;
+
+    CustomerGet => customer::CustomerGetResponseRaw;
+    CustomerList => customer::CustomerListResponseRaw;
+    CustomerCreate(customer::CustomerCreatePayload) => customer::CustomerCreateResponseRaw;
+    CustomerUpdate(customer::CustomerUpdatePayload<'static>) => RawAPIResponse<()>;
+    CustomerDestroy => RawAPIResponse<()>;
+
+    QueryGet => query::QueryGetResponseRaw;
+    QueryList => query::QueryListResponseRaw;
+    QueryCreate(query::QueryCreatePayload) => query::QueryCreateResponseRaw;
+    QueryUpdate(query::QueryUpdatePayload<'static>) => RawAPIResponse<()>;
+    QueryDestroy => RawAPIResponse<()>;
+
+    SubscriptionGet => subscription::SubscriptionGetResponseRaw;
+    SubscriptionList => subscription::SubscriptionListResponseRaw;
+    SubscriptionCreate(subscription::SubscriptionCreatePayload) => subscription::SubscriptionCreateResponseRaw;
+    SubscriptionDestroy => RawAPIResponse<()>;
+}
+
+/// Describes a single [`RequestType`] as an OpenAPI operation.
+fn operation(request_type: RequestType, gen: &mut SchemaGenerator) -> Value {
+    let (body, response) = schemas(request_type, gen);
+    let (_, path) = request_type.request_details();
+
+    // The merchant header, then each path parameter, then the query parameter
+    let mut parameters = vec![json!({ "$ref": "#/components/parameters/merchant" })];
+    for param in path.split('/').filter_map(|x| x.strip_prefix('{')?.strip_suffix('}')) {
+        parameters.push(json!({
+            "name": param,
+            "in": "path",
+            "required": true,
+            "schema": { "type": "string" },
+        }));
+    }
+    if let Some(param) = request_type.query_parameter() {
+        parameters.push(json!({
+            "name": param,
+            "in": "query",
+            "required": false,
+            "schema": { "type": "integer", "minimum": 1 },
+        }));
+    }
+
+    // The operation id is the variant, in camel case
+    let name = format!("{:?}", request_type);
+    let mut operation = json!({
+        "operationId": name[..1].to_lowercase() + &name[1..],
+        "summary": format!("{} {}", request_type.action(), request_type.resource()),
+        "tags": [request_type.resource().to_string()],
+        "parameters": parameters,
+        "responses": {
+            "200": {
+                "description": "Successful response",
+                "content": { "application/json": { "schema": response } },
+            },
+        },
+    });
+    if let Some(body) = body {
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { "application/json": { "schema": body } },
+        });
+    }
+    operation
+}
+
+/// Generates an OpenAPI 3.0 document, with a path for every [`RequestType`] and a schema for every model they use.
+///
+/// ```
+/// let document = sellix_api_models::schema::openapi();
+/// assert_eq!(document["paths"]["/blacklists/{uniqid}"]["get"]["operationId"], "blacklistGet");
+/// ```
+pub fn openapi() -> Value {
+    let mut gen = SchemaSettings::openapi3().into_generator();
+
+    // Group each operation by its path
+    let mut paths = Map::new();
+    for request_type in RequestType::ALL {
+        let (method, path) = request_type.request_details();
+        let operation = operation(*request_type, &mut gen);
+        paths.entry(path)
+            .or_insert_with(|| json!({}))
+            [method.as_str().to_lowercase()] = operation;
+    }
+
+    // The settings' visitors (e.g. to move siblings of a `$ref` into an `allOf`) are only applied to root schemas by default
+    let mut schemas = gen.take_definitions();
+    for visitor in gen.visitors_mut() {
+        for schema in schemas.values_mut() {
+            visitor.visit_schema(schema);
+        }
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Sellix",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "servers": [{ "url": SERVER }],
+        "security": [{ "bearer": [] }],
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "parameters": {
+                "merchant": {
+                    "name": "X-Sellix-Merchant",
+                    "in": "header",
+                    "required": false,
+                    "description": "The merchant to send the request on behalf of.",
+                    "schema": { "type": "string" },
+                },
+            },
+            "securitySchemes": {
+                "bearer": { "type": "http", "scheme": "bearer" },
+            },
+        },
+    })
+}
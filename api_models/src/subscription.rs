@@ -3,38 +3,38 @@ use crate::UniqidDict;
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
+use crate::timestamp::Timestamp;
 use super::RawAPIResponse;
 use super::payment::PaymentGateway;
+use super::validation::{self, ValidationError};
 
-/// All of the possible intervals subscriptions can be billed within
-/// <https://developers.sellix.io/#subscriptions-features>.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum RecurringBillingIntervals {
-    Daily,
-    Weekly,
-    Monthly,
-    Yearly
+api_enum! {
+    /// All of the possible intervals subscriptions can be billed within
+    /// <https://developers.sellix.io/#subscriptions-features>.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum RecurringBillingIntervals {
+        Daily = "DAILY",
+        Weekly = "WEEKLY",
+        Monthly = "MONTHLY",
+        Yearly = "YEARLY",
+    }
 }
 
-/// All of the possible statuses a subscription can be in
-/// <https://developers.sellix.io/#subscriptions-configure-webhooks>.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum SubscriptionStatus {
-    Pending,
-    Cancelled,
-    Trialing,
-    Active
+api_enum! {
+    /// All of the possible statuses a subscription can be in
+    /// <https://developers.sellix.io/#subscriptions-configure-webhooks>.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum SubscriptionStatus {
+        Pending = "PENDING",
+        Cancelled = "CANCELLED",
+        Trialing = "TRIALING",
+        Active = "ACTIVE",
+    }
 }
 
 /// Represents the possible customer details from an API object.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CustomerData {
     /// Customer name
     pub name: String,
@@ -62,8 +62,8 @@ pub struct CustomerData {
 
 /// Represents the raw API response for a subscription object
 /// <https://developers.sellix.io/#subscriptions-configure-webhooks>.
-#[serde_with::serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SubscriptionRaw {
     /// ID of the subscription (recurring bill)
     pub id: String,
@@ -74,7 +74,7 @@ pub struct SubscriptionRaw {
     /// Subscription's status, see more info about statuses below
     pub status: SubscriptionStatus,
     /// Subscription's gateway chosen by the customer. If status is TRIALING, this field is null
-    pub gateway: PaymentGateway,
+    pub gateway: Option<PaymentGateway>,
     /// Custom fields passed (required by the product configuration)
     pub custom_fields: HashMap<String, String>,
     /// ID of the customer for which this subscription was created
@@ -88,8 +88,7 @@ pub struct SubscriptionRaw {
     /// ID of the coupon used on this subscription
     pub coupon_id: Option<String>,
     /// When this subscription period will end
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub current_period_end: SystemTime,
+    pub current_period_end: Timestamp,
     /// When this subscription period will end
     pub upcoming_email_1_week_sent: bool,
     /// Email sent notifying the customer of an ending trial
@@ -97,13 +96,15 @@ pub struct SubscriptionRaw {
     /// Whether or not a new invoice has already been created for the new period
     pub renewal_invoice_created: bool,
     /// When was this subscription created
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
-    /// When was this subscription created
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    pub created_at: Timestamp,
+    /// When was this subscription last updated
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// When was this subscription canceled
-    pub canceled_at: Option<String>,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub canceled_at: Option<Timestamp>,
     // Digital Software
     pub product_title: String,
     /// Customer name
@@ -136,6 +137,7 @@ pub struct SubscriptionRaw {
 /// <https://developers.sellix.io/#subscriptions-get>.
 /// Used in [`SubscriptionGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SubscriptionOneRaw {
     pub subscription: SubscriptionRaw
 }
@@ -148,6 +150,7 @@ pub type SubscriptionGetResponseRaw = RawAPIResponse<SubscriptionOneRaw>;
 /// <https://developers.sellix.io/#subscriptions-list>.
 /// Used for [`SubscriptionListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SubscriptionArray {
     pub subscriptions: Vec<SubscriptionRaw>
 }
@@ -157,17 +160,63 @@ pub type SubscriptionListResponseRaw = RawAPIResponse<SubscriptionArray>;
 
 /// Represents the payload for creating a subscription.
 /// <https://developers.sellix.io/#subscriptions-create>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SubscriptionCreatePayload<'a> {
-    /// ID of the subscription product.
-    product_id: &'a str,
+/// Use [`SubscriptionCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SubscriptionCreatePayload {
     /// ID of the subscription product.
-    coupon_code: Option<&'a str>,
+    pub product_id: String,
+    /// Code of the coupon to apply.
+    pub coupon_code: Option<String>,
     /// key-value JSON having as key the custom field name and as value the custom field value inserted by the customer. Custom fields can both be used as inputs from the customers but also as metadata for invoices, letting you pass hidden fields for internal referencing.
-    custom_fields: HashMap<String, String>,
+    pub custom_fields: HashMap<String, String>,
     /// ID of the store customer.
-    customer_id: &'a str,
-    gateway: PaymentGateway
+    pub customer_id: String,
+    pub gateway: PaymentGateway
+}
+impl SubscriptionCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(product_id: &str, customer_id: &str, gateway: PaymentGateway) -> SubscriptionCreatePayloadBuilder {
+        SubscriptionCreatePayloadBuilder {
+            payload: Self {
+                product_id: product_id.to_owned(),
+                coupon_code: None,
+                custom_fields: HashMap::new(),
+                customer_id: customer_id.to_owned(),
+                gateway
+            }
+        }
+    }
+}
+
+/// Builds a [`SubscriptionCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct SubscriptionCreatePayloadBuilder {
+    payload: SubscriptionCreatePayload
+}
+impl SubscriptionCreatePayloadBuilder {
+    /// Sets the coupon code.
+    pub fn coupon_code(mut self, coupon_code: &str) -> Self {
+        self.payload.coupon_code = Some(coupon_code.to_owned());
+        self
+    }
+
+    /// Add a custom field.
+    pub fn add_custom_field(mut self, key: &str, value: &str) -> Self {
+        self.payload.custom_fields.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Validates, then builds the payload.
+    pub fn build(self) -> Result<SubscriptionCreatePayload, ValidationError> {
+        validation::non_empty("product_id", &self.payload.product_id)?;
+        validation::non_empty("customer_id", &self.payload.customer_id)?;
+        if let Some(coupon_code) = &self.payload.coupon_code {
+            validation::non_empty("coupon_code", coupon_code)?;
+        }
+        Ok(self.payload)
+    }
 }
 
 /// Represents the response after creating a subscription.
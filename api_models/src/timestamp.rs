@@ -0,0 +1,253 @@
+// Dependencies
+use std::fmt;
+use std::ops::RangeBounds;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A point in time, as the number of seconds since the unix epoch (UTC).
+///
+/// Deserializes from an integer, a float or a numeric string, and serializes as an integer.
+/// Enable the `chrono` or `time` features to convert into a timezone-aware datetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Timestamp(i64);
+impl Timestamp {
+    /// The unix epoch.
+    pub const EPOCH: Timestamp = Timestamp(0);
+
+    /// The current time.
+    pub fn now() -> Self {
+        Self::from(SystemTime::now())
+    }
+
+    /// Creates a timestamp from the number of seconds since the unix epoch.
+    pub fn from_unix(seconds: i64) -> Self {
+        Self(seconds)
+    }
+
+    /// The number of seconds since the unix epoch.
+    pub fn unix(&self) -> i64 {
+        self.0
+    }
+
+    /// Converts into a [`SystemTime`].
+    pub fn to_system_time(&self) -> SystemTime {
+        let offset = Duration::from_secs(self.0.unsigned_abs());
+        if self.0 >= 0 { UNIX_EPOCH + offset } else { UNIX_EPOCH - offset }
+    }
+
+    /// Whether the timestamp is within the range, e.g. `start..end` or `start..`.
+    pub fn is_within<R: RangeBounds<Timestamp>>(&self, range: R) -> bool {
+        range.contains(self)
+    }
+}
+impl From<SystemTime> for Timestamp {
+    /// Rounds down to the second.
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(offset) => Self(offset.as_secs() as i64),
+            Err(error) => Self(-(error.duration().as_secs_f64().ceil() as i64)),
+        }
+    }
+}
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_system_time()
+    }
+}
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.0)
+    }
+}
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TimestampVisitor)?
+            .ok_or_else(|| de::Error::custom("expected a timestamp, found an empty value"))
+    }
+}
+
+/// Reads a timestamp in any of the forms the API sends.
+/// Empty values (`null` and `""`) are returned as `None`, which a non-nullable [`Timestamp`] rejects.
+struct TimestampVisitor;
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Option<Timestamp>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a unix timestamp, as a number or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Some(Timestamp(value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        i64::try_from(value).map(|x| Some(Timestamp(x))).map_err(|_| E::custom("timestamp is out of range"))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Some(Timestamp(value.floor() as i64)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        if let Ok(seconds) = value.parse::<i64>() {
+            return self.visit_i64(seconds);
+        }
+        value.parse::<f64>()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            .and_then(|x| self.visit_f64(x))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+/// For nullable timestamps, used with `#[serde(default, with = "crate::timestamp::nullable")]`.
+///
+/// The API marks a missing timestamp as either `null`, `0` or `""`; all of which become `None`.
+pub mod nullable {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Option<Timestamp>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Timestamp>, D::Error> {
+        let value = deserializer.deserialize_option(TimestampVisitor)?;
+        Ok(value.filter(|x| *x != Timestamp::EPOCH))
+    }
+}
+
+/// Implemented by each resource that records when it was created.
+pub trait Timestamped {
+    /// When the resource was created.
+    fn created_at(&self) -> Timestamp;
+
+    /// When the resource was last edited, if it has been.
+    fn updated_at(&self) -> Option<Timestamp>;
+
+    /// Whether the resource was created within the range, e.g. `start..end` or `start..`.
+    fn created_within<R: RangeBounds<Timestamp>>(&self, range: R) -> bool {
+        self.created_at().is_within(range)
+    }
+}
+
+/// Implements [`Timestamped`] using the `created_at` and `updated_at` fields.
+macro_rules! timestamped {
+    ($($ty:path),* $(,)?) => {$(
+        impl Timestamped for $ty {
+            fn created_at(&self) -> Timestamp {
+                self.created_at
+            }
+
+            fn updated_at(&self) -> Option<Timestamp> {
+                self.updated_at
+            }
+        }
+    )*};
+}
+
+timestamped!(
+    crate::blacklist::BlacklistRaw,
+    crate::whitelist::WhitelistRaw,
+    crate::category::CategoryRaw,
+    crate::coupon::CouponRaw,
+    crate::feedback::FeedbackRaw,
+    crate::group::GroupRaw,
+    crate::order::OrderRaw,
+    crate::product::ProductRaw,
+    crate::query::QueryRaw,
+    crate::subscription::SubscriptionRaw,
+);
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Timestamp {
+    fn schema_name() -> String {
+        "Timestamp".to_owned()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Integer.into()),
+            format: Some("int64".to_owned()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("Seconds since the unix epoch (UTC).".to_owned()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{DateTime, TimeZone, Utc};
+    use super::Timestamp;
+
+    impl Timestamp {
+        /// Converts into a UTC [`chrono::DateTime`], saturating at the range chrono supports.
+        pub fn to_chrono(&self) -> DateTime<Utc> {
+            match Utc.timestamp_opt(self.0, 0).single() {
+                Some(datetime) => datetime,
+                None if self.0 < 0 => DateTime::<Utc>::MIN_UTC,
+                None => DateTime::<Utc>::MAX_UTC,
+            }
+        }
+    }
+    impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+        fn from(datetime: DateTime<Tz>) -> Self {
+            Timestamp(datetime.timestamp())
+        }
+    }
+    impl From<Timestamp> for DateTime<Utc> {
+        fn from(timestamp: Timestamp) -> Self {
+            timestamp.to_chrono()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use time::{OffsetDateTime, PrimitiveDateTime};
+    use super::Timestamp;
+
+    impl Timestamp {
+        /// Converts into a UTC [`time::OffsetDateTime`], saturating at the range time supports.
+        pub fn to_offset_datetime(&self) -> OffsetDateTime {
+            match OffsetDateTime::from_unix_timestamp(self.0) {
+                Ok(datetime) => datetime,
+                Err(_) if self.0 < 0 => PrimitiveDateTime::MIN.assume_utc(),
+                Err(_) => PrimitiveDateTime::MAX.assume_utc(),
+            }
+        }
+    }
+    impl From<OffsetDateTime> for Timestamp {
+        fn from(datetime: OffsetDateTime) -> Self {
+            Timestamp(datetime.unix_timestamp())
+        }
+    }
+    impl From<Timestamp> for OffsetDateTime {
+        fn from(timestamp: Timestamp) -> Self {
+            timestamp.to_offset_datetime()
+        }
+    }
+}
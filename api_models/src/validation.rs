@@ -0,0 +1,97 @@
+// Dependencies
+use std::fmt;
+use std::net::IpAddr;
+use super::{SellixError, SellixHttpCode};
+
+/// Returned when a payload fails validation, before it is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The name of the invalid field.
+    pub field: &'static str,
+    /// Why the field is invalid.
+    pub reason: String,
+}
+impl ValidationError {
+    /// Creates an error for a field.
+    pub fn new(field: &'static str, reason: &str) -> Self {
+        Self {
+            field,
+            reason: reason.to_owned(),
+        }
+    }
+}
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} - {}", self.field, self.reason)
+    }
+}
+impl std::error::Error for ValidationError {}
+impl From<ValidationError> for SellixError {
+    fn from(error: ValidationError) -> Self {
+        SellixError {
+            kind: SellixHttpCode::BadRequest.into(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Makes sure the value is not empty, or only whitespace.
+pub fn non_empty(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::new(field, "must not be empty"));
+    }
+    Ok(())
+}
+
+/// Makes sure the value looks like an email address, e.g. `name@example.com`.
+pub fn email(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::new(field, "must be an email address");
+    let (local, domain) = value.split_once('@').ok_or_else(invalid)?;
+
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !value.chars().any(char::is_whitespace);
+    if !valid {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Makes sure the value is an ISO 3166-1 alpha-2 country code, e.g. `GB`.
+pub fn country_code(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    if value.len() != 2 || !value.bytes().all(|x| x.is_ascii_uppercase()) {
+        return Err(ValidationError::new(field, "must be a two letter, uppercase country code"));
+    }
+    Ok(())
+}
+
+/// Makes sure the value is an IP address, optionally in CIDR notation, e.g. `10.0.0.0/8`.
+pub fn ip(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::new(field, "must be an IP address or CIDR range");
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+
+    let address: IpAddr = address.parse().map_err(|_| invalid())?;
+    if let Some(prefix) = prefix {
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        match prefix.parse::<u8>() {
+            Ok(prefix) if prefix <= max => {},
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(())
+}
+
+/// Makes sure the value is an autonomous system number, e.g. `AS15169` or `15169`.
+pub fn asn(field: &'static str, value: &str) -> Result<(), ValidationError> {
+    let digits = value.strip_prefix("AS").unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return Err(ValidationError::new(field, "must be an autonomous system number"));
+    }
+    Ok(())
+}
@@ -4,7 +4,8 @@ use crate::events::Event;
 
 /// The base request from a Sellix webhook.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RawWebsocketRequest<T> {
-    event: Event,
-    data: T
+    pub event: Event,
+    pub data: T
 }
\ No newline at end of file
@@ -1,28 +1,27 @@
 // Dependencies
 use serde::{Serialize, Deserialize};
-use super::{RawAPIResponse, UniqidDict};
-use serde_with::TimestampSeconds;
-use serde_with::formats::Flexible;
-use std::time::SystemTime;
+use super::{changed, RawAPIResponse, UniqidDict};
+use super::validation::{self, ValidationError};
+use crate::timestamp::Timestamp;
 
-/// The types of whitelist.
-/// Used by [`WhitelistRaw`].
-#[derive(Debug, Serialize, Deserialize, strum_macros::EnumString, strum_macros::Display)]
-#[strum(serialize_all="UPPERCASE")]
-#[serde(rename_all="UPPERCASE")]
-pub enum WhitelistTypes {
-    Email,
-    IP,
-    Country,
-    ISP,
-    ASN,
-    Host
+api_enum! {
+    /// The types of whitelist.
+    /// Used by [`WhitelistRaw`].
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum WhitelistTypes {
+        Email = "EMAIL",
+        IP = "IP",
+        Country = "COUNTRY",
+        ISP = "ISP",
+        ASN = "ASN",
+        Host = "HOST",
+    }
 }
 
 /// Represents the raw API response for a whitelist object.
 /// <https://developers.sellix.io/#whitelists>.
-#[serde_with::serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WhitelistRaw {
     /// ID of the resource
     pub id: u64,
@@ -37,11 +36,11 @@ pub struct WhitelistRaw {
     /// Additional note provided on whitelist creation.
     pub note: String,
     /// Creation date of the whitelist.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub created_at: SystemTime,
+    pub created_at: Timestamp,
     /// Date, available if the whitelist has been edited.
-    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
-    pub updated_at: SystemTime,
+    #[serde(default, with = "crate::timestamp::nullable")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Timestamp>"))]
+    pub updated_at: Option<Timestamp>,
     /// User ID, available if the whitelist has been edited.
     pub updated_by: u64,
 }
@@ -50,6 +49,7 @@ pub struct WhitelistRaw {
 /// <https://developers.sellix.io/#whitelist-get>.
 /// Used in [`WhitelistGetResponseRaw`]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WhitelistOneRaw {
     pub whitelist: WhitelistRaw
 }
@@ -62,23 +62,97 @@ pub type WhitelistGetResponseRaw = RawAPIResponse<WhitelistOneRaw>;
 /// <https://developers.sellix.io/#whitelist-list>.
 /// Used for [`WhitelistListResponseRaw`].
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WhitelistArray {
     pub whitelists: Vec<WhitelistRaw>
 }
 pub type WhitelistListResponseRaw = RawAPIResponse<WhitelistArray>;
 
-/// Represents the payload for creating (or updating) a whitelist.
+/// Represents the payload for creating a whitelist.
 /// <https://developers.sellix.io/#whitelist-create>.
-/// <https://developers.sellix.io/#whitelist-update>.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct WhitelistCreatePayload<'a> {
+/// Use [`WhitelistCreatePayload::builder`] to create one, which validates it, as it cannot be constructed directly.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct WhitelistCreatePayload {
     /// The type of data of this whitelist.
     pub r#type: WhitelistTypes,
     /// Blocked data. Either country code, email or IP address
-    pub data: &'a str,
+    pub data: String,
+    /// Internal note for the reasoning of the whitelist
+    pub note: Option<String>
+}
+impl WhitelistCreatePayload {
+    /// Initialises a builder.
+    pub fn builder(r#type: WhitelistTypes, data: &str) -> WhitelistCreatePayloadBuilder {
+        WhitelistCreatePayloadBuilder {
+            payload: Self {
+                r#type,
+                data: data.to_owned(),
+                note: None
+            }
+        }
+    }
+}
+
+/// Builds a [`WhitelistCreatePayload`], validating it.
+#[derive(Debug, Clone)]
+pub struct WhitelistCreatePayloadBuilder {
+    payload: WhitelistCreatePayload
+}
+impl WhitelistCreatePayloadBuilder {
+    /// Sets the note.
+    pub fn note(mut self, note: &str) -> Self {
+        self.payload.note = Some(note.to_owned());
+        self
+    }
+
+    /// Validates the data against its type, then builds the payload.
+    pub fn build(self) -> Result<WhitelistCreatePayload, ValidationError> {
+        let data = &self.payload.data;
+        validation::non_empty("data", data)?;
+        match self.payload.r#type {
+            WhitelistTypes::Email => validation::email("data", data)?,
+            WhitelistTypes::IP => validation::ip("data", data)?,
+            WhitelistTypes::Country => validation::country_code("data", data)?,
+            WhitelistTypes::ASN => validation::asn("data", data)?,
+            WhitelistTypes::ISP | WhitelistTypes::Host | WhitelistTypes::Unknown(_) => {},
+        }
+        Ok(self.payload)
+    }
+}
+
+/// Represents the payload for updating a whitelist.
+/// Only the fields that are set are sent.
+/// <https://developers.sellix.io/#whitelist-update>.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WhitelistUpdatePayload<'a> {
+    /// The type of data of this whitelist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<WhitelistTypes>,
+    /// Blocked data. Either country code, email or IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<&'a str>,
     /// Internal note for the reasoning of the whitelist
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<&'a str>
 }
+impl<'a> WhitelistUpdatePayload<'a> {
+    /// Creates the minimal update that turns `old` into `new`.
+    pub fn diff(old: &WhitelistRaw, new: &'a WhitelistRaw) -> Self {
+        Self {
+            r#type: changed(&old.r#type, &new.r#type).cloned(),
+            data: changed(&old.data, &new.data).map(String::as_str),
+            note: changed(&old.note, &new.note).map(String::as_str),
+        }
+    }
+
+    /// Whether there is nothing to update.
+    pub fn is_empty(&self) -> bool {
+        self.r#type.is_none() && self.data.is_none() && self.note.is_none()
+    }
+}
 
 /// Represents the response after creating a whitelist.
 /// <https://developers.sellix.io/#whitelist-create>.
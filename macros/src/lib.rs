@@ -1,8 +1,11 @@
 // Dependencies
 use proc_macro::TokenStream;
-use quote::{quote, format_ident};
-use syn::{parse_macro_input, DeriveInput};
+use quote::{quote, quote_spanned, format_ident};
+use syn::{parse_macro_input, DeriveInput, Ident, LitStr, Token, Type};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
 use darling::FromDeriveInput;
+use sellix_api_models::RequestType;
 
 /// Ensures an API model has an api_key and merchant field.
 /// Used for do_request.
@@ -31,15 +34,20 @@ pub fn with_api_key_derive(input: TokenStream) -> TokenStream {
 }
 
 /// Implements `DoRequest` for a struct.
+/// The struct must have a `transport` field.
 /// 
-/// `impl DoRequest for #name {}`
+/// `impl DoRequest for #name { ... }`
 #[proc_macro_derive(WithDoRequest)]
 pub fn with_do_request_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let name = &ast.ident;
 
     let gen = quote! {
-        impl DoRequest for #name {}
+        impl DoRequest for #name {
+            fn transport(&self) -> &Transport {
+                &self.transport
+            }
+        }
     };
 
     gen.into()
@@ -60,8 +68,200 @@ struct DefaultAPIOpts {
     _non_exhaustive: (),
 }
 
-/// Adds default API methods.
-#[proc_macro_derive(DefaultAPI, attributes(api_methods))]
+/// A custom endpoint, declared with `#[endpoint(...)]` alongside [`DefaultAPI`].
+struct Endpoint {
+    /// The name of the generated method.
+    name: LitStr,
+    /// The HTTP method, e.g. `POST`.
+    method: Ident,
+    /// The path template, e.g. `"/feedback/reply/{uniqid}"`.
+    path: LitStr,
+    /// The request body, if any.
+    body: Option<Type>,
+    /// The type of the response's data.
+    returns: Type,
+    /// The doc comment of the generated method.
+    doc: Option<LitStr>,
+}
+impl Parse for Endpoint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (mut name, mut method, mut path, mut body, mut returns, mut doc) = (None, None, None, None, None, None);
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "name" => name = Some(input.parse()?),
+                "method" => method = Some(input.parse()?),
+                "path" => path = Some(input.parse()?),
+                "body" => body = Some(input.parse()?),
+                "returns" => returns = Some(input.parse()?),
+                "doc" => doc = Some(input.parse()?),
+                _ => return Err(syn::Error::new(key.span(), "unknown option, expected one of `name`, `method`, `path`, `body`, `returns` or `doc`")),
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        // Make sure the required options are set
+        let missing = |option: &str| syn::Error::new(input.span(), format!("missing the `{}` option", option));
+        Ok(Self {
+            name: name.ok_or_else(|| missing("name"))?,
+            method: method.ok_or_else(|| missing("method"))?,
+            path: path.ok_or_else(|| missing("path"))?,
+            body,
+            returns: returns.ok_or_else(|| missing("returns"))?,
+            doc,
+        })
+    }
+}
+
+/// Resolves a model given to `#[endpoint]`.
+/// A bare name (e.g. `FeedbackReplyPayload`) is looked up within the resource's module, any other type is used as is.
+fn endpoint_model(module: &Ident, ty: &Type) -> proc_macro2::TokenStream {
+    match ty {
+        Type::Path(path) if path.qself.is_none() && path.path.segments.len() == 1 => {
+            let segment = &path.path.segments[0];
+            let (ident, arguments) = (&segment.ident, &segment.arguments);
+            quote_spanned! {ty.span()=> sellix_api_models::#module::#ident #arguments }
+        },
+        _ => quote! { #ty },
+    }
+}
+
+/// The code generated for an `#[endpoint(...)]`.
+struct EndpointTokens {
+    /// The name of the method.
+    name: Ident,
+    /// The method on the sub-API.
+    method: proc_macro2::TokenStream,
+    /// The `MockMethod` field of the mock.
+    mock_field: proc_macro2::TokenStream,
+    /// The method on the mock, which calls its field.
+    mock_method: proc_macro2::TokenStream,
+}
+
+/// Generates the method for an `#[endpoint(...)]`, along with its mock.
+/// The route must be declared within `sellix_api_models`, with the same method and path.
+fn endpoint(module: &Ident, endpoint: &Endpoint) -> syn::Result<EndpointTokens> {
+    // Find which request type this is for
+    let path = endpoint.path.value();
+    let method = endpoint.method.to_string();
+    let request_type = RequestType::ALL.iter()
+        .find(|x| {
+            let (x_method, x_path) = x.request_details();
+            x_method.as_str() == method && x_path == path
+        })
+        .ok_or_else(|| syn::Error::new(endpoint.path.span(), format!("no route is declared for {} {}", method, path)))?;
+
+    // The route constructor is the request type in snake case, e.g. `FeedbackReply` => `feedback_reply`
+    let mut constructor = String::new();
+    for (i, c) in format!("{:?}", request_type).chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            constructor.push('_');
+        }
+        constructor.push(c.to_ascii_lowercase());
+    }
+    let constructor = format_ident!("{}", constructor);
+
+    // Each path parameter becomes an argument, followed by the query parameter and then the body
+    let params: Vec<Ident> = path.split('/')
+        .filter_map(|x| x.strip_prefix('{')?.strip_suffix('}'))
+        .map(|x| format_ident!("{}", x))
+        .collect();
+    let query: Vec<Ident> = request_type.query_parameter().iter().map(|x| format_ident!("{}", x)).collect();
+    let (body_arg, body_type, body_value) = match &endpoint.body {
+        Some(body) => {
+            let body = endpoint_model(module, body);
+            (quote! { payload: impl Into<#body>, }, body, quote! { Some(payload.into()) })
+        },
+        None => (quote! {}, quote! { Value }, quote! { None }),
+    };
+
+    // A unit response returns whether the request succeeded, like `edit` and `delete`
+    let unit = matches!(&endpoint.returns, Type::Tuple(x) if x.elems.is_empty());
+    let (returns, response, output) = if unit {
+        (quote! { bool }, quote! { () }, quote! { x.status == sellix_api_models::SellixHttpCode::Ok })
+    } else {
+        let returns = endpoint_model(module, &endpoint.returns);
+        (returns.clone(), returns, quote! { x.into_data()? })
+    };
+
+    let name = format_ident!("{}", endpoint.name.value(), span = endpoint.name.span());
+    let doc = match &endpoint.doc {
+        Some(doc) => doc.value(),
+        None => format!("Sends a `{} {}` request.", method, path),
+    };
+    let method = quote! {
+        #[doc = #doc]
+        pub async fn #name(&self, #(#params: &str,)* #(#query: Option<u64>,)* #body_arg) -> Result<#returns, sellix_api_models::SellixError> {
+            // Used to build the url
+            let route = sellix_api_models::Route::#constructor(#(#params,)* #(#query)*);
+
+            // Send it
+            self.do_request::<sellix_api_models::RawAPIResponse<#response>, #body_type>(route, #body_value)
+                .await
+                .and_then(|x| Ok(#output))
+        }
+    };
+
+    // The mock is called with each argument, owned, and the payload as JSON, like `edit`
+    let mut arg_types = Vec::new();
+    let mut arg_values = Vec::new();
+    let mut arg_docs = Vec::new();
+    for param in &params {
+        arg_types.push(quote! { String });
+        arg_values.push(quote! { #param.to_owned() });
+        arg_docs.push(format!("the {}", param));
+    }
+    for query in &query {
+        arg_types.push(quote! { Option<u64> });
+        arg_values.push(quote! { #query });
+        arg_docs.push(format!("the {}", query));
+    }
+    if endpoint.body.is_some() {
+        arg_types.push(quote! { ::sellix_rs::mock::Value });
+        arg_values.push(quote! { ::sellix_rs::mock::to_value::<#body_type>(payload.into()).unwrap() });
+        arg_docs.push("the payload as JSON".to_owned());
+    }
+    let (args_type, args_value) = match arg_types.len() {
+        1 => (arg_types.remove(0), arg_values.remove(0)),
+        _ => (quote! { (#(#arg_types),*) }, quote! { (#(#arg_values),*) }),
+    };
+    let mock_doc = match arg_docs.split_last() {
+        None => "Called without any arguments.".to_owned(),
+        Some((last, [])) => format!("Called with {}.", last),
+        Some((last, rest)) => format!("Called with {}, and {}.", rest.join(", "), last),
+    };
+    let mock_field = quote! {
+        #[doc = #mock_doc]
+        pub #name: ::sellix_rs::mock::MockMethod<#args_type, #returns>
+    };
+    let mock_method = quote! {
+        #[doc = #doc]
+        pub async fn #name(&self, #(#params: &str,)* #(#query: Option<u64>,)* #body_arg) -> Result<#returns, sellix_api_models::SellixError> {
+            self.#name.call(#args_value)
+        }
+    };
+
+    Ok(EndpointTokens {
+        name,
+        method,
+        mock_field,
+        mock_method,
+    })
+}
+
+/// Adds default API methods, and implements `Resource` along with a capability trait (e.g. `Gettable`) for each of them.
+///
+/// Non-CRUD actions are declared with `#[endpoint(...)]`, which generates a typed method for a declared route:
+///
+/// `#[endpoint(name = "reply", method = POST, path = "/feedback/reply/{uniqid}", body = FeedbackReplyPayload<'_>, returns = ())]`
+///
+/// Bare model names are looked up within the resource's module in `sellix_api_models`.
+/// The body is taken as anything which converts into it.
+/// Returning `()` gives whether the request succeeded.
+#[proc_macro_derive(DefaultAPI, attributes(api_methods, endpoint))]
 pub fn default_api_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree.
     let input = parse_macro_input!(input);
@@ -69,30 +269,39 @@ pub fn default_api_derive(input: TokenStream) -> TokenStream {
         Ok(options) => options,
         Err(err) => return err.write_errors().into(),
     };
-    let DeriveInput { ident, .. } = input;
+    let DeriveInput { ident, attrs, .. } = input;
     let name = format_ident!("{}", ident.to_string().to_lowercase());
     
     // All of the structs/enum names that could be used...
     let x_raw = format_ident!("{}Raw", ident);
-    let x_get = format_ident!("{}Get", ident);
     let x_array = format_ident!("{}Array", ident);
-    let x_list = format_ident!("{}List", ident);
-    let x_create = format_ident!("{}Create", ident);
-    let x_update = format_ident!("{}Update", ident);
-    let x_destroy = format_ident!("{}Destroy", ident);
     let x_get_response = format_ident!("{}GetResponseRaw", ident);
     let x_list_response = format_ident!("{}ListResponseRaw", ident);
     let x_create_response_raw = format_ident!("{}CreateResponseRaw", ident);
     let x_create_payload = format_ident!("{}CreatePayload", ident);
+    let x_update_payload = format_ident!("{}UpdatePayload", ident);
+
+    // The typed route constructors...
+    let route_get = format_ident!("{}_get", name);
+    let route_list = format_ident!("{}_list", name);
+    let route_create = format_ident!("{}_create", name);
+    let route_update = format_ident!("{}_update", name);
+    let route_destroy = format_ident!("{}_destroy", name);
 
     // Each of the default methods...
     let empty = quote! {};
     let client = quote! {
         /// Creates a new instance client.
         pub fn client(api_key: &str, merchant: Option<&str>) -> Self {
+            Self::with_transport(api_key, merchant, Transport::default())
+        }
+
+        /// Creates a new instance client that sends requests through an existing transport.
+        pub fn with_transport(api_key: &str, merchant: Option<&str>, transport: Transport) -> Self {
             Self {
                 api_key: api_key.to_string(),
-                merchant: merchant.and_then(|x| Some(x.to_string()))
+                merchant: merchant.and_then(|x| Some(x.to_string())),
+                transport
             }
         }
     };
@@ -101,17 +310,12 @@ pub fn default_api_derive(input: TokenStream) -> TokenStream {
         /// Retrieves a instance of this class by its uniqid.
         pub async fn get(&self, uniqid: &str) -> Result<sellix_api_models::#name::#x_raw, sellix_api_models::SellixError> {
             // Used to build the url
-            let (method, path_builder) = sellix_api_models::RequestType::#x_get.request_details();
-            let path = handlebars::Handlebars::new()
-                .render_template(path_builder, &json!({
-                    "uniqid": uniqid
-                }))
-                .expect("unable to parse path");
+            let route = sellix_api_models::Route::#route_get(uniqid);
 
             // Send it
-            self.do_request::<sellix_api_models::#name::#x_get_response, sellix_api_models::#name::#x_raw>(method, &path, None)
+            self.do_request::<sellix_api_models::#name::#x_get_response, sellix_api_models::#name::#x_raw>(route, None)
                 .await
-                .and_then(|x| Ok(x.data.unwrap().#name))
+                .and_then(|x| Ok(x.into_data()?.#name))
         }
     };
     let get = if opts.get.unwrap_or(true) {get} else {empty.clone()};
@@ -120,49 +324,37 @@ pub fn default_api_derive(input: TokenStream) -> TokenStream {
         /// Sorted by creation date.
         pub async fn get_list(&self, page: Option<u64>) -> Result<sellix_api_models::#name::#x_array, sellix_api_models::SellixError> {
             // Used to build the url
-            let (method, path_builder) = sellix_api_models::RequestType::#x_list.request_details();
-            let path = handlebars::Handlebars::new()
-                .render_template(path_builder, &json!({
-                    "page": page
-                }))
-                .expect("unable to parse path");
+            let route = sellix_api_models::Route::#route_list(page);
 
             // Send it
-            self.do_request::<sellix_api_models::#name::#x_list_response, sellix_api_models::#name::#x_array>(method, &path, None)
+            self.do_request::<sellix_api_models::#name::#x_list_response, sellix_api_models::#name::#x_array>(route, None)
                 .await
-                .and_then(|x| Ok(x.data.unwrap()))
+                .and_then(|x| x.into_data())
         }
     };
     let list = if opts.list.unwrap_or(true) {list} else {empty.clone()};
     let create = quote! {
         /// Creates a instance to the API.
-        pub async fn create(&self, payload: sellix_api_models::#name::#x_create_payload<'_>) -> Result<sellix_api_models::UniqidDict, sellix_api_models::SellixError> {
+        pub async fn create(&self, payload: sellix_api_models::#name::#x_create_payload) -> Result<sellix_api_models::UniqidDict, sellix_api_models::SellixError> {
             // Used to build the url
-            let (method, path_builder) = sellix_api_models::RequestType::#x_create.request_details();
-            let path = handlebars::Handlebars::new()
-                .render_template(path_builder, &payload)
-                .expect("unable to parse path");
+            let route = sellix_api_models::Route::#route_create();
 
             // Send it
-            self.do_request::<sellix_api_models::#name::#x_create_response_raw, sellix_api_models::#name::#x_create_payload>(method, &path, Some(payload))
+            self.do_request::<sellix_api_models::#name::#x_create_response_raw, sellix_api_models::#name::#x_create_payload>(route, Some(payload))
                 .await
-                .and_then(|x| Ok(x.data.unwrap()))
+                .and_then(|x| x.into_data())
         }
     };
     let create = if opts.create.unwrap_or(true) {create} else {empty.clone()};
     let edit = quote! {
         /// Edits an instance via API.
-        pub async fn edit(&self, uniqid: &str, payload: sellix_api_models::#name::#x_create_payload<'_>) -> Result<bool, sellix_api_models::SellixError> {
+        /// Only the fields set within the payload are changed.
+        pub async fn edit(&self, uniqid: &str, payload: sellix_api_models::#name::#x_update_payload<'_>) -> Result<bool, sellix_api_models::SellixError> {
             // Used to build the url
-            let (method, path_builder) = sellix_api_models::RequestType::#x_update.request_details();
-            let path = handlebars::Handlebars::new()
-                .render_template(path_builder, &json!({
-                    "uniqid": uniqid
-                }))
-                .expect("unable to parse path");
+            let route = sellix_api_models::Route::#route_update(uniqid);
 
             // Send it
-            self.do_request::<sellix_api_models::RawAPIResponse<()>, sellix_api_models::#name::#x_create_payload>(method, &path, Some(payload))
+            self.do_request::<sellix_api_models::RawAPIResponse<()>, sellix_api_models::#name::#x_update_payload>(route, Some(payload))
                 .await
                 .and_then(|x| Ok(x.status == sellix_api_models::SellixHttpCode::Ok))
         }
@@ -172,22 +364,208 @@ pub fn default_api_derive(input: TokenStream) -> TokenStream {
         /// Deletes an instance via API.
         pub async fn delete(&self, uniqid: &str) -> Result<bool, sellix_api_models::SellixError> {
             // Used to build the url
-            let (method, path_builder) = sellix_api_models::RequestType::#x_destroy.request_details();
-            let path = handlebars::Handlebars::new()
-                .render_template(path_builder, &json!({
-                    "uniqid": uniqid
-                }))
-                .expect("unable to parse path");
+            let route = sellix_api_models::Route::#route_destroy(uniqid);
 
             // Send it
-            self.do_request::<sellix_api_models::RawAPIResponse<()>, Value>(method, &path, None)
+            self.do_request::<sellix_api_models::RawAPIResponse<()>, Value>(route, None)
                 .await
                 .and_then(|x| Ok(x.status == sellix_api_models::SellixHttpCode::Ok))
         }
     };
-    let delete = if opts.delete.unwrap_or(true) {delete} else {empty};
+    let delete = if opts.delete.unwrap_or(true) {delete} else {empty.clone()};
+
+    // Each capability trait, following the same options
+    let resource = quote! {
+        impl Resource for #ident {
+            const KIND: sellix_api_models::ResourceKind = sellix_api_models::ResourceKind::#ident;
+            type Raw = sellix_api_models::#name::#x_raw;
+            type List = sellix_api_models::#name::#x_array;
+        }
+    };
+    let gettable = quote! {
+        #[async_trait]
+        impl Gettable for #ident {
+            async fn get(&self, uniqid: &str) -> Result<Self::Raw, sellix_api_models::SellixError> {
+                #ident::get(self, uniqid).await
+            }
+        }
+    };
+    let gettable = if opts.get.unwrap_or(true) {gettable} else {empty.clone()};
+    let listable = quote! {
+        #[async_trait]
+        impl Listable for #ident {
+            async fn get_list(&self, page: Option<u64>) -> Result<Self::List, sellix_api_models::SellixError> {
+                #ident::get_list(self, page).await
+            }
+        }
+    };
+    let listable = if opts.list.unwrap_or(true) {listable} else {empty.clone()};
+    let creatable = quote! {
+        #[async_trait]
+        impl Creatable for #ident {
+            type CreatePayload = sellix_api_models::#name::#x_create_payload;
+
+            async fn create(&self, payload: Self::CreatePayload) -> Result<sellix_api_models::UniqidDict, sellix_api_models::SellixError> {
+                #ident::create(self, payload).await
+            }
+        }
+    };
+    let creatable = if opts.create.unwrap_or(true) {creatable} else {empty.clone()};
+    let editable = quote! {
+        #[async_trait]
+        impl Editable for #ident {
+            type UpdatePayload<'a> = sellix_api_models::#name::#x_update_payload<'a>;
+
+            async fn edit(&self, uniqid: &str, payload: Self::UpdatePayload<'_>) -> Result<bool, sellix_api_models::SellixError> {
+                #ident::edit(self, uniqid, payload).await
+            }
+        }
+    };
+    let editable = if opts.edit.unwrap_or(true) {editable} else {empty.clone()};
+    let deletable = quote! {
+        #[async_trait]
+        impl Deletable for #ident {
+            async fn delete(&self, uniqid: &str) -> Result<bool, sellix_api_models::SellixError> {
+                #ident::delete(self, uniqid).await
+            }
+        }
+    };
+    let deletable = if opts.delete.unwrap_or(true) {deletable} else {empty.clone()};
+
+    // Each custom endpoint, an invalid one being left out so that only its error is reported
+    let mut errors = quote! {};
+    let endpoints: Vec<_> = attrs.iter()
+        .filter(|x| x.path().is_ident("endpoint"))
+        .filter_map(|x| match x.parse_args::<Endpoint>().and_then(|x| endpoint(&name, &x)) {
+            Ok(endpoint) => Some(endpoint),
+            Err(err) => {
+                errors.extend(err.to_compile_error());
+                None
+            },
+        })
+        .collect();
+
+    // The mock, with a field for each method, implementing the same capability traits
+    let mock_ident = format_ident!("Mock{}", ident);
+    let mock_doc = format!("A mock of [`{}`], see [`sellix_rs::mock`].", ident);
+    let mut mock_fields = Vec::new();
+    let mut mock_impls = Vec::new();
+    let mut method_names = Vec::new();
+    if opts.get.unwrap_or(true) {
+        mock_fields.push(quote! { pub get: ::sellix_rs::mock::MockMethod<String, sellix_api_models::#name::#x_raw> });
+        mock_impls.push(quote! {
+            #[async_trait]
+            impl Gettable for #mock_ident {
+                async fn get(&self, uniqid: &str) -> Result<Self::Raw, sellix_api_models::SellixError> {
+                    self.get.call(uniqid.to_owned())
+                }
+            }
+        });
+        method_names.push(format_ident!("get"));
+    }
+    if opts.list.unwrap_or(true) {
+        mock_fields.push(quote! { pub get_list: ::sellix_rs::mock::MockMethod<Option<u64>, sellix_api_models::#name::#x_array> });
+        mock_impls.push(quote! {
+            #[async_trait]
+            impl Listable for #mock_ident {
+                async fn get_list(&self, page: Option<u64>) -> Result<Self::List, sellix_api_models::SellixError> {
+                    self.get_list.call(page)
+                }
+            }
+        });
+        method_names.push(format_ident!("get_list"));
+    }
+    if opts.create.unwrap_or(true) {
+        mock_fields.push(quote! { pub create: ::sellix_rs::mock::MockMethod<sellix_api_models::#name::#x_create_payload, sellix_api_models::UniqidDict> });
+        mock_impls.push(quote! {
+            #[async_trait]
+            impl Creatable for #mock_ident {
+                type CreatePayload = sellix_api_models::#name::#x_create_payload;
+
+                async fn create(&self, payload: Self::CreatePayload) -> Result<sellix_api_models::UniqidDict, sellix_api_models::SellixError> {
+                    self.create.call(payload)
+                }
+            }
+        });
+        method_names.push(format_ident!("create"));
+    }
+    if opts.edit.unwrap_or(true) {
+        mock_fields.push(quote! {
+            /// Called with the uniqid, and the payload as JSON.
+            pub edit: ::sellix_rs::mock::MockMethod<(String, ::sellix_rs::mock::Value), bool>
+        });
+        mock_impls.push(quote! {
+            #[async_trait]
+            impl Editable for #mock_ident {
+                type UpdatePayload<'a> = sellix_api_models::#name::#x_update_payload<'a>;
+
+                async fn edit(&self, uniqid: &str, payload: Self::UpdatePayload<'_>) -> Result<bool, sellix_api_models::SellixError> {
+                    self.edit.call((uniqid.to_owned(), ::sellix_rs::mock::to_value(payload).unwrap()))
+                }
+            }
+        });
+        method_names.push(format_ident!("edit"));
+    }
+    if opts.delete.unwrap_or(true) {
+        mock_fields.push(quote! { pub delete: ::sellix_rs::mock::MockMethod<String, bool> });
+        mock_impls.push(quote! {
+            #[async_trait]
+            impl Deletable for #mock_ident {
+                async fn delete(&self, uniqid: &str) -> Result<bool, sellix_api_models::SellixError> {
+                    self.delete.call(uniqid.to_owned())
+                }
+            }
+        });
+        method_names.push(format_ident!("delete"));
+    }
+    let mut mock_methods = Vec::new();
+    for endpoint in &endpoints {
+        mock_fields.push(endpoint.mock_field.clone());
+        mock_methods.push(endpoint.mock_method.clone());
+        method_names.push(endpoint.name.clone());
+    }
+    let method_labels = method_names.iter().map(|x| format!("{}::{}", mock_ident, x));
+
+    // Only kept when `sellix_rs` has the `mock` feature, wherever it is derived
+    let mock = quote! {
+        ::sellix_rs::__mock! {
+            #[doc = #mock_doc]
+            pub struct #mock_ident {
+                #(#mock_fields,)*
+            }
+            const _: () = {
+                impl #mock_ident {
+                    /// Creates a mock without any expectations.
+                    pub fn new() -> Self {
+                        Self {
+                            #(#method_names: ::sellix_rs::mock::MockMethod::new(#method_labels),)*
+                        }
+                    }
+
+                    /// Panics if any expectation with a set number of calls was not called that many times.
+                    pub fn verify(&self) {
+                        #(self.#method_names.verify();)*
+                    }
+
+                    #(#mock_methods)*
+                }
+                impl Default for #mock_ident {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+                impl Resource for #mock_ident {
+                    const KIND: sellix_api_models::ResourceKind = sellix_api_models::ResourceKind::#ident;
+                    type Raw = sellix_api_models::#name::#x_raw;
+                    type List = sellix_api_models::#name::#x_array;
+                }
+                #(#mock_impls)*
+            };
+        }
+    };
 
     // Final output
+    let methods = endpoints.iter().map(|x| &x.method);
     let out = quote! {
         impl #ident {
             #client
@@ -196,7 +574,16 @@ pub fn default_api_derive(input: TokenStream) -> TokenStream {
             #create
             #edit
             #delete
+            #(#methods)*
         }
+        #resource
+        #gettable
+        #listable
+        #creatable
+        #editable
+        #deletable
+        #mock
+        #errors
     };
 
     // Return